@@ -1,15 +1,18 @@
 import { AnchorProvider, Program, Wallet } from "@coral-xyz/anchor";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { TOKEN_PROGRAM_ID, AccountLayout, getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { MPL_TOKEN_METADATA_PROGRAM_ID } from "@metaplex-foundation/mpl-token-metadata";
 import {
+    AccountMeta,
     PublicKey,
     Connection,
+    Signer,
     TransactionInstruction,
     VersionedTransaction,
     TransactionMessage,
     LAMPORTS_PER_SOL,
 } from "@solana/web3.js";
 import { BN } from "bn.js";
+import { createHash } from "crypto";
 import { ChainTicket } from "../types/chain_ticket";
 import * as IDL from "../types/chain_ticket.json";
 
@@ -19,6 +22,23 @@ const EVENT_SEED: string = "event";
 const MINT_SEED: string = "mint";
 const VAULT_SEED: string = "vault";
 const METADATA_SEED: string = "metadata";
+const WAITLIST_SEED: string = "waitlist";
+const HOLD_SEED: string = "hold";
+const RECEIPT_SEED: string = "receipt";
+const PLATFORM_SEED: string = "platform";
+const AGENT_SEED: string = "agent";
+const STAFF_SEED: string = "staff";
+const ATTENDANCE_MINT_SEED: string = "attendance_mint";
+const CHECK_IN_BATCH_SEED: string = "check_in_batch";
+const LISTING_SEED: string = "listing";
+const OFFER_SEED: string = "offer";
+const PROFILE_SEED: string = "profile";
+const AFFILIATE_SEED: string = "affiliate";
+
+// Staff permission flags, see `grant_role`
+export const PERMISSION_SCANNER: number = 1 << 0;
+export const PERMISSION_REFUND_AGENT: number = 1 << 1;
+export const PERMISSION_METADATA_EDITOR: number = 1 << 3;
 
 export function getEventAddress(authority: PublicKey): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
@@ -62,6 +82,155 @@ export function getMetadataAddress(mintAddress: PublicKey): [PublicKey, number]
     );
 }
 
+export function getPlatformAddress(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(PLATFORM_SEED),
+        ],
+        new PublicKey(idl.address),
+    );
+}
+
+export function getReceiptAddress(eventAddress: PublicKey, holder: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(RECEIPT_SEED),
+            eventAddress.toBuffer(),
+            holder.toBuffer(),
+        ],
+        new PublicKey(idl.address),
+    );
+}
+
+export function getWaitlistAddress(eventAddress: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(WAITLIST_SEED),
+            eventAddress.toBuffer(),
+        ],
+        new PublicKey(idl.address),
+    );
+}
+
+export function getHoldAddress(eventAddress: PublicKey, holder: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(HOLD_SEED),
+            eventAddress.toBuffer(),
+            holder.toBuffer(),
+        ],
+        new PublicKey(idl.address),
+    );
+}
+
+export function getAgentAddress(eventAddress: PublicKey, agent: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(AGENT_SEED),
+            eventAddress.toBuffer(),
+            agent.toBuffer(),
+        ],
+        new PublicKey(idl.address),
+    );
+}
+
+export function getStaffAddress(eventAddress: PublicKey, key: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(STAFF_SEED),
+            eventAddress.toBuffer(),
+            key.toBuffer(),
+        ],
+        new PublicKey(idl.address),
+    );
+}
+
+export function getAttendanceMintAddress(eventAddress: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(ATTENDANCE_MINT_SEED),
+            eventAddress.toBuffer(),
+        ],
+        new PublicKey(idl.address),
+    );
+}
+
+export function getCheckInBatchAddress(eventAddress: PublicKey, index: number): [PublicKey, number] {
+    const indexBuffer = Buffer.alloc(4);
+    indexBuffer.writeUInt32LE(index);
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(CHECK_IN_BATCH_SEED),
+            eventAddress.toBuffer(),
+            indexBuffer,
+        ],
+        new PublicKey(idl.address),
+    );
+}
+
+export function getProfileAddress(authority: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(PROFILE_SEED),
+            authority.toBuffer(),
+        ],
+        new PublicKey(idl.address),
+    );
+}
+
+export function getAffiliateAddress(eventAddress: PublicKey, wallet: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(AFFILIATE_SEED),
+            eventAddress.toBuffer(),
+            wallet.toBuffer(),
+        ],
+        new PublicKey(idl.address),
+    );
+}
+
+export function getListingAddress(eventAddress: PublicKey, seller: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(LISTING_SEED),
+            eventAddress.toBuffer(),
+            seller.toBuffer(),
+        ],
+        new PublicKey(idl.address),
+    );
+}
+
+export function getOfferAddress(eventAddress: PublicKey, buyer: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(OFFER_SEED),
+            eventAddress.toBuffer(),
+            buyer.toBuffer(),
+        ],
+        new PublicKey(idl.address),
+    );
+}
+
+// Leaf of an offline check-in Merkle tree, must match `utils::check_in_leaf`
+export function checkInLeaf(receipt: PublicKey, issuedSlot: BN, session: number): Buffer {
+    return createHash("sha256")
+        .update(Buffer.from([0]))
+        .update(receipt.toBuffer())
+        .update(issuedSlot.toArrayLike(Buffer, "le", 8))
+        .update(Buffer.from([session]))
+        .digest();
+}
+
+// Parent of two Merkle nodes, pairs are sorted so proofs do not encode sibling order
+export function checkInNode(a: Buffer, b: Buffer): Buffer {
+    const [left, right] = Buffer.compare(a, b) <= 0 ? [a, b] : [b, a];
+    return createHash("sha256")
+        .update(Buffer.from([1]))
+        .update(left)
+        .update(right)
+        .digest();
+}
+
 export async function burnRefundAll(connection: Connection, wallet: Wallet, refund: boolean): Promise<[string[], string[]]> {
     const chainTicketProgram = new ChainTicketProgram(connection, wallet);
     const eventAddress = getEventAddress(wallet.publicKey)[0];
@@ -72,15 +241,19 @@ export async function burnRefundAll(connection: Connection, wallet: Wallet, refu
         },
         {
             memcmp: {
-                offset: 32, // Offset for the mint address
+                offset: 0, // Offset for the mint address
                 bytes: mintAddress.toBase58(),
             },
         },
     ];
 
+    // Tickets are refunded and burned by their holder's wallet, the owner of the token account
     const accounts = await connection.getProgramAccounts(TOKEN_PROGRAM_ID, {
         filters: filter,
-    }).then(r => r.map(({ pubkey }) => pubkey));
+    }).then(r => r
+        .map(({ account }) => AccountLayout.decode(account.data))
+        .filter(({ amount }) => amount.toString() !== "0")
+        .map(({ owner }) => owner));
 
     let txids: string[] = [];
     let failures: string[] = [];
@@ -123,10 +296,68 @@ export type InitEventFields = {
     refundPeriod: number, // As a unix timestamp
 }
 
+// Fields left undefined are not amended
 export type AmendEventFields = {
-    eventDate: number, // As a unix timestamp
-    ticketPrice: number, // In sol, a program will convert SOL -> Lamports
-    numTickets: number,
+    eventDate?: number, // As a unix timestamp
+    ticketPrice?: number, // In sol, a program will convert SOL -> Lamports
+    numTickets?: number,
+    holdTtl?: number, // In seconds
+    compQuota?: number,
+    resaleCapBps?: number, // Maximum resale price as basis points of face value, zero disables resale
+    resaleRoyaltyBps?: number, // Organiser royalty on resales as basis points of the sale price
+    transfersEnabled?: boolean,
+    transferLockout?: number, // Seconds before the event date from which transfers are locked
+    maxTransfers?: number, // Per ticket, zero means no limit
+    transferFee?: number, // In sol, paid into the vault by the sender
+}
+
+// Replaces the ticket's token metadata, every field is required
+export type UpdateMetadataFields = {
+    eventName: string,
+    eventSymbol: string,
+    imageUri: string,
+}
+
+// Party a gifted ticket's refund is returned to
+export type RefundRecipient = "payer" | "holder";
+
+export type SessionFields = {
+    date: number, // As a unix timestamp
+    price: number, // In sol, program will convert SOL -> Lamports
+    capacity: number,
+}
+
+export type PayeeFields = {
+    recipient: PublicKey,
+    shareBps: number, // Shares of all payees add up to 10,000
+}
+
+export type FeeTierFields = {
+    minVolume: number, // Lifetime gross sales in SOL from which the tier applies
+    feeBps: number,
+}
+
+export type InitAttendanceFields = {
+    name: string,
+    symbol: string,
+    uri: string,
+    transferable: boolean, // Souvenirs are frozen in the holder's wallet otherwise
+}
+
+export type BuyTicketOptions = {
+    buyer?: PublicKey, // Defaults to the wallet, the wallet pays the rent either way
+    sessions?: number, // Bitmask of the sessions of a multi-day event
+    relayerFee?: number, // In lamports, only the platform relayer can be reimbursed
+    hold?: boolean, // Whether to consume the buyer's hold
+    affiliate?: PublicKey, // Wallet of the registered affiliate that referred the sale
+}
+
+function toBN(value?: number): BN | null {
+    return value === undefined ? null : new BN(value);
+}
+
+function solToBN(value?: number): BN | null {
+    return value === undefined ? null : new BN(value * LAMPORTS_PER_SOL);
 }
 
 export class ChainTicketProgram {
@@ -144,9 +375,12 @@ export class ChainTicketProgram {
         this.program = new Program(idl, provider);
     }
 
-    async sendTransaction(instructions: TransactionInstruction[]): Promise<string> {
+    async sendTransaction(
+        instructions: TransactionInstruction[],
+        signers: Signer[] = [],
+    ): Promise<string> {
         const transaction = await this.prepareTransaction(instructions);
-        const txid = await this.program.provider.sendAndConfirm(transaction);
+        const txid = await this.program.provider.sendAndConfirm(transaction, signers);
 
         return txid;
     }
@@ -156,7 +390,7 @@ export class ChainTicketProgram {
         const delay = 1000;
         let retries = 0;
 
-        while (retries > maxRetries) {
+        while (retries < maxRetries) {
             try {
                 const recentBlockhash = await this.program
                     .provider
@@ -210,18 +444,42 @@ export class ChainTicketProgram {
         fields: AmendEventFields,
     ): Promise<TransactionInstruction> {
         const authority = this.program.provider.publicKey;
-        const ticketPrice = fields.ticketPrice * LAMPORTS_PER_SOL;
 
         return this.program.methods.amendEvent({
-            eventDate: new BN(fields.eventDate),
-            ticketPrice: new BN(ticketPrice),
-            numTickets: fields.numTickets,
+            eventDate: toBN(fields.eventDate),
+            ticketPrice: solToBN(fields.ticketPrice),
+            numTickets: fields.numTickets ?? null,
+            holdTtl: toBN(fields.holdTtl),
+            compQuota: fields.compQuota ?? null,
+            resaleCapBps: fields.resaleCapBps ?? null,
+            resaleRoyaltyBps: fields.resaleRoyaltyBps ?? null,
+            transfersEnabled: fields.transfersEnabled ?? null,
+            transferLockout: toBN(fields.transferLockout),
+            maxTransfers: fields.maxTransfers ?? null,
+            transferFee: solToBN(fields.transferFee),
         })
             .accounts({
-                authority,
+                authority
             }).instruction();
     }
 
+    // Updates the ticket's token metadata with the wallet as the editor
+    getUpdateMetadataIx(
+        event: PublicKey,
+        fields: UpdateMetadataFields,
+        asStaff: boolean = false,
+    ): Promise<TransactionInstruction> {
+        const editor = this.program.provider.publicKey;
+
+        return this.program.methods.updateMetadata(fields).accountsPartial(
+            {
+                editor,
+                staff: asStaff ? getStaffAddress(event, editor)[0] : null,
+                event,
+            }
+        ).instruction();
+    }
+
     getStartSaleIx(): Promise<TransactionInstruction> {
         return this.program.methods.startSale().accounts(
             {
@@ -230,46 +488,217 @@ export class ChainTicketProgram {
         ).instruction();
     }
 
-    getBuyTicketIx(event: PublicKey): Promise<TransactionInstruction> {
-        return this.program.methods.buyTicket().accounts(
+    // Payees can only be set before the sale starts, an empty list sends all proceeds to the
+    // authority
+    getSetPayeesIx(payees: PayeeFields[]): Promise<TransactionInstruction> {
+        return this.program.methods.setPayees(payees).accounts(
             {
-                event,
-                buyer: this.program.provider.publicKey,
+                authority: this.program.provider.publicKey,
             }
         ).instruction();
     }
 
-    getRefundTicketIx(buyer: PublicKey): Promise<TransactionInstruction> {
-        return this.program.methods.refundTicket().accounts(
+    // Sessions can only be set before the sale starts
+    getSetSessionsIx(sessions: SessionFields[]): Promise<TransactionInstruction> {
+        return this.program.methods.setSessions(sessions.map(session => ({
+            date: new BN(session.date),
+            price: new BN(session.price * LAMPORTS_PER_SOL),
+            capacity: session.capacity,
+        }))).accounts(
             {
                 authority: this.program.provider.publicKey,
+            }
+        ).instruction();
+    }
+
+    getBuyTicketIx(event: PublicKey, options: BuyTicketOptions = {}): Promise<TransactionInstruction> {
+        const feePayer = this.program.provider.publicKey;
+        const buyer = options.buyer ?? feePayer;
+
+        return this.program.methods.buyTicket(
+            options.sessions ?? 0,
+            new BN(options.relayerFee ?? 0),
+        ).accountsPartial(
+            {
+                event,
                 buyer,
+                feePayer,
+                hold: options.hold ? getHoldAddress(event, buyer)[0] : null,
+                affiliate: options.affiliate ? getAffiliateAddress(event, options.affiliate)[0] : null,
             }
         ).instruction();
     }
 
-    getBurnTicketIx(event: PublicKey): Promise<TransactionInstruction> {
-        return this.program.methods.burnTicket().accounts(
+    async getRefundTicketIx(buyer: PublicKey): Promise<TransactionInstruction> {
+        const operator = this.program.provider.publicKey;
+        const event = getEventAddress(operator)[0];
+        const receipt = await this.program.account.receipt
+            .fetch(getReceiptAddress(event, buyer)[0]);
+
+        return this.program.methods.refundTicket().accountsPartial(
             {
+                operator,
+                staff: null,
                 event,
-                ticketHolder: this.program.provider.publicKey,
+                buyer,
+                payer: receipt.payer,
+                rentPayer: receipt.rentPayer,
+                // The commission of the referring affiliate is reversed
+                affiliate: receipt.commission.isZero() ? null : receipt.affiliate,
             }
         ).instruction();
     }
 
-    getDelegateBurnIx(targetWallet: PublicKey): Promise<TransactionInstruction> {
+    // Burns the wallet's ticket, or `ticketHolder`'s ticket who must co-sign while the wallet pays
+    // the transaction fee
+    async getBurnTicketIx(event: PublicKey, ticketHolder?: PublicKey): Promise<TransactionInstruction> {
+        const holder = ticketHolder ?? this.program.provider.publicKey;
+        const receipt = await this.program.account.receipt
+            .fetch(getReceiptAddress(event, holder)[0]);
+
+        return this.program.methods.burnTicket().accountsPartial(
+            {
+                event,
+                ticketHolder: holder,
+                rentPayer: receipt.rentPayer,
+            }
+        ).instruction();
+    }
+
+    async getDelegateBurnIx(targetWallet: PublicKey): Promise<TransactionInstruction> {
+        const authority = this.program.provider.publicKey;
+        const event = getEventAddress(authority)[0];
+        const receipt = await this.program.account.receipt
+            .fetch(getReceiptAddress(event, targetWallet)[0]);
+
         return this.program.methods.delegateBurn().accounts(
             {
-                authority: this.program.provider.publicKey,
+                authority,
                 targetWallet,
+                rentPayer: receipt.rentPayer,
+            }
+        ).instruction();
+    }
+
+    // The organiser's profile is required to withdraw proceeds
+    getInitProfileIx(): Promise<TransactionInstruction> {
+        return this.program.methods.initProfile().accounts(
+            {
+                authority: this.program.provider.publicKey,
+            }
+        ).instruction();
+    }
+
+    // Only the platform authority can waive the fee of an organiser
+    getSetFeeExemptionIx(organiser: PublicKey, exempt: boolean): Promise<TransactionInstruction> {
+        return this.program.methods.setFeeExemption(exempt).accountsPartial(
+            {
+                authority: this.program.provider.publicKey,
+                profile: getProfileAddress(organiser)[0],
+            }
+        ).instruction();
+    }
+
+    // Only the platform authority can set the fee tiers, in ascending order of volume
+    getSetFeeTiersIx(tiers: FeeTierFields[]): Promise<TransactionInstruction> {
+        return this.program.methods.setFeeTiers(tiers.map(tier => ({
+            minVolume: new BN(tier.minVolume * LAMPORTS_PER_SOL),
+            feeBps: tier.feeBps,
+        }))).accounts(
+            {
+                authority: this.program.provider.publicKey,
             }
         ).instruction();
     }
 
-    getWithdrawFundsIx(): Promise<TransactionInstruction> {
+    // Payee accounts in the order of the event's payee table, proceeds are paid out to these
+    private async payeeAccounts(event: PublicKey): Promise<AccountMeta[]> {
+        const eventData = await this.program.account.event.fetch(event);
+        return eventData.payees.map(payee => ({
+            pubkey: payee.recipient,
+            isSigner: false,
+            isWritable: true,
+        }));
+    }
+
+    // Affiliates owed commission and their wallets, in pairs
+    private async affiliateAccounts(event: PublicKey): Promise<AccountMeta[]> {
+        const affiliates = await this.program.account.affiliate.all([
+            {
+                memcmp: {
+                    offset: 8 + 1, // Discriminator and bump
+                    bytes: event.toBase58(),
+                },
+            },
+        ]);
+        return affiliates
+            .filter(({ account }) => account.accrued.gt(account.paid))
+            .flatMap(({ publicKey, account }) => [
+                { pubkey: publicKey, isSigner: false, isWritable: true },
+                { pubkey: account.wallet, isSigner: false, isWritable: true },
+            ]);
+    }
+
+    // Withdraws the proceeds and pays out the commission owed to the event's affiliates
+    async getWithdrawFundsIx(): Promise<TransactionInstruction> {
+        const authority = this.program.provider.publicKey;
+        const event = getEventAddress(authority)[0];
+
         return this.program.methods.withdrawFunds().accounts(
+            {
+                authority,
+            }
+        ).remainingAccounts([
+            ...await this.payeeAccounts(event),
+            ...await this.affiliateAccounts(event),
+        ]).instruction();
+    }
+
+    // Advances `amount` SOL of the proceeds that can no longer be refunded
+    async getAdvanceFundsIx(amount: number): Promise<TransactionInstruction> {
+        const authority = this.program.provider.publicKey;
+
+        return this.program.methods.advanceFunds(new BN(amount * LAMPORTS_PER_SOL)).accounts(
+            {
+                authority,
+            }
+        ).remainingAccounts(await this.payeeAccounts(getEventAddress(authority)[0])).instruction();
+    }
+
+    // Claims the proceeds vested since the last claim, vesting starts after the refund period
+    async getClaimVestedIx(): Promise<TransactionInstruction> {
+        const authority = this.program.provider.publicKey;
+
+        return this.program.methods.claimVested().accounts(
+            {
+                authority,
+            }
+        ).remainingAccounts(await this.payeeAccounts(getEventAddress(authority)[0])).instruction();
+    }
+
+    // Only the platform authority can flag an event
+    getFlagEventIx(event: PublicKey, flagged: boolean): Promise<TransactionInstruction> {
+        return this.program.methods.flagEvent(flagged).accounts(
             {
                 authority: this.program.provider.publicKey,
+                event,
+            }
+        ).instruction();
+    }
+
+    // Reclaims the price of the wallet's ticket to a flagged event
+    async getRefundFlaggedIx(event: PublicKey): Promise<TransactionInstruction> {
+        const holder = this.program.provider.publicKey;
+        const receipt = await this.program.account.receipt
+            .fetch(getReceiptAddress(event, holder)[0]);
+
+        return this.program.methods.refundFlagged().accountsPartial(
+            {
+                event,
+                holder,
+                payer: receipt.payer,
+                rentPayer: receipt.rentPayer,
+                affiliate: receipt.commission.isZero() ? null : receipt.affiliate,
             }
         ).instruction();
     }
@@ -289,4 +718,371 @@ export class ChainTicketProgram {
             }
         ).instruction();
     }
+
+    getInitWaitlistIx(): Promise<TransactionInstruction> {
+        return this.program.methods.initWaitlist().accounts(
+            {
+                authority: this.program.provider.publicKey,
+            }
+        ).instruction();
+    }
+
+    getJoinWaitlistIx(event: PublicKey): Promise<TransactionInstruction> {
+        return this.program.methods.joinWaitlist().accountsPartial(
+            {
+                event,
+                wallet: this.program.provider.publicKey,
+            }
+        ).instruction();
+    }
+
+    getLeaveWaitlistIx(event: PublicKey): Promise<TransactionInstruction> {
+        return this.program.methods.leaveWaitlist().accountsPartial(
+            {
+                event,
+                wallet: this.program.provider.publicKey,
+            }
+        ).instruction();
+    }
+
+    getServeWaitlistIx(event: PublicKey, wallet: PublicKey): Promise<TransactionInstruction> {
+        return this.program.methods.serveWaitlist().accountsPartial(
+            {
+                event,
+                wallet,
+            }
+        ).instruction();
+    }
+
+    getEvictWaitlistHeadIx(event: PublicKey, wallet: PublicKey): Promise<TransactionInstruction> {
+        return this.program.methods.evictWaitlistHead().accountsPartial(
+            {
+                operator: this.program.provider.publicKey,
+                event,
+                wallet,
+                walletAta: getAssociatedTokenAddressSync(getMintAddress(event)[0], wallet),
+            }
+        ).instruction();
+    }
+
+    getPlaceHoldIx(event: PublicKey): Promise<TransactionInstruction> {
+        return this.program.methods.placeHold().accounts(
+            {
+                event,
+                holder: this.program.provider.publicKey,
+            }
+        ).instruction();
+    }
+
+    getExpireHoldIx(event: PublicKey, holder: PublicKey): Promise<TransactionInstruction> {
+        return this.program.methods.expireHold().accountsPartial(
+            {
+                event,
+                holder,
+            }
+        ).instruction();
+    }
+
+    getBuyGiftTicketIx(
+        event: PublicKey,
+        recipient: PublicKey,
+        refundRecipient: RefundRecipient,
+        sessions: number = 0,
+    ): Promise<TransactionInstruction> {
+        return this.program.methods.buyGiftTicket(
+            sessions,
+            refundRecipient === "payer" ? { payer: {} } : { holder: {} },
+        ).accountsPartial(
+            {
+                event,
+                payer: this.program.provider.publicKey,
+                recipient,
+            }
+        ).instruction();
+    }
+
+    getIssueCompTicketIx(guest: PublicKey, sessions: number = 0): Promise<TransactionInstruction> {
+        return this.program.methods.issueCompTicket(sessions).accounts(
+            {
+                authority: this.program.provider.publicKey,
+                guest,
+            }
+        ).instruction();
+    }
+
+    getRegisterAgentIx(agent: PublicKey, quota: number): Promise<TransactionInstruction> {
+        return this.program.methods.registerAgent(quota).accounts(
+            {
+                authority: this.program.provider.publicKey,
+                agent,
+            }
+        ).instruction();
+    }
+
+    getAgentIssueTicketIx(
+        event: PublicKey,
+        customer: PublicKey,
+        declaredRevenue: number,
+        sessions: number = 0,
+    ): Promise<TransactionInstruction> {
+        const agent = this.program.provider.publicKey;
+
+        return this.program.methods.agentIssueTicket(sessions, new BN(declaredRevenue))
+            .accountsPartial(
+                {
+                    event,
+                    agent,
+                    customer,
+                }
+            ).instruction();
+    }
+
+    getGrantRoleIx(staffKey: PublicKey, permissions: number): Promise<TransactionInstruction> {
+        return this.program.methods.grantRole(permissions).accounts(
+            {
+                authority: this.program.provider.publicKey,
+                staffKey,
+            }
+        ).instruction();
+    }
+
+    getRevokeRoleIx(staffKey: PublicKey, permissions: number): Promise<TransactionInstruction> {
+        const authority = this.program.provider.publicKey;
+        const event = getEventAddress(authority)[0];
+
+        return this.program.methods.revokeRole(permissions).accountsPartial(
+            {
+                authority,
+                staff: getStaffAddress(event, staffKey)[0],
+            }
+        ).instruction();
+    }
+
+    // Checks in `ticketHolder`, who must co-sign, with the wallet as the scanner. Staff scanners
+    // pass their staff account, the event authority does not need one
+    getCheckInIx(
+        event: PublicKey,
+        ticketHolder: PublicKey,
+        session: number = 0,
+        asStaff: boolean = false,
+    ): Promise<TransactionInstruction> {
+        const scanner = this.program.provider.publicKey;
+
+        return this.program.methods.checkIn(session).accountsPartial(
+            {
+                event,
+                ticketHolder,
+                scanner,
+                staff: asStaff ? getStaffAddress(event, scanner)[0] : null,
+            }
+        ).instruction();
+    }
+
+    getInitAttendanceIx(fields: InitAttendanceFields): Promise<TransactionInstruction> {
+        return this.program.methods.initAttendance(fields).accounts(
+            {
+                authority: this.program.provider.publicKey,
+            }
+        ).instruction();
+    }
+
+    getClaimAttendanceIx(event: PublicKey): Promise<TransactionInstruction> {
+        return this.program.methods.claimAttendance().accountsPartial(
+            {
+                event,
+                ticketHolder: this.program.provider.publicKey,
+            }
+        ).instruction();
+    }
+
+    // Settles a batch of offline check-ins under `root` with the wallet as the scanner
+    async getSettleCheckInsIx(
+        event: PublicKey,
+        root: Buffer,
+        count: number,
+        asStaff: boolean = false,
+    ): Promise<TransactionInstruction> {
+        const scanner = this.program.provider.publicKey;
+        const eventData = await this.program.account.event.fetch(event);
+
+        return this.program.methods.settleCheckIns(Array.from(root), count).accountsPartial(
+            {
+                scanner,
+                staff: asStaff ? getStaffAddress(event, scanner)[0] : null,
+                event,
+                batch: getCheckInBatchAddress(event, eventData.checkInBatches)[0],
+            }
+        ).instruction();
+    }
+
+    // Proves `holder`'s check-in, claims can be submitted by any wallet
+    getClaimCheckInIx(
+        event: PublicKey,
+        holder: PublicKey,
+        batchIndex: number,
+        session: number,
+        proof: Buffer[],
+    ): Promise<TransactionInstruction> {
+        return this.program.methods.claimCheckIn(session, proof.map(node => Array.from(node))).accountsPartial(
+            {
+                event,
+                batch: getCheckInBatchAddress(event, batchIndex)[0],
+                receipt: getReceiptAddress(event, holder)[0],
+            }
+        ).instruction();
+    }
+
+    // Lists the wallet's ticket for resale at `price` in SOL
+    getListTicketIx(event: PublicKey, price: number): Promise<TransactionInstruction> {
+        return this.program.methods.listTicket(new BN(price * LAMPORTS_PER_SOL)).accountsPartial(
+            {
+                event,
+                seller: this.program.provider.publicKey,
+            }
+        ).instruction();
+    }
+
+    getCancelListingIx(event: PublicKey): Promise<TransactionInstruction> {
+        return this.program.methods.cancelListing().accountsPartial(
+            {
+                event,
+                seller: this.program.provider.publicKey,
+            }
+        ).instruction();
+    }
+
+    // Buys `seller`'s listed ticket with the wallet, royalties are paid out of the asking price
+    async getBuyListingIx(event: PublicKey, seller: PublicKey): Promise<TransactionInstruction> {
+        const receipt = await this.program.account.receipt
+            .fetch(getReceiptAddress(event, seller)[0]);
+        const platform = await this.program.account.platformConfig
+            .fetch(getPlatformAddress()[0]);
+
+        return this.program.methods.buyListing().accountsPartial(
+            {
+                event,
+                seller,
+                treasury: platform.treasury,
+                sellerRentPayer: receipt.rentPayer,
+                buyer: this.program.provider.publicKey,
+            }
+        ).instruction();
+    }
+
+    // Hands the wallet's ticket to `recipient`, who must co-sign
+    async getTransferTicketIx(event: PublicKey, recipient: PublicKey): Promise<TransactionInstruction> {
+        const sender = this.program.provider.publicKey;
+        const receipt = await this.program.account.receipt
+            .fetch(getReceiptAddress(event, sender)[0]);
+
+        return this.program.methods.transferTicket().accountsPartial(
+            {
+                event,
+                sender,
+                senderRentPayer: receipt.rentPayer,
+                recipient,
+            }
+        ).instruction();
+    }
+
+    // Swaps the wallet's ticket to `eventA` for `holderB`'s ticket to `eventB`, `holderB` must
+    // co-sign
+    async getSwapTicketsIx(
+        eventA: PublicKey,
+        eventB: PublicKey,
+        holderB: PublicKey,
+    ): Promise<TransactionInstruction> {
+        const holderA = this.program.provider.publicKey;
+        const receiptA = await this.program.account.receipt
+            .fetch(getReceiptAddress(eventA, holderA)[0]);
+        const receiptB = await this.program.account.receipt
+            .fetch(getReceiptAddress(eventB, holderB)[0]);
+
+        return this.program.methods.swapTickets().accountsPartial(
+            {
+                eventA,
+                eventB,
+                holderA,
+                holderB,
+                rentPayerA: receiptA.rentPayer,
+                rentPayerB: receiptB.rentPayer,
+            }
+        ).instruction();
+    }
+
+    // Swaps the entitlements of the wallet's and `holderB`'s tickets to the same event, `holderB`
+    // must co-sign
+    getSwapSessionsIx(event: PublicKey, holderB: PublicKey): Promise<TransactionInstruction> {
+        return this.program.methods.swapSessions().accountsPartial(
+            {
+                event,
+                holderA: this.program.provider.publicKey,
+                holderB,
+            }
+        ).instruction();
+    }
+
+    // Offers `price` SOL for a ticket of `event` carrying the `sessions` entitlement
+    getMakeOfferIx(
+        event: PublicKey,
+        price: number,
+        sessions: number = 0,
+    ): Promise<TransactionInstruction> {
+        return this.program.methods.makeOffer(new BN(price * LAMPORTS_PER_SOL), sessions).accountsPartial(
+            {
+                event,
+                buyer: this.program.provider.publicKey,
+            }
+        ).instruction();
+    }
+
+    // Fills `buyer`'s offer with the wallet's ticket
+    async getAcceptOfferIx(event: PublicKey, buyer: PublicKey): Promise<TransactionInstruction> {
+        const seller = this.program.provider.publicKey;
+        const receipt = await this.program.account.receipt
+            .fetch(getReceiptAddress(event, seller)[0]);
+        const platform = await this.program.account.platformConfig
+            .fetch(getPlatformAddress()[0]);
+
+        return this.program.methods.acceptOffer().accountsPartial(
+            {
+                event,
+                treasury: platform.treasury,
+                seller,
+                sellerRentPayer: receipt.rentPayer,
+                buyer,
+            }
+        ).instruction();
+    }
+
+    getCancelOfferIx(event: PublicKey, buyer: PublicKey): Promise<TransactionInstruction> {
+        return this.program.methods.cancelOffer().accountsPartial(
+            {
+                event,
+                caller: this.program.provider.publicKey,
+                buyer,
+            }
+        ).instruction();
+    }
+
+    // Registers `wallet` as an affiliate of the wallet's event, or updates its commission rate
+    getRegisterAffiliateIx(wallet: PublicKey, commissionBps: number): Promise<TransactionInstruction> {
+        return this.program.methods.registerAffiliate(commissionBps).accounts(
+            {
+                authority: this.program.provider.publicKey,
+                wallet,
+            }
+        ).instruction();
+    }
+
+    // Pays `wallet` the commission it accrued as an affiliate of `event`, callable by anyone
+    getClaimCommissionIx(event: PublicKey, wallet: PublicKey): Promise<TransactionInstruction> {
+        return this.program.methods.claimCommission().accountsPartial(
+            {
+                event,
+                affiliate: getAffiliateAddress(event, wallet)[0],
+                wallet,
+            }
+        ).instruction();
+    }
 }
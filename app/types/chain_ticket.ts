@@ -14,76 +14,77 @@ export type ChainTicket = {
   },
   "instructions": [
     {
-      "name": "amendEvent",
+      "name": "acceptOffer",
       "discriminator": [
-        119,
-        33,
-        201,
-        211,
-        127,
-        27,
-        193,
-        97
+        227,
+        82,
+        234,
+        131,
+        1,
+        18,
+        48,
+        2
       ],
       "accounts": [
         {
-          "name": "authority",
+          "name": "event",
           "writable": true,
-          "signer": true
+          "relations": [
+            "sellerReceipt",
+            "offer"
+          ]
         },
         {
-          "name": "event",
+          "name": "vault",
+          "docs": [
+            "transfer fee"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  101,
                   118,
-                  101,
-                  110,
+                  97,
+                  117,
+                  108,
                   116
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "event"
               }
             ]
           }
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "data",
-          "type": {
-            "defined": {
-              "name": "amendEventFields"
-            }
+          "name": "platform",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              }
+            ]
           }
-        }
-      ]
-    },
-    {
-      "name": "burnTicket",
-      "discriminator": [
-        31,
-        250,
-        96,
-        233,
-        181,
-        137,
-        195,
-        87
-      ],
-      "accounts": [
+        },
         {
-          "name": "event"
+          "name": "treasury",
+          "writable": true
         },
         {
           "name": "mint",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -103,18 +104,21 @@ export type ChainTicket = {
           }
         },
         {
-          "name": "ticketHolder",
+          "name": "seller",
+          "docs": [
+            "Holder filling the offer, pays the rent of the buyer's receipt"
+          ],
           "writable": true,
           "signer": true
         },
         {
-          "name": "ticketHolderAta",
+          "name": "sellerAta",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "ticketHolder"
+                "path": "seller"
               },
               {
                 "kind": "const",
@@ -198,79 +202,40 @@ export type ChainTicket = {
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "buyTicket",
-      "discriminator": [
-        11,
-        24,
-        17,
-        193,
-        168,
-        116,
-        164,
-        169
-      ],
-      "accounts": [
-        {
-          "name": "event"
-        },
-        {
-          "name": "vault",
-          "docs": [
-            "in order to facilitate transfers from the vault",
-            "it must have no data and thus no discriminator."
-          ],
+          "name": "sellerReceipt",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  97,
-                  117,
-                  108,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
                   116
                 ]
               },
               {
                 "kind": "account",
                 "path": "event"
-              }
-            ]
-          }
-        },
-        {
-          "name": "mint",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  109,
-                  105,
-                  110,
-                  116
-                ]
               },
               {
                 "kind": "account",
-                "path": "event"
+                "path": "seller"
               }
             ]
           }
         },
+        {
+          "name": "sellerRentPayer",
+          "writable": true
+        },
         {
           "name": "buyer",
-          "writable": true,
-          "signer": true
+          "writable": true
         },
         {
           "name": "buyerAta",
@@ -362,6 +327,60 @@ export type ChainTicket = {
             }
           }
         },
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyerReceipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -369,31 +388,47 @@ export type ChainTicket = {
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         }
       ],
       "args": []
     },
     {
-      "name": "cancelEvent",
+      "name": "advanceFunds",
       "discriminator": [
-        55,
-        143,
-        36,
-        45,
-        59,
-        241,
-        89,
-        119
+        198,
+        117,
+        73,
+        173,
+        214,
+        239,
+        98,
+        6
       ],
       "accounts": [
         {
           "name": "platformOwner",
           "writable": true,
-          "address": "FAwHWq8AQzhw1vfCUEUnZuBZd5hKbR3EdybAtctX5ph"
+          "address": "AAFtJwAvDErpcDXVYotdqerUJHRkjNTGChMiAeuKMZVc"
+        },
+        {
+          "name": "platform",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              }
+            ]
+          }
         },
         {
           "name": "authority",
@@ -401,18 +436,19 @@ export type ChainTicket = {
           "signer": true
         },
         {
-          "name": "event",
-          "writable": true,
+          "name": "profile",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  101,
-                  118,
-                  101,
-                  110,
-                  116
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
                 ]
               },
               {
@@ -423,21 +459,23 @@ export type ChainTicket = {
           }
         },
         {
-          "name": "mint",
+          "name": "event",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  109,
-                  105,
+                  101,
+                  118,
+                  101,
                   110,
                   116
                 ]
               },
               {
                 "kind": "account",
-                "path": "event"
+                "path": "authority"
               }
             ]
           }
@@ -467,63 +505,78 @@ export type ChainTicket = {
               }
             ]
           }
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "delegateBurn",
+      "name": "agentIssueTicket",
       "discriminator": [
-        0,
-        224,
-        203,
-        248,
-        189,
-        129,
-        100,
-        99
+        31,
+        88,
+        114,
+        254,
+        89,
+        76,
+        168,
+        146
       ],
       "accounts": [
         {
-          "name": "authority",
+          "name": "event",
           "writable": true,
-          "signer": true
+          "relations": [
+            "salesAgent"
+          ]
         },
         {
-          "name": "event",
+          "name": "mint",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  101,
-                  118,
-                  101,
+                  109,
+                  105,
                   110,
                   116
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "event"
               }
             ]
           }
         },
         {
-          "name": "mint",
+          "name": "agent",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "salesAgent"
+          ]
+        },
+        {
+          "name": "salesAgent",
+          "docs": [
+            "Registration of the agent by the event authority, the only authorisation to issue"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  109,
-                  105,
+                  97,
+                  103,
+                  101,
                   110,
                   116
                 ]
@@ -531,21 +584,30 @@ export type ChainTicket = {
               {
                 "kind": "account",
                 "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "agent"
               }
             ]
           }
         },
         {
-          "name": "targetWallet"
+          "name": "customer",
+          "docs": [
+            "The customer only signs to approve the event as delegate on their token account, the",
+            "agent pays for the token account and receipt"
+          ],
+          "signer": true
         },
         {
-          "name": "targetAta",
+          "name": "customerAta",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "targetWallet"
+                "path": "customer"
               },
               {
                 "kind": "const",
@@ -629,121 +691,68 @@ export type ChainTicket = {
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "endEvent",
-      "discriminator": [
-        210,
-        72,
-        122,
-        58,
-        113,
-        167,
-        161,
-        20
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "event",
+          "name": "receipt",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  114,
                   101,
-                  118,
+                  99,
                   101,
-                  110,
+                  105,
+                  112,
                   116
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
-              }
-            ]
-          }
-        },
-        {
-          "name": "vault",
-          "docs": [
-            "in order to facilitate transfers from the vault",
-            "it must have no data and thus no discriminator."
-          ],
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  118,
-                  97,
-                  117,
-                  108,
-                  116
-                ]
+                "path": "event"
               },
               {
                 "kind": "account",
-                "path": "event"
+                "path": "customer"
               }
             ]
           }
         },
         {
-          "name": "mint",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  109,
-                  105,
-                  110,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "event"
-              }
-            ]
-          }
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "sessions",
+          "type": "u8"
+        },
+        {
+          "name": "declaredRevenue",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "initEvent",
+      "name": "amendEvent",
       "discriminator": [
-        187,
-        76,
-        29,
-        231,
-        45,
-        94,
-        249,
-        84
+        119,
+        33,
+        201,
+        211,
+        127,
+        27,
+        193,
+        97
       ],
       "accounts": [
         {
@@ -774,42 +783,41 @@ export type ChainTicket = {
           }
         },
         {
-          "name": "vault",
-          "docs": [
-            "in order to facilitate transfers from the vault",
-            "it must have no data and thus no discriminator."
-          ],
-          "writable": true,
+          "name": "platform",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  97,
-                  117,
+                  112,
                   108,
-                  116
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "event"
               }
             ]
           }
         },
         {
-          "name": "mint",
+          "name": "vault",
+          "docs": [
+            "in order to facilitate transfers from the vault",
+            "it must have no data and thus no discriminator."
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  109,
-                  105,
-                  110,
+                  118,
+                  97,
+                  117,
+                  108,
                   116
                 ]
               },
@@ -820,54 +828,9 @@ export type ChainTicket = {
             ]
           }
         },
-        {
-          "name": "metadata",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  109,
-                  101,
-                  116,
-                  97,
-                  100,
-                  97,
-                  116,
-                  97
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "tokenMetadataProgram"
-              },
-              {
-                "kind": "account",
-                "path": "mint"
-              }
-            ],
-            "program": {
-              "kind": "account",
-              "path": "tokenMetadataProgram"
-            }
-          }
-        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "tokenMetadataProgram",
-          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
         }
       ],
       "args": [
@@ -875,110 +838,69 @@ export type ChainTicket = {
           "name": "data",
           "type": {
             "defined": {
-              "name": "initEventFields"
+              "name": "amendEventFields"
             }
           }
         }
       ]
     },
     {
-      "name": "refundTicket",
+      "name": "burnTicket",
       "discriminator": [
-        178,
-        97,
-        75,
-        218,
-        227,
-        28,
-        21,
-        73
+        31,
+        250,
+        96,
+        233,
+        181,
+        137,
+        195,
+        87
       ],
       "accounts": [
         {
-          "name": "authority",
+          "name": "event",
           "writable": true,
-          "signer": true
+          "relations": [
+            "receipt"
+          ]
         },
         {
-          "name": "event",
+          "name": "mint",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  101,
-                  118,
-                  101,
+                  109,
+                  105,
                   110,
                   116
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "event"
               }
             ]
           }
         },
         {
-          "name": "vault",
+          "name": "ticketHolder",
           "docs": [
-            "in order to facilitate transfers from the vault",
-            "it must have no data and thus no discriminator."
+            "Signs for the burn and receives the token account rent"
           ],
           "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  118,
-                  97,
-                  117,
-                  108,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "event"
-              }
-            ]
-          }
+          "signer": true
         },
         {
-          "name": "mint",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  109,
-                  105,
-                  110,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "event"
-              }
-            ]
-          }
-        },
-        {
-          "name": "buyer",
-          "writable": true
-        },
-        {
-          "name": "buyerAta",
+          "name": "ticketHolderAta",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "buyer"
+                "path": "ticketHolder"
               },
               {
                 "kind": "const",
@@ -1061,6 +983,38 @@ export type ChainTicket = {
             }
           }
         },
+        {
+          "name": "receipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "ticketHolder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "writable": true
+        },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
@@ -1069,61 +1023,59 @@ export type ChainTicket = {
       "args": []
     },
     {
-      "name": "startSale",
+      "name": "buyGiftTicket",
       "discriminator": [
-        130,
-        69,
-        235,
+        133,
+        201,
+        148,
+        141,
+        23,
         113,
-        173,
-        219,
-        48,
-        228
+        211,
+        151
       ],
       "accounts": [
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true
+          "name": "event",
+          "writable": true
         },
         {
-          "name": "event",
+          "name": "vault",
+          "docs": [
+            "in order to facilitate transfers from the vault",
+            "it must have no data and thus no discriminator."
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  101,
                   118,
-                  101,
-                  110,
+                  97,
+                  117,
+                  108,
                   116
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "event"
               }
             ]
           }
         },
         {
-          "name": "vault",
-          "docs": [
-            "in order to facilitate transfers from the vault",
-            "it must have no data and thus no discriminator."
-          ],
+          "name": "mint",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  97,
-                  117,
-                  108,
+                  109,
+                  105,
+                  110,
                   116
                 ]
               },
@@ -1135,61 +1087,189 @@ export type ChainTicket = {
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "withdrawFunds",
-      "discriminator": [
-        241,
-        36,
-        29,
-        111,
-        208,
-        31,
-        104,
-        217
-      ],
-      "accounts": [
-        {
-          "name": "platformOwner",
+          "name": "payer",
           "writable": true,
-          "address": "FAwHWq8AQzhw1vfCUEUnZuBZd5hKbR3EdybAtctX5ph"
+          "signer": true
         },
         {
-          "name": "authority",
-          "writable": true,
+          "name": "recipient",
+          "docs": [
+            "The recipient only signs to approve the event as delegate on their token account, they",
+            "do not pay for anything"
+          ],
           "signer": true
         },
         {
-          "name": "event",
+          "name": "recipientAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "recipient"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "receipt",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  114,
                   101,
-                  118,
+                  99,
                   101,
-                  110,
+                  105,
+                  112,
                   116
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "recipient"
               }
             ]
           }
         },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessions",
+          "type": "u8"
+        },
+        {
+          "name": "refundRecipient",
+          "type": {
+            "defined": {
+              "name": "refundRecipient"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyListing",
+      "discriminator": [
+        115,
+        149,
+        42,
+        108,
+        44,
+        49,
+        140,
+        153
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "writable": true,
+          "relations": [
+            "listing",
+            "sellerReceipt"
+          ]
+        },
         {
           "name": "vault",
           "docs": [
-            "in order to facilitate transfers from the vault",
-            "it must have no data and thus no discriminator."
+            "transfer fee"
           ],
           "writable": true,
           "pda": {
@@ -1210,112 +1290,8010 @@ export type ChainTicket = {
               }
             ]
           }
-        }
-      ],
-      "args": []
+        },
+        {
+          "name": "platform",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "relations": [
+            "listing"
+          ]
+        },
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "listing"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "sellerReceipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sellerRentPayer",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "buyerAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "buyer"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "buyerReceipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "buyTicket",
+      "discriminator": [
+        11,
+        24,
+        17,
+        193,
+        168,
+        116,
+        164,
+        169
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "writable": true,
+          "relations": [
+            "hold",
+            "affiliate"
+          ]
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "in order to facilitate transfers from the vault",
+            "it must have no data and thus no discriminator."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platform",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "feePayer",
+          "docs": [
+            "Pays rent for the token account and receipt, either the buyer themselves or the",
+            "platform relayer sponsoring the purchase"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "buyerAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "buyer"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "receipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "hold",
+          "docs": [
+            "Optional hold placed by the buyer through `place_hold`, consumed by this purchase"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  111,
+                  108,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "affiliate",
+          "docs": [
+            "Optional affiliate the sale was referred by, accrues commission on the price"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  102,
+                  102,
+                  105,
+                  108,
+                  105,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "affiliate.wallet",
+                "account": "affiliate"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessions",
+          "type": "u8"
+        },
+        {
+          "name": "relayerFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cancelEvent",
+      "discriminator": [
+        55,
+        143,
+        36,
+        45,
+        59,
+        241,
+        89,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "platformOwner",
+          "writable": true,
+          "address": "AAFtJwAvDErpcDXVYotdqerUJHRkjNTGChMiAeuKMZVc"
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "event",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "in order to facilitate transfers from the vault",
+            "it must have no data and thus no discriminator."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelListing",
+      "discriminator": [
+        41,
+        183,
+        50,
+        232,
+        230,
+        233,
+        157,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "relations": [
+            "listing"
+          ]
+        },
+        {
+          "name": "mint",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "listing"
+          ]
+        },
+        {
+          "name": "sellerAta",
+          "docs": [
+            "Re-created if the seller closed it while the ticket was listed"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "listing"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelOffer",
+      "discriminator": [
+        92,
+        203,
+        223,
+        40,
+        92,
+        89,
+        53,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "docs": [
+            "was made"
+          ],
+          "writable": true,
+          "relations": [
+            "offer"
+          ]
+        },
+        {
+          "name": "caller",
+          "docs": [
+            "The buyer, or anyone once the event has started"
+          ],
+          "signer": true
+        },
+        {
+          "name": "buyer",
+          "writable": true
+        },
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "checkIn",
+      "discriminator": [
+        209,
+        253,
+        4,
+        217,
+        250,
+        241,
+        207,
+        50
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "writable": true,
+          "relations": [
+            "receipt",
+            "staff"
+          ]
+        },
+        {
+          "name": "mint",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "ticketHolder",
+          "signer": true
+        },
+        {
+          "name": "ticketHolderAta",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "ticketHolder"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "receipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "ticketHolder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "scanner",
+          "signer": true
+        },
+        {
+          "name": "staff",
+          "docs": [
+            "Staff account of the scanner, not required when the event authority scans"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  102,
+                  102
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "scanner"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "session",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "claimAttendance",
+      "discriminator": [
+        52,
+        220,
+        29,
+        53,
+        93,
+        49,
+        52,
+        101
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "relations": [
+            "receipt"
+          ]
+        },
+        {
+          "name": "ticketHolder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "receipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "ticketHolder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "attendanceMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  116,
+                  116,
+                  101,
+                  110,
+                  100,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "ticketHolderAttendanceAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "ticketHolder"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "attendanceMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimCheckIn",
+      "discriminator": [
+        146,
+        233,
+        152,
+        186,
+        218,
+        12,
+        179,
+        140
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "writable": true,
+          "relations": [
+            "batch",
+            "receipt"
+          ]
+        },
+        {
+          "name": "batch",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  101,
+                  99,
+                  107,
+                  95,
+                  105,
+                  110,
+                  95,
+                  98,
+                  97,
+                  116,
+                  99,
+                  104
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "batch.index",
+                "account": "checkInBatch"
+              }
+            ]
+          }
+        },
+        {
+          "name": "receipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "receipt.holder",
+                "account": "receipt"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "session",
+          "type": "u8"
+        },
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimCommission",
+      "discriminator": [
+        12,
+        9,
+        15,
+        170,
+        155,
+        235,
+        124,
+        254
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event.authority",
+                "account": "event"
+              }
+            ]
+          },
+          "relations": [
+            "affiliate"
+          ]
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "in order to facilitate transfers from the vault",
+            "it must have no data and thus no discriminator."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "affiliate",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  102,
+                  102,
+                  105,
+                  108,
+                  105,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "relations": [
+            "affiliate"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimVested",
+      "discriminator": [
+        208,
+        190,
+        166,
+        114,
+        203,
+        225,
+        140,
+        208
+      ],
+      "accounts": [
+        {
+          "name": "platformOwner",
+          "writable": true,
+          "address": "AAFtJwAvDErpcDXVYotdqerUJHRkjNTGChMiAeuKMZVc"
+        },
+        {
+          "name": "platform",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "profile",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "event",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "in order to facilitate transfers from the vault",
+            "it must have no data and thus no discriminator."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeWaitlist",
+      "discriminator": [
+        166,
+        123,
+        207,
+        193,
+        131,
+        254,
+        227,
+        184
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "event",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          },
+          "relations": [
+            "waitlist"
+          ]
+        },
+        {
+          "name": "waitlist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  97,
+                  105,
+                  116,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "delegateBurn",
+      "discriminator": [
+        0,
+        224,
+        203,
+        248,
+        189,
+        129,
+        100,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "event",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          },
+          "relations": [
+            "receipt"
+          ]
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetWallet"
+        },
+        {
+          "name": "targetAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "targetWallet"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "receipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "targetWallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "endEvent",
+      "discriminator": [
+        210,
+        72,
+        122,
+        58,
+        113,
+        167,
+        161,
+        20
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "event",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "in order to facilitate transfers from the vault",
+            "it must have no data and thus no discriminator."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "evictWaitlistHead",
+      "discriminator": [
+        228,
+        215,
+        24,
+        194,
+        64,
+        197,
+        45,
+        139
+      ],
+      "accounts": [
+        {
+          "name": "operator",
+          "docs": [
+            "Anyone once the head's token account has been closed, otherwise the event authority"
+          ],
+          "signer": true
+        },
+        {
+          "name": "event",
+          "writable": true,
+          "relations": [
+            "waitlist",
+            "receipt"
+          ]
+        },
+        {
+          "name": "waitlist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  97,
+                  105,
+                  116,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet",
+          "writable": true
+        },
+        {
+          "name": "walletAta"
+        },
+        {
+          "name": "receipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "expireHold",
+      "discriminator": [
+        180,
+        235,
+        181,
+        215,
+        239,
+        218,
+        104,
+        166
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "writable": true,
+          "relations": [
+            "hold"
+          ]
+        },
+        {
+          "name": "holder",
+          "writable": true
+        },
+        {
+          "name": "hold",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  111,
+                  108,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "holder"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "flagEvent",
+      "discriminator": [
+        126,
+        238,
+        71,
+        248,
+        150,
+        165,
+        31,
+        35
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "platform",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "event",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "flagged",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "grantRole",
+      "discriminator": [
+        218,
+        234,
+        128,
+        15,
+        82,
+        33,
+        236,
+        253
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "event",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "staffKey"
+        },
+        {
+          "name": "staff",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  102,
+                  102
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "staffKey"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "permissions",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "initAttendance",
+      "discriminator": [
+        6,
+        75,
+        39,
+        7,
+        112,
+        194,
+        92,
+        197
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "event",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "attendanceMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  116,
+                  116,
+                  101,
+                  110,
+                  100,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "metadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMetadataProgram"
+              },
+              {
+                "kind": "account",
+                "path": "attendanceMint"
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "tokenMetadataProgram"
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": {
+            "defined": {
+              "name": "initAttendanceFields"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initEvent",
+      "discriminator": [
+        187,
+        76,
+        29,
+        231,
+        45,
+        94,
+        249,
+        84
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "event",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "in order to facilitate transfers from the vault",
+            "it must have no data and thus no discriminator."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "metadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMetadataProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "tokenMetadataProgram"
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": {
+            "defined": {
+              "name": "initEventFields"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initPlatform",
+      "discriminator": [
+        29,
+        22,
+        210,
+        225,
+        219,
+        114,
+        193,
+        169
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "address": "AAFtJwAvDErpcDXVYotdqerUJHRkjNTGChMiAeuKMZVc"
+        },
+        {
+          "name": "platform",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": {
+            "defined": {
+              "name": "initPlatformFields"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initProfile",
+      "discriminator": [
+        210,
+        162,
+        212,
+        95,
+        95,
+        186,
+        89,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initWaitlist",
+      "discriminator": [
+        123,
+        46,
+        13,
+        91,
+        57,
+        139,
+        121,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "event",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "waitlist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  97,
+                  105,
+                  116,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "issueCompTicket",
+      "discriminator": [
+        132,
+        193,
+        24,
+        49,
+        213,
+        167,
+        151,
+        116
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "event",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "guest",
+          "docs": [
+            "The guest only signs to approve the event as delegate on their token account, the",
+            "organiser pays for everything"
+          ],
+          "signer": true
+        },
+        {
+          "name": "guestAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "guest"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "receipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "guest"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "sessions",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "joinWaitlist",
+      "discriminator": [
+        44,
+        90,
+        151,
+        255,
+        199,
+        17,
+        177,
+        44
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "writable": true,
+          "relations": [
+            "waitlist"
+          ]
+        },
+        {
+          "name": "waitlist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  97,
+                  105,
+                  116,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "walletAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "wallet"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "receipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "leaveWaitlist",
+      "discriminator": [
+        115,
+        211,
+        169,
+        27,
+        12,
+        153,
+        253,
+        122
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "docs": [
+            "wallet joined"
+          ],
+          "writable": true,
+          "relations": [
+            "waitlist",
+            "receipt"
+          ]
+        },
+        {
+          "name": "waitlist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  97,
+                  105,
+                  116,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "receipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "listTicket",
+      "discriminator": [
+        11,
+        213,
+        240,
+        45,
+        246,
+        35,
+        44,
+        162
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "relations": [
+            "receipt"
+          ]
+        },
+        {
+          "name": "mint",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "sellerAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "receipt",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "listing",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "seller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "listing"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "makeOffer",
+      "discriminator": [
+        214,
+        98,
+        97,
+        35,
+        59,
+        12,
+        44,
+        178
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "buyerAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "buyer"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "sessions",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "placeHold",
+      "discriminator": [
+        26,
+        142,
+        195,
+        169,
+        37,
+        133,
+        174,
+        185
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "writable": true
+        },
+        {
+          "name": "holder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "hold",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  111,
+                  108,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "holder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refundFlagged",
+      "discriminator": [
+        77,
+        140,
+        216,
+        190,
+        226,
+        58,
+        191,
+        219
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event.authority",
+                "account": "event"
+              }
+            ]
+          },
+          "relations": [
+            "receipt"
+          ]
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "in order to facilitate transfers from the vault",
+            "it must have no data and thus no discriminator."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "holder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "holderAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "holder"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "receipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "holder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "writable": true
+        },
+        {
+          "name": "rentPayer",
+          "writable": true
+        },
+        {
+          "name": "affiliate",
+          "docs": [
+            "Affiliate credited with the sale, required if the receipt records a commission"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refundTicket",
+      "discriminator": [
+        178,
+        97,
+        75,
+        218,
+        227,
+        28,
+        21,
+        73
+      ],
+      "accounts": [
+        {
+          "name": "operator",
+          "docs": [
+            "The event authority or a staff key holding the refund agent permission"
+          ],
+          "signer": true
+        },
+        {
+          "name": "staff",
+          "docs": [
+            "Staff account of the operator, not required when the event authority refunds"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  102,
+                  102
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "operator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "event",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event.authority",
+                "account": "event"
+              }
+            ]
+          },
+          "relations": [
+            "staff",
+            "receipt"
+          ]
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "in order to facilitate transfers from the vault",
+            "it must have no data and thus no discriminator."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "buyer",
+          "writable": true
+        },
+        {
+          "name": "buyerAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "buyer"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "receipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "writable": true
+        },
+        {
+          "name": "rentPayer",
+          "writable": true
+        },
+        {
+          "name": "affiliate",
+          "docs": [
+            "Affiliate credited with the sale, required if the receipt records a commission"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerAffiliate",
+      "discriminator": [
+        87,
+        121,
+        99,
+        184,
+        126,
+        63,
+        103,
+        217
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "event",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet"
+        },
+        {
+          "name": "affiliate",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  102,
+                  102,
+                  105,
+                  108,
+                  105,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "wallet"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "commissionBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "registerAgent",
+      "discriminator": [
+        135,
+        157,
+        66,
+        195,
+        2,
+        113,
+        175,
+        30
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "event",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agent"
+        },
+        {
+          "name": "salesAgent",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  103,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "agent"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "quota",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "revokeRole",
+      "discriminator": [
+        179,
+        232,
+        2,
+        180,
+        48,
+        227,
+        82,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "event",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          },
+          "relations": [
+            "staff"
+          ]
+        },
+        {
+          "name": "staff",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  102,
+                  102
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "staff"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "permissions",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "serveWaitlist",
+      "discriminator": [
+        98,
+        186,
+        58,
+        75,
+        173,
+        223,
+        67,
+        29
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "writable": true,
+          "relations": [
+            "waitlist"
+          ]
+        },
+        {
+          "name": "waitlist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  97,
+                  105,
+                  116,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "in order to facilitate transfers from the vault",
+            "it must have no data and thus no discriminator."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wallet"
+        },
+        {
+          "name": "walletAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "wallet"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setFeeExemption",
+      "discriminator": [
+        49,
+        221,
+        99,
+        185,
+        22,
+        228,
+        186,
+        160
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "platform",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "profile.authority",
+                "account": "organiserProfile"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "exempt",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setFeeTiers",
+      "discriminator": [
+        162,
+        35,
+        72,
+        250,
+        39,
+        183,
+        30,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "platform",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "tiers",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "feeTier"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPayees",
+      "discriminator": [
+        92,
+        10,
+        255,
+        107,
+        111,
+        30,
+        22,
+        33
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "event",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "payees",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "payee"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setSessions",
+      "discriminator": [
+        160,
+        105,
+        254,
+        224,
+        158,
+        224,
+        85,
+        62
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "event",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "sessions",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "sessionFields"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "settleCheckIns",
+      "discriminator": [
+        243,
+        128,
+        35,
+        46,
+        84,
+        205,
+        7,
+        191
+      ],
+      "accounts": [
+        {
+          "name": "scanner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "staff",
+          "docs": [
+            "Staff account of the scanner, not required when the event authority settles"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  102,
+                  102
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "scanner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "event",
+          "writable": true,
+          "relations": [
+            "staff"
+          ]
+        },
+        {
+          "name": "batch",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  101,
+                  99,
+                  107,
+                  95,
+                  105,
+                  110,
+                  95,
+                  98,
+                  97,
+                  116,
+                  99,
+                  104
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "event.check_in_batches",
+                "account": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "count",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "startSale",
+      "discriminator": [
+        130,
+        69,
+        235,
+        113,
+        173,
+        219,
+        48,
+        228
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "event",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platform",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "in order to facilitate transfers from the vault",
+            "it must have no data and thus no discriminator."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "swapSessions",
+      "discriminator": [
+        78,
+        43,
+        7,
+        250,
+        241,
+        255,
+        238,
+        3
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "writable": true,
+          "relations": [
+            "receiptA",
+            "receiptB"
+          ]
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "holderA",
+          "docs": [
+            "Pays the transfer fee for giving up ticket A"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "holderB",
+          "docs": [
+            "Pays the transfer fee for giving up ticket B"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "holderATicket",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "holderA"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "holderBTicket",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "holderB"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "receiptA",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "holderA"
+              }
+            ]
+          }
+        },
+        {
+          "name": "receiptB",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "holderB"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "swapTickets",
+      "discriminator": [
+        35,
+        64,
+        135,
+        86,
+        5,
+        112,
+        182,
+        115
+      ],
+      "accounts": [
+        {
+          "name": "eventA",
+          "writable": true
+        },
+        {
+          "name": "vaultA",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "eventA"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mintA",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "eventA"
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventB",
+          "writable": true
+        },
+        {
+          "name": "vaultB",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "eventB"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mintB",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "eventB"
+              }
+            ]
+          }
+        },
+        {
+          "name": "holderA",
+          "docs": [
+            "Holds a ticket for event A, pays event A's transfer fee and the rent of the accounts",
+            "receiving ticket B"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "holderB",
+          "docs": [
+            "Holds a ticket for event B, pays event B's transfer fee and the rent of the accounts",
+            "receiving ticket A"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "holderATicket",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "holderA"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mintA"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "holderBTicket",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "holderB"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mintB"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "receiptA",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "eventA"
+              },
+              {
+                "kind": "account",
+                "path": "holderA"
+              }
+            ]
+          }
+        },
+        {
+          "name": "receiptB",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "eventB"
+              },
+              {
+                "kind": "account",
+                "path": "holderB"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayerA",
+          "writable": true
+        },
+        {
+          "name": "rentPayerB",
+          "writable": true
+        },
+        {
+          "name": "holderANewTicket",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "holderA"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mintB"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "holderBNewTicket",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "holderB"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mintA"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "holderANewReceipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "eventB"
+              },
+              {
+                "kind": "account",
+                "path": "holderA"
+              }
+            ]
+          }
+        },
+        {
+          "name": "holderBNewReceipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "eventA"
+              },
+              {
+                "kind": "account",
+                "path": "holderB"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferTicket",
+      "discriminator": [
+        191,
+        184,
+        74,
+        239,
+        164,
+        172,
+        188,
+        32
+      ],
+      "accounts": [
+        {
+          "name": "event",
+          "writable": true,
+          "relations": [
+            "senderReceipt"
+          ]
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sender",
+          "docs": [
+            "Current holder, pays the transfer fee and the rent of the recipient's accounts"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "senderAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "sender"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "senderReceipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "sender"
+              }
+            ]
+          }
+        },
+        {
+          "name": "senderRentPayer",
+          "writable": true
+        },
+        {
+          "name": "recipient",
+          "docs": [
+            "Signs to approve the event as delegate of their token account"
+          ],
+          "signer": true
+        },
+        {
+          "name": "recipientAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "recipient"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "recipientReceipt",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "recipient"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateMetadata",
+      "discriminator": [
+        170,
+        182,
+        43,
+        239,
+        97,
+        78,
+        225,
+        186
+      ],
+      "accounts": [
+        {
+          "name": "editor",
+          "signer": true
+        },
+        {
+          "name": "staff",
+          "docs": [
+            "Staff account of the editor, not required when the event authority updates"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  102,
+                  102
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              },
+              {
+                "kind": "account",
+                "path": "editor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "event",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event.authority",
+                "account": "event"
+              }
+            ]
+          },
+          "relations": [
+            "staff"
+          ]
+        },
+        {
+          "name": "mint",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          },
+          "relations": [
+            "event"
+          ]
+        },
+        {
+          "name": "metadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "tokenMetadataProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "tokenMetadataProgram"
+            }
+          }
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": {
+            "defined": {
+              "name": "updateMetadataFields"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updatePlatform",
+      "discriminator": [
+        46,
+        78,
+        138,
+        189,
+        47,
+        163,
+        120,
+        85
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "platform",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": {
+            "defined": {
+              "name": "updatePlatformFields"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawFunds",
+      "discriminator": [
+        241,
+        36,
+        29,
+        111,
+        208,
+        31,
+        104,
+        217
+      ],
+      "accounts": [
+        {
+          "name": "platformOwner",
+          "writable": true,
+          "address": "AAFtJwAvDErpcDXVYotdqerUJHRkjNTGChMiAeuKMZVc"
+        },
+        {
+          "name": "platform",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "profile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "event",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "in order to facilitate transfers from the vault",
+            "it must have no data and thus no discriminator."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "event"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
     }
   ],
   "accounts": [
     {
-      "name": "event",
-      "discriminator": [
-        125,
-        192,
-        125,
-        158,
-        9,
-        115,
-        152,
-        233
-      ]
+      "name": "affiliate",
+      "discriminator": [
+        136,
+        95,
+        107,
+        149,
+        36,
+        195,
+        146,
+        35
+      ]
+    },
+    {
+      "name": "checkInBatch",
+      "discriminator": [
+        237,
+        135,
+        57,
+        153,
+        211,
+        55,
+        67,
+        187
+      ]
+    },
+    {
+      "name": "event",
+      "discriminator": [
+        125,
+        192,
+        125,
+        158,
+        9,
+        115,
+        152,
+        233
+      ]
+    },
+    {
+      "name": "hold",
+      "discriminator": [
+        110,
+        65,
+        238,
+        142,
+        146,
+        91,
+        196,
+        171
+      ]
+    },
+    {
+      "name": "listing",
+      "discriminator": [
+        218,
+        32,
+        50,
+        73,
+        43,
+        134,
+        26,
+        58
+      ]
+    },
+    {
+      "name": "offer",
+      "discriminator": [
+        215,
+        88,
+        60,
+        71,
+        170,
+        162,
+        73,
+        229
+      ]
+    },
+    {
+      "name": "organiserProfile",
+      "discriminator": [
+        38,
+        192,
+        210,
+        244,
+        104,
+        164,
+        46,
+        18
+      ]
+    },
+    {
+      "name": "platformConfig",
+      "discriminator": [
+        160,
+        78,
+        128,
+        0,
+        248,
+        83,
+        230,
+        160
+      ]
+    },
+    {
+      "name": "receipt",
+      "discriminator": [
+        39,
+        154,
+        73,
+        106,
+        80,
+        102,
+        145,
+        153
+      ]
+    },
+    {
+      "name": "salesAgent",
+      "discriminator": [
+        122,
+        67,
+        119,
+        41,
+        253,
+        225,
+        61,
+        212
+      ]
+    },
+    {
+      "name": "staff",
+      "discriminator": [
+        116,
+        23,
+        32,
+        52,
+        10,
+        46,
+        140,
+        21
+      ]
+    },
+    {
+      "name": "waitlist",
+      "discriminator": [
+        154,
+        192,
+        138,
+        217,
+        79,
+        229,
+        115,
+        79
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "feeCalculationError",
+      "msg": "Error calculating platform fee"
+    },
+    {
+      "code": 6001,
+      "name": "maxTicketsExceeded",
+      "msg": "Max tickets sold"
+    },
+    {
+      "code": 6002,
+      "name": "unauthorised",
+      "msg": "Unauthorised access"
+    },
+    {
+      "code": 6003,
+      "name": "nonZeroSupply",
+      "msg": "Mint supply is not zero"
+    },
+    {
+      "code": 6004,
+      "name": "saleNotStarted",
+      "msg": "Sale has not started"
+    },
+    {
+      "code": 6005,
+      "name": "eventNotEnded",
+      "msg": "Event has not ended"
+    },
+    {
+      "code": 6006,
+      "name": "pubkeyParseError",
+      "msg": "Could not parse pubkey"
+    },
+    {
+      "code": 6007,
+      "name": "incorrectPlatformOwner",
+      "msg": "Incorrect platform owner address"
+    },
+    {
+      "code": 6008,
+      "name": "overflow",
+      "msg": "Amount overflow"
+    },
+    {
+      "code": 6009,
+      "name": "invalidMint",
+      "msg": "Invalid mint address"
+    },
+    {
+      "code": 6010,
+      "name": "invalidVault",
+      "msg": "Invalid vault address"
+    },
+    {
+      "code": 6011,
+      "name": "alreadyPurchased",
+      "msg": "User has already purchased a ticket"
+    },
+    {
+      "code": 6012,
+      "name": "ticketsAvailable",
+      "msg": "Tickets are still available"
+    },
+    {
+      "code": 6013,
+      "name": "waitlistFull",
+      "msg": "Waitlist is full"
+    },
+    {
+      "code": 6014,
+      "name": "alreadyWaitlisted",
+      "msg": "Wallet is already on the waitlist"
+    },
+    {
+      "code": 6015,
+      "name": "notWaitlisted",
+      "msg": "Wallet is not on the waitlist"
+    },
+    {
+      "code": 6016,
+      "name": "noWaitlistOffer",
+      "msg": "No tickets have been freed for the waitlist"
+    },
+    {
+      "code": 6017,
+      "name": "notWaitlistHead",
+      "msg": "Wallet is not at the head of the waitlist"
+    },
+    {
+      "code": 6018,
+      "name": "waitlistNotEmpty",
+      "msg": "Waitlist is not empty"
+    },
+    {
+      "code": 6019,
+      "name": "holdExpired",
+      "msg": "Hold has expired"
+    },
+    {
+      "code": 6020,
+      "name": "holdNotExpired",
+      "msg": "Hold has not expired"
+    },
+    {
+      "code": 6021,
+      "name": "invalidHoldTtl",
+      "msg": "Invalid hold duration"
+    },
+    {
+      "code": 6022,
+      "name": "invalidReceiptPayer",
+      "msg": "Invalid receipt payer"
+    },
+    {
+      "code": 6023,
+      "name": "relayerFeeExceeded",
+      "msg": "Relayer fee exceeds the platform cap"
+    },
+    {
+      "code": 6024,
+      "name": "compQuotaExceeded",
+      "msg": "Complimentary ticket quota exceeded"
+    },
+    {
+      "code": 6025,
+      "name": "compNotRefundable",
+      "msg": "Complimentary tickets cannot be refunded"
+    },
+    {
+      "code": 6026,
+      "name": "agentQuotaExceeded",
+      "msg": "Sales agent quota exceeded"
+    },
+    {
+      "code": 6027,
+      "name": "alreadyCheckedIn",
+      "msg": "Ticket has already been checked in"
+    },
+    {
+      "code": 6028,
+      "name": "noTicket",
+      "msg": "Wallet does not hold a ticket"
+    },
+    {
+      "code": 6029,
+      "name": "invalidSessions",
+      "msg": "Invalid session selection"
+    },
+    {
+      "code": 6030,
+      "name": "sessionSoldOut",
+      "msg": "Session is sold out"
+    },
+    {
+      "code": 6031,
+      "name": "notEntitled",
+      "msg": "Ticket is not valid for this session"
+    },
+    {
+      "code": 6032,
+      "name": "sessionNotOpen",
+      "msg": "Session is not open for check-in"
+    },
+    {
+      "code": 6033,
+      "name": "saleStarted",
+      "msg": "Sessions cannot be changed once sales have started"
+    },
+    {
+      "code": 6034,
+      "name": "notCheckedIn",
+      "msg": "Ticket has not been checked in"
+    },
+    {
+      "code": 6035,
+      "name": "attendanceClaimed",
+      "msg": "Proof of attendance already claimed"
+    },
+    {
+      "code": 6036,
+      "name": "invalidCheckInProof",
+      "msg": "Invalid check-in proof"
+    },
+    {
+      "code": 6037,
+      "name": "resaleDisabled",
+      "msg": "Resale is disabled for this event"
+    },
+    {
+      "code": 6038,
+      "name": "resalePriceExceeded",
+      "msg": "Price exceeds the resale cap"
+    },
+    {
+      "code": 6039,
+      "name": "eventStarted",
+      "msg": "Event has already started"
+    },
+    {
+      "code": 6040,
+      "name": "invalidBasisPoints",
+      "msg": "Basis points exceed the allowed total"
+    },
+    {
+      "code": 6041,
+      "name": "transfersDisabled",
+      "msg": "Transfers are disabled for this event"
+    },
+    {
+      "code": 6042,
+      "name": "transferLockedOut",
+      "msg": "Transfers are locked ahead of the event"
+    },
+    {
+      "code": 6043,
+      "name": "transferLimitReached",
+      "msg": "Ticket has reached the maximum number of transfers"
+    },
+    {
+      "code": 6044,
+      "name": "invalidSwap",
+      "msg": "Swapped tickets are not valid for this instruction"
+    },
+    {
+      "code": 6045,
+      "name": "invalidDelegate",
+      "msg": "Event is not set as delegate of the token account"
+    },
+    {
+      "code": 6046,
+      "name": "offerMismatch",
+      "msg": "Ticket does not match the offer"
+    },
+    {
+      "code": 6047,
+      "name": "invalidPayees",
+      "msg": "Payee shares must add up to the whole of the proceeds"
+    },
+    {
+      "code": 6048,
+      "name": "advanceExceeded",
+      "msg": "Amount exceeds the proceeds available for an advance"
+    },
+    {
+      "code": 6049,
+      "name": "eventFlagged",
+      "msg": "Event has been flagged by the platform"
+    },
+    {
+      "code": 6050,
+      "name": "eventNotFlagged",
+      "msg": "Event has not been flagged by the platform"
+    },
+    {
+      "code": 6051,
+      "name": "nothingVested",
+      "msg": "No vested proceeds to claim"
+    },
+    {
+      "code": 6052,
+      "name": "fundsNotWithdrawn",
+      "msg": "Event proceeds have not been withdrawn"
+    },
+    {
+      "code": 6053,
+      "name": "invalidDepositBounds",
+      "msg": "Minimum deposit exceeds the maximum deposit"
+    },
+    {
+      "code": 6054,
+      "name": "invalidFeeTiers",
+      "msg": "Fee tiers must be in ascending order of volume"
+    },
+    {
+      "code": 6055,
+      "name": "invalidAffiliate",
+      "msg": "Affiliate does not match the ticket or purchase"
+    },
+    {
+      "code": 6056,
+      "name": "nothingAccrued",
+      "msg": "No commission to pay out"
+    },
+    {
+      "code": 6057,
+      "name": "eventBroughtForward",
+      "msg": "The event date cannot be brought forward once sales have started"
+    },
+    {
+      "code": 6058,
+      "name": "invalidTokenAccount",
+      "msg": "Token account is not the wallet's associated token account"
+    },
+    {
+      "code": 6059,
+      "name": "priceBelowFees",
+      "msg": "Price does not cover the royalties and transfer fee"
+    },
+    {
+      "code": 6060,
+      "name": "refundExceedsProceeds",
+      "msg": "Refund exceeds the proceeds left in the vault"
+    },
+    {
+      "code": 6061,
+      "name": "offersOpen",
+      "msg": "Offers for the event have not been filled or cancelled"
+    },
+    {
+      "code": 6062,
+      "name": "insufficientVaultBalance",
+      "msg": "Vault holds less than the ledger records"
+    },
+    {
+      "code": 6063,
+      "name": "holdLimitReached",
+      "msg": "Too many of the event's tickets are held"
+    },
+    {
+      "code": 6064,
+      "name": "commissionsUnpaid",
+      "msg": "Affiliate commissions are still owed"
     }
   ],
-  "errors": [
+  "types": [
     {
-      "code": 6000,
-      "name": "feeCalculationError",
-      "msg": "Error calculating platform fee"
+      "name": "affiliate",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "event",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "commissionBps",
+            "type": "u16"
+          },
+          {
+            "name": "sales",
+            "type": "u32"
+          },
+          {
+            "name": "accrued",
+            "type": "u64"
+          },
+          {
+            "name": "paid",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "amendEventFields",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "eventDate",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "ticketPrice",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "numTickets",
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "holdTtl",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "compQuota",
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "resaleCapBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "resaleRoyaltyBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "transfersEnabled",
+            "type": {
+              "option": "bool"
+            }
+          },
+          {
+            "name": "transferLockout",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "maxTransfers",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "transferFee",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "checkInBatch",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "event",
+            "type": "pubkey"
+          },
+          {
+            "name": "scanner",
+            "type": "pubkey"
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "count",
+            "type": "u32"
+          },
+          {
+            "name": "settledAt",
+            "type": "i64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "event",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "allowPurchase",
+            "type": "bool"
+          },
+          {
+            "name": "eventDate",
+            "type": "i64"
+          },
+          {
+            "name": "ticketPrice",
+            "type": "u64"
+          },
+          {
+            "name": "refundPeriod",
+            "type": "i64"
+          },
+          {
+            "name": "numTickets",
+            "type": "u32"
+          },
+          {
+            "name": "waitlisted",
+            "type": "u32"
+          },
+          {
+            "name": "waitlistOffers",
+            "type": "u32"
+          },
+          {
+            "name": "heldTickets",
+            "type": "u32"
+          },
+          {
+            "name": "holdTtl",
+            "type": "i64"
+          },
+          {
+            "name": "compQuota",
+            "type": "u32"
+          },
+          {
+            "name": "compsIssued",
+            "type": "u32"
+          },
+          {
+            "name": "checkInBatches",
+            "type": "u32"
+          },
+          {
+            "name": "settledCheckIns",
+            "type": "u32"
+          },
+          {
+            "name": "resaleCapBps",
+            "type": "u16"
+          },
+          {
+            "name": "resaleRoyaltyBps",
+            "type": "u16"
+          },
+          {
+            "name": "organiserRoyalties",
+            "type": "u64"
+          },
+          {
+            "name": "platformRoyalties",
+            "type": "u64"
+          },
+          {
+            "name": "transfersEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "transferLockout",
+            "type": "i64"
+          },
+          {
+            "name": "maxTransfers",
+            "type": "u8"
+          },
+          {
+            "name": "transferFee",
+            "type": "u64"
+          },
+          {
+            "name": "transferFees",
+            "type": "u64"
+          },
+          {
+            "name": "checkedInProceeds",
+            "type": "u64"
+          },
+          {
+            "name": "advanced",
+            "type": "u64"
+          },
+          {
+            "name": "vestingPeriod",
+            "type": "i64"
+          },
+          {
+            "name": "vestingTotal",
+            "type": "u64"
+          },
+          {
+            "name": "vestedClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "flagged",
+            "type": "bool"
+          },
+          {
+            "name": "grossSales",
+            "type": "u64"
+          },
+          {
+            "name": "refundsPaid",
+            "type": "u64"
+          },
+          {
+            "name": "withdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "feesPaid",
+            "type": "u64"
+          },
+          {
+            "name": "ticketsSold",
+            "type": "u32"
+          },
+          {
+            "name": "ticketsRefunded",
+            "type": "u32"
+          },
+          {
+            "name": "ticketsBurned",
+            "type": "u32"
+          },
+          {
+            "name": "ticketsCheckedIn",
+            "type": "u32"
+          },
+          {
+            "name": "deposit",
+            "type": "u64"
+          },
+          {
+            "name": "creditedVolume",
+            "type": "u64"
+          },
+          {
+            "name": "commissionsAccrued",
+            "type": "u64"
+          },
+          {
+            "name": "commissionsPaid",
+            "type": "u64"
+          },
+          {
+            "name": "openOffers",
+            "type": "u32"
+          },
+          {
+            "name": "attendanceTransferable",
+            "type": "bool"
+          },
+          {
+            "name": "sessions",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "session"
+                }
+              }
+            }
+          },
+          {
+            "name": "payees",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "payee"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "maxTicketsExceeded",
-      "msg": "Max tickets sold"
+      "name": "feeTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minVolume",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "unauthorised",
-      "msg": "Unauthorised access"
+      "name": "hold",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "event",
+            "type": "pubkey"
+          },
+          {
+            "name": "holder",
+            "type": "pubkey"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "nonZeroSupply",
-      "msg": "Mint supply is not zero"
+      "name": "initAttendanceFields",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "transferable",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "eventNotEnded",
-      "msg": "Event has not ended"
+      "name": "initEventFields",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "eventName",
+            "type": "string"
+          },
+          {
+            "name": "eventSymbol",
+            "type": "string"
+          },
+          {
+            "name": "imageUri",
+            "type": "string"
+          },
+          {
+            "name": "metadataUri",
+            "type": "string"
+          },
+          {
+            "name": "eventDate",
+            "type": "i64"
+          },
+          {
+            "name": "ticketPrice",
+            "type": "u64"
+          },
+          {
+            "name": "numTickets",
+            "type": "u32"
+          },
+          {
+            "name": "refundPeriod",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "pubkeyParseError",
-      "msg": "Could not parse pubkey"
+      "name": "initPlatformFields",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "relayer",
+            "type": "pubkey"
+          },
+          {
+            "name": "relayerFeeCap",
+            "type": "u64"
+          },
+          {
+            "name": "treasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "resaleRoyaltyBps",
+            "type": "u16"
+          },
+          {
+            "name": "advanceBps",
+            "type": "u16"
+          },
+          {
+            "name": "vestingPeriod",
+            "type": "i64"
+          },
+          {
+            "name": "depositBps",
+            "type": "u16"
+          },
+          {
+            "name": "depositMin",
+            "type": "u64"
+          },
+          {
+            "name": "depositMax",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "incorrectPlatformOwner",
-      "msg": "Incorrect platform owner address"
+      "name": "listing",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "event",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "overflow",
-      "msg": "Amount overflow"
+      "name": "offer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "event",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "sessions",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "invalidMint",
-      "msg": "Invalid mint address"
+      "name": "organiserProfile",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "lifetimeGross",
+            "type": "u64"
+          },
+          {
+            "name": "feeExempt",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "invalidVault",
-      "msg": "Invalid vault address"
+      "name": "payee",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "shareBps",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "alreadyPurchased",
-      "msg": "User has already purchased a ticket"
-    }
-  ],
-  "types": [
-    {
-      "name": "amendEventFields",
+      "name": "platformConfig",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "eventDate",
-            "type": {
-              "option": "i64"
-            }
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "ticketPrice",
-            "type": {
-              "option": "u64"
-            }
+            "name": "authority",
+            "type": "pubkey"
           },
           {
-            "name": "numTickets",
+            "name": "relayer",
+            "type": "pubkey"
+          },
+          {
+            "name": "relayerFeeCap",
+            "type": "u64"
+          },
+          {
+            "name": "treasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "resaleRoyaltyBps",
+            "type": "u16"
+          },
+          {
+            "name": "advanceBps",
+            "type": "u16"
+          },
+          {
+            "name": "vestingPeriod",
+            "type": "i64"
+          },
+          {
+            "name": "depositBps",
+            "type": "u16"
+          },
+          {
+            "name": "depositMin",
+            "type": "u64"
+          },
+          {
+            "name": "depositMax",
+            "type": "u64"
+          },
+          {
+            "name": "feeTiers",
             "type": {
-              "option": "u32"
+              "vec": {
+                "defined": {
+                  "name": "feeTier"
+                }
+              }
             }
           }
         ]
       }
     },
     {
-      "name": "event",
+      "name": "receipt",
       "type": {
         "kind": "struct",
         "fields": [
@@ -1324,42 +9302,192 @@ export type ChainTicket = {
             "type": "u8"
           },
           {
-            "name": "authority",
+            "name": "event",
             "type": "pubkey"
           },
           {
-            "name": "vault",
+            "name": "payer",
             "type": "pubkey"
           },
           {
-            "name": "mint",
+            "name": "rentPayer",
             "type": "pubkey"
           },
           {
-            "name": "allowPurchase",
+            "name": "holder",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "refundRecipient",
+            "type": {
+              "defined": {
+                "name": "refundRecipient"
+              }
+            }
+          },
+          {
+            "name": "comp",
             "type": "bool"
           },
           {
-            "name": "eventDate",
+            "name": "checkedInAt",
             "type": "i64"
           },
           {
-            "name": "ticketPrice",
+            "name": "sessions",
+            "type": "u8"
+          },
+          {
+            "name": "checkedInSessions",
+            "type": "u8"
+          },
+          {
+            "name": "attendanceClaimed",
+            "type": "bool"
+          },
+          {
+            "name": "transfers",
+            "type": "u8"
+          },
+          {
+            "name": "affiliate",
+            "type": "pubkey"
+          },
+          {
+            "name": "commission",
             "type": "u64"
           },
           {
-            "name": "refundPeriod",
+            "name": "issuedSlot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "refundRecipient",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "payer"
+          },
+          {
+            "name": "holder"
+          }
+        ]
+      }
+    },
+    {
+      "name": "salesAgent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "event",
+            "type": "pubkey"
+          },
+          {
+            "name": "agent",
+            "type": "pubkey"
+          },
+          {
+            "name": "quota",
+            "type": "u32"
+          },
+          {
+            "name": "issued",
+            "type": "u32"
+          },
+          {
+            "name": "declaredRevenue",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "session",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "date",
             "type": "i64"
           },
           {
-            "name": "numTickets",
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "capacity",
+            "type": "u32"
+          },
+          {
+            "name": "sold",
+            "type": "u32"
+          },
+          {
+            "name": "attendance",
             "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "initEventFields",
+      "name": "sessionFields",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "date",
+            "type": "i64"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "capacity",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "staff",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "event",
+            "type": "pubkey"
+          },
+          {
+            "name": "key",
+            "type": "pubkey"
+          },
+          {
+            "name": "permissions",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "updateMetadataFields",
       "type": {
         "kind": "struct",
         "fields": [
@@ -1374,26 +9502,110 @@ export type ChainTicket = {
           {
             "name": "imageUri",
             "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "updatePlatformFields",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "relayer",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "metadataUri",
-            "type": "string"
+            "name": "relayerFeeCap",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "eventDate",
-            "type": "i64"
+            "name": "treasury",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "ticketPrice",
-            "type": "u64"
+            "name": "resaleRoyaltyBps",
+            "type": {
+              "option": "u16"
+            }
           },
           {
-            "name": "numTickets",
-            "type": "u32"
+            "name": "advanceBps",
+            "type": {
+              "option": "u16"
+            }
           },
           {
-            "name": "refundPeriod",
-            "type": "i64"
+            "name": "vestingPeriod",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "depositBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "depositMin",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "depositMax",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "waitlist",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "event",
+            "type": "pubkey"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "waitlistEntry"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "waitlistEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "escrow",
+            "type": "u64"
           }
         ]
       }
@@ -0,0 +1,42 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryPassError {
+    /// The encoded pass is not `ENTRY_PASS_SIZE` bytes long.
+    InvalidLength,
+    /// The pass was encoded with an unsupported format version.
+    UnsupportedVersion,
+    /// The holder key is not a valid ed25519 public key.
+    InvalidHolderKey,
+    /// The signature does not match the pass contents and holder key.
+    InvalidSignature,
+    /// The pass was signed for a different event or mint than the snapshot.
+    WrongEvent,
+    /// The pass timestamp is outside of the accepted window.
+    Expired,
+    /// A pass with the same or an older timestamp was already accepted for this token account.
+    Replayed,
+    /// A pass was already accepted for this token account and re-entry is disabled.
+    AlreadyEntered,
+    /// The token account is not in the snapshot, is owned by someone else or holds no ticket.
+    NotTicketHolder,
+}
+
+impl fmt::Display for EntryPassError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Self::InvalidLength => "Invalid entry pass length",
+            Self::UnsupportedVersion => "Unsupported entry pass version",
+            Self::InvalidHolderKey => "Invalid holder public key",
+            Self::InvalidSignature => "Invalid entry pass signature",
+            Self::WrongEvent => "Entry pass is for a different event",
+            Self::Expired => "Entry pass has expired",
+            Self::Replayed => "Entry pass has already been used",
+            Self::AlreadyEntered => "Ticket has already been admitted",
+            Self::NotTicketHolder => "Holder does not own a ticket",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl std::error::Error for EntryPassError {}
@@ -0,0 +1,21 @@
+//! Compact signed entry passes for scanning tickets without network access.
+//!
+//! A ticket holder signs an [`EntryPass`] with their wallet key, binding the event, the ticket
+//! mint, the associated token account holding the ticket and a timestamp which doubles as a
+//! nonce. Door scanners check the pass with a [`Verifier`] against a [`Snapshot`] of the event's
+//! token accounts taken while they were still online, scanners sharing a door must share a
+//! verifier so that a ticket is only admitted once. Accepted check-ins are later settled
+//! on-chain in bulk as a Merkle root built with the [`merkle`] module.
+
+mod error;
+pub mod merkle;
+mod pass;
+mod verifier;
+
+pub use error::EntryPassError;
+pub use pass::{EntryPass, ENTRY_PASS_SIZE, ENTRY_PASS_VERSION};
+pub use verifier::{Holding, Snapshot, Verifier};
+
+/// Raw 32 byte public key, kept independent of the Solana SDK so the crate stays small enough
+/// for scanner devices.
+pub type Pubkey = [u8; 32];
@@ -0,0 +1,75 @@
+//! Merkle trees of offline check-ins, settled on-chain with `settle_check_ins` and proven per
+//! ticket with `claim_check_in`. The hashing scheme must match the program's `utils` module.
+
+use {crate::Pubkey, sha2::{Digest, Sha256}};
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// Leaf for a ticket, identified by its receipt address and the slot the receipt was issued in,
+/// checked in for a session. Single session events use session `0`.
+pub fn leaf(receipt: &Pubkey, issued_slot: u64, session: u8) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([LEAF_PREFIX]);
+    hasher.update(receipt);
+    hasher.update(issued_slot.to_le_bytes());
+    hasher.update([session]);
+    hasher.finalize().into()
+}
+
+fn node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut hasher = Sha256::new();
+    hasher.update([NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Builds every level of the tree, from the leaves up to the root. A node without a sibling is
+/// carried up to the next level unchanged.
+fn levels(leaves: &[[u8; 32]]) -> Vec<Vec<[u8; 32]>> {
+    let mut levels = vec![leaves.to_vec()];
+    while levels.last().is_some_and(|level| level.len() > 1) {
+        let next = levels
+            .last()
+            .unwrap()
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => node(a, b),
+                [a] => *a,
+                _ => unreachable!(),
+            })
+            .collect();
+        levels.push(next);
+    }
+    levels
+}
+
+/// Root of the tree over `leaves`, `None` if there are no leaves.
+pub fn root(leaves: &[[u8; 32]]) -> Option<[u8; 32]> {
+    levels(leaves).last().and_then(|level| level.first().copied())
+}
+
+/// Proof that the leaf at `index` is part of the tree, `None` if `index` is out of range.
+pub fn proof(leaves: &[[u8; 32]], index: usize) -> Option<Vec<[u8; 32]>> {
+    if index >= leaves.len() {
+        return None;
+    }
+
+    let levels = levels(leaves);
+    let mut proof = Vec::new();
+    let mut position = index;
+    for level in &levels[..levels.len() - 1] {
+        if let Some(sibling) = level.get(position ^ 1) {
+            proof.push(*sibling);
+        }
+        position /= 2;
+    }
+    Some(proof)
+}
+
+/// Verifies a proof produced by `proof`, mirroring the on-chain check.
+pub fn verify(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    proof.iter().fold(leaf, |computed, sibling| node(&computed, sibling)) == *root
+}
@@ -0,0 +1,110 @@
+use {
+    crate::{EntryPassError, Pubkey},
+    ed25519_dalek::{Keypair, PublicKey, Signature, Signer, Verifier},
+};
+
+/// Current version of the encoded entry pass format.
+pub const ENTRY_PASS_VERSION: u8 = 1;
+
+/// Size in bytes of an encoded entry pass: version, event, mint, token account, holder,
+/// timestamp and signature.
+pub const ENTRY_PASS_SIZE: usize = 1 + 32 * 4 + 8 + 64;
+
+/// Prefix of the signed message so a pass signature cannot be confused with a transaction or
+/// any other message signed by the wallet.
+const DOMAIN: &[u8] = b"chain-ticket entry pass";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryPass {
+    // The event PDA the ticket belongs to
+    pub event: Pubkey,
+    // The event's ticket mint
+    pub mint: Pubkey,
+    // The holder's associated token account holding the ticket
+    pub ata: Pubkey,
+    // The holder's wallet, the key that signed the pass
+    pub holder: Pubkey,
+    // Unix time the pass was signed at, also used as a nonce
+    pub timestamp: i64,
+    pub signature: [u8; 64],
+}
+
+impl EntryPass {
+    /// Creates a pass signed by the holder's wallet key.
+    pub fn sign(
+        holder: &Keypair,
+        event: Pubkey,
+        mint: Pubkey,
+        ata: Pubkey,
+        timestamp: i64,
+    ) -> Self {
+        let signature = holder.sign(&message(&event, &mint, &ata, timestamp));
+        Self {
+            event,
+            mint,
+            ata,
+            holder: holder.public.to_bytes(),
+            timestamp,
+            signature: signature.to_bytes(),
+        }
+    }
+
+    /// Checks that the pass was signed by `holder`. This says nothing about whether the holder
+    /// owns a ticket, see `Verifier::verify`.
+    pub fn verify_signature(&self) -> Result<(), EntryPassError> {
+        let key =
+            PublicKey::from_bytes(&self.holder).map_err(|_| EntryPassError::InvalidHolderKey)?;
+        let signature = Signature::from_bytes(&self.signature)
+            .map_err(|_| EntryPassError::InvalidSignature)?;
+        key.verify(
+            &message(&self.event, &self.mint, &self.ata, self.timestamp),
+            &signature,
+        )
+        .map_err(|_| EntryPassError::InvalidSignature)
+    }
+
+    /// Encodes the pass into its compact binary form, e.g. for a QR code.
+    pub fn to_bytes(&self) -> [u8; ENTRY_PASS_SIZE] {
+        let mut bytes = [0u8; ENTRY_PASS_SIZE];
+        bytes[0] = ENTRY_PASS_VERSION;
+        bytes[1..33].copy_from_slice(&self.event);
+        bytes[33..65].copy_from_slice(&self.mint);
+        bytes[65..97].copy_from_slice(&self.ata);
+        bytes[97..129].copy_from_slice(&self.holder);
+        bytes[129..137].copy_from_slice(&self.timestamp.to_le_bytes());
+        bytes[137..].copy_from_slice(&self.signature);
+        bytes
+    }
+
+    /// Decodes a pass produced by `to_bytes`. The signature is not checked.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EntryPassError> {
+        if bytes.len() != ENTRY_PASS_SIZE {
+            return Err(EntryPassError::InvalidLength);
+        }
+        if bytes[0] != ENTRY_PASS_VERSION {
+            return Err(EntryPassError::UnsupportedVersion);
+        }
+
+        let key = |offset: usize| -> Pubkey { bytes[offset..offset + 32].try_into().unwrap() };
+
+        Ok(Self {
+            event: key(1),
+            mint: key(33),
+            ata: key(65),
+            holder: key(97),
+            timestamp: i64::from_le_bytes(bytes[129..137].try_into().unwrap()),
+            signature: bytes[137..].try_into().unwrap(),
+        })
+    }
+}
+
+fn message(event: &Pubkey, mint: &Pubkey, ata: &Pubkey, timestamp: i64) -> Vec<u8> {
+    let mut message = Vec::with_capacity(DOMAIN.len() + 1 + 32 * 3 + 8);
+    message.extend_from_slice(DOMAIN);
+    message.push(ENTRY_PASS_VERSION);
+    message.extend_from_slice(event);
+    message.extend_from_slice(mint);
+    message.extend_from_slice(ata);
+    message.extend_from_slice(&timestamp.to_le_bytes());
+    message
+}
@@ -0,0 +1,117 @@
+use {
+    crate::{EntryPass, EntryPassError, Pubkey},
+    std::collections::HashMap,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Holding {
+    // Owner of the token account
+    pub owner: Pubkey,
+    // Number of tickets held, at most one for accounts created by the program
+    pub amount: u64,
+}
+
+/// Token accounts of an event's ticket mint, fetched while the scanner was online (e.g. with
+/// `getProgramAccounts` filtered on the mint, as `burnRefundAll` does in the client).
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub event: Pubkey,
+    pub mint: Pubkey,
+    holdings: HashMap<Pubkey, Holding>,
+}
+
+impl Snapshot {
+    pub fn new(event: Pubkey, mint: Pubkey) -> Self {
+        Self {
+            event,
+            mint,
+            holdings: HashMap::new(),
+        }
+    }
+
+    /// Records the state of a token account, replacing any previous entry.
+    pub fn insert(&mut self, ata: Pubkey, holding: Holding) {
+        self.holdings.insert(ata, holding);
+    }
+
+    pub fn get(&self, ata: &Pubkey) -> Option<&Holding> {
+        self.holdings.get(ata)
+    }
+}
+
+/// Checks entry passes against a snapshot. Accepted passes are remembered per token account so
+/// that a ticket is only admitted once, or, with re-entry enabled, so that the same pass cannot be
+/// presented twice. The accepted set is only as good as the passes it has seen: scanners sharing a
+/// door must share one `Verifier`, or sync their accepted passes, otherwise a holder can enter once
+/// per scanner.
+#[derive(Debug, Clone)]
+pub struct Verifier {
+    snapshot: Snapshot,
+    // Number of seconds a pass stays valid after it was signed
+    max_age: i64,
+    // Number of seconds a pass can be dated in the future to allow for clock drift
+    max_skew: i64,
+    // Whether a newer pass readmits a token account, e.g. leaving and re-entering the venue
+    reentry: bool,
+    // Timestamp of the last accepted pass for each token account
+    accepted: HashMap<Pubkey, i64>,
+}
+
+impl Verifier {
+    pub fn new(snapshot: Snapshot, max_age: i64, max_skew: i64) -> Self {
+        Self {
+            snapshot,
+            max_age,
+            max_skew,
+            reentry: false,
+            accepted: HashMap::new(),
+        }
+    }
+
+    /// Lets a holder re-enter with a pass signed after the last one accepted for their token
+    /// account. Disabled by default.
+    pub fn with_reentry(mut self, reentry: bool) -> Self {
+        self.reentry = reentry;
+        self
+    }
+
+    pub fn snapshot(&self) -> &Snapshot {
+        &self.snapshot
+    }
+
+    /// Verifies a pass presented at unix time `now` and records it as used. A pass is accepted
+    /// when it is signed by its holder for this event, is fresh, the snapshot shows the holder
+    /// owning a ticket in that account and no pass was previously accepted for the token account.
+    /// With re-entry enabled a pass newer than the last one accepted is accepted again.
+    pub fn verify(&mut self, pass: &EntryPass, now: i64) -> Result<(), EntryPassError> {
+        if pass.event != self.snapshot.event || pass.mint != self.snapshot.mint {
+            return Err(EntryPassError::WrongEvent);
+        }
+
+        pass.verify_signature()?;
+
+        if pass.timestamp > now.saturating_add(self.max_skew)
+            || pass.timestamp < now.saturating_sub(self.max_age)
+        {
+            return Err(EntryPassError::Expired);
+        }
+
+        if let Some(last) = self.accepted.get(&pass.ata) {
+            if pass.timestamp <= *last {
+                return Err(EntryPassError::Replayed);
+            }
+            if !self.reentry {
+                return Err(EntryPassError::AlreadyEntered);
+            }
+        }
+
+        match self.snapshot.get(&pass.ata) {
+            Some(holding) if holding.owner == pass.holder && holding.amount > 0 => {}
+            _ => return Err(EntryPassError::NotTicketHolder),
+        }
+
+        self.accepted.insert(pass.ata, pass.timestamp);
+
+        Ok(())
+    }
+}
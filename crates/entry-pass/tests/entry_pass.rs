@@ -0,0 +1,202 @@
+use {
+    ed25519_dalek::Keypair,
+    entry_pass::{EntryPass, EntryPassError, Holding, Snapshot, Verifier, ENTRY_PASS_SIZE},
+    rand::rngs::OsRng,
+};
+
+const NOW: i64 = 1_700_000_000;
+const MAX_AGE: i64 = 300;
+const MAX_SKEW: i64 = 30;
+
+struct Fixture {
+    holder: Keypair,
+    event: [u8; 32],
+    mint: [u8; 32],
+    ata: [u8; 32],
+    verifier: Verifier,
+}
+
+fn random_key() -> [u8; 32] {
+    Keypair::generate(&mut OsRng).public.to_bytes()
+}
+
+fn fixture() -> Fixture {
+    let holder = Keypair::generate(&mut OsRng);
+    let event = random_key();
+    let mint = random_key();
+    let ata = random_key();
+
+    let mut snapshot = Snapshot::new(event, mint);
+    snapshot.insert(
+        ata,
+        Holding {
+            owner: holder.public.to_bytes(),
+            amount: 1,
+        },
+    );
+
+    Fixture {
+        holder,
+        event,
+        mint,
+        ata,
+        verifier: Verifier::new(snapshot, MAX_AGE, MAX_SKEW),
+    }
+}
+
+impl Fixture {
+    fn pass(&self, timestamp: i64) -> EntryPass {
+        EntryPass::sign(&self.holder, self.event, self.mint, self.ata, timestamp)
+    }
+}
+
+#[test]
+fn accepts_valid_pass() {
+    let mut f = fixture();
+    let pass = f.pass(NOW);
+
+    assert_eq!(f.verifier.verify(&pass, NOW), Ok(()));
+}
+
+#[test]
+fn round_trips_encoding() {
+    let f = fixture();
+    let pass = f.pass(NOW);
+    let bytes = pass.to_bytes();
+
+    assert_eq!(bytes.len(), ENTRY_PASS_SIZE);
+    assert_eq!(EntryPass::from_bytes(&bytes), Ok(pass));
+}
+
+#[test]
+fn rejects_malformed_encoding() {
+    let f = fixture();
+    let mut bytes = f.pass(NOW).to_bytes();
+
+    assert_eq!(
+        EntryPass::from_bytes(&bytes[1..]),
+        Err(EntryPassError::InvalidLength)
+    );
+
+    bytes[0] = 0;
+    assert_eq!(
+        EntryPass::from_bytes(&bytes),
+        Err(EntryPassError::UnsupportedVersion)
+    );
+}
+
+#[test]
+fn rejects_tampered_pass() {
+    let mut f = fixture();
+    let mut pass = f.pass(NOW);
+    pass.timestamp += 1;
+
+    assert_eq!(
+        f.verifier.verify(&pass, NOW),
+        Err(EntryPassError::InvalidSignature)
+    );
+}
+
+#[test]
+fn rejects_pass_signed_by_another_wallet() {
+    let mut f = fixture();
+    let other = Keypair::generate(&mut OsRng);
+    let mut pass = EntryPass::sign(&other, f.event, f.mint, f.ata, NOW);
+
+    assert_eq!(
+        f.verifier.verify(&pass, NOW),
+        Err(EntryPassError::NotTicketHolder)
+    );
+
+    // Claiming to be the holder without their key fails the signature check
+    pass.holder = f.holder.public.to_bytes();
+    assert_eq!(
+        f.verifier.verify(&pass, NOW),
+        Err(EntryPassError::InvalidSignature)
+    );
+}
+
+#[test]
+fn rejects_other_event() {
+    let mut f = fixture();
+    let pass = EntryPass::sign(&f.holder, random_key(), f.mint, f.ata, NOW);
+
+    assert_eq!(
+        f.verifier.verify(&pass, NOW),
+        Err(EntryPassError::WrongEvent)
+    );
+}
+
+#[test]
+fn rejects_stale_and_future_passes() {
+    let mut f = fixture();
+
+    assert_eq!(
+        f.verifier.verify(&f.pass(NOW - MAX_AGE - 1), NOW),
+        Err(EntryPassError::Expired)
+    );
+    assert_eq!(
+        f.verifier.verify(&f.pass(NOW + MAX_SKEW + 1), NOW),
+        Err(EntryPassError::Expired)
+    );
+    assert_eq!(f.verifier.verify(&f.pass(NOW - MAX_AGE), NOW), Ok(()));
+}
+
+#[test]
+fn rejects_replayed_pass() {
+    let mut f = fixture();
+    let pass = f.pass(NOW);
+
+    assert_eq!(f.verifier.verify(&pass, NOW), Ok(()));
+    assert_eq!(
+        f.verifier.verify(&pass, NOW + 1),
+        Err(EntryPassError::Replayed)
+    );
+
+    // A freshly signed pass does not admit the ticket a second time
+    assert_eq!(
+        f.verifier.verify(&f.pass(NOW + 10), NOW + 10),
+        Err(EntryPassError::AlreadyEntered)
+    );
+}
+
+#[test]
+fn readmits_newer_pass_with_reentry() {
+    let mut f = fixture();
+    f.verifier = f.verifier.with_reentry(true);
+    let pass = f.pass(NOW);
+
+    assert_eq!(f.verifier.verify(&pass, NOW), Ok(()));
+    assert_eq!(
+        f.verifier.verify(&pass, NOW + 1),
+        Err(EntryPassError::Replayed)
+    );
+
+    // Leaving and re-entering the venue with a freshly signed pass
+    assert_eq!(f.verifier.verify(&f.pass(NOW + 10), NOW + 10), Ok(()));
+}
+
+#[test]
+fn rejects_account_without_ticket() {
+    let mut f = fixture();
+    let mut snapshot = f.verifier.snapshot().clone();
+    snapshot.insert(
+        f.ata,
+        Holding {
+            owner: f.holder.public.to_bytes(),
+            amount: 0,
+        },
+    );
+    f.verifier = Verifier::new(snapshot, MAX_AGE, MAX_SKEW);
+
+    assert_eq!(
+        f.verifier.verify(&f.pass(NOW), NOW),
+        Err(EntryPassError::NotTicketHolder)
+    );
+
+    let unknown = EntryPass::sign(&f.holder, f.event, f.mint, random_key(), NOW);
+    assert_eq!(
+        f.verifier.verify(&unknown, NOW),
+        Err(EntryPassError::NotTicketHolder)
+    );
+}
@@ -0,0 +1,56 @@
+use entry_pass::merkle::{leaf, proof, root, verify};
+
+fn leaves(count: u8) -> Vec<[u8; 32]> {
+    (0..count).map(|i| leaf(&[i; 32], 7, 0)).collect()
+}
+
+#[test]
+fn empty_tree_has_no_root() {
+    assert_eq!(root(&[]), None);
+    assert_eq!(proof(&[], 0), None);
+}
+
+#[test]
+fn single_leaf_is_the_root() {
+    let leaves = leaves(1);
+
+    assert_eq!(root(&leaves), Some(leaves[0]));
+    assert_eq!(proof(&leaves, 0), Some(vec![]));
+}
+
+#[test]
+fn proves_every_leaf() {
+    // Odd sizes exercise nodes carried up without a sibling
+    for count in 1..=9 {
+        let leaves = leaves(count);
+        let root = root(&leaves).unwrap();
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = proof(&leaves, index).unwrap();
+            assert!(verify(&proof, &root, *leaf), "leaf {index} of {count}");
+        }
+    }
+}
+
+#[test]
+fn rejects_leaf_outside_tree() {
+    let leaves = leaves(5);
+    let root = root(&leaves).unwrap();
+    let proof = proof(&leaves, 2).unwrap();
+
+    assert!(!verify(&proof, &root, leaf(&[42; 32], 7, 0)));
+    // Same ticket, different session
+    assert!(!verify(&proof, &root, leaf(&[2; 32], 7, 1)));
+    // Same address, receipt re-created in a later slot
+    assert!(!verify(&proof, &root, leaf(&[2; 32], 8, 0)));
+}
+
+#[test]
+fn rejects_tampered_proof() {
+    let leaves = leaves(4);
+    let root = root(&leaves).unwrap();
+    let mut proof = proof(&leaves, 1).unwrap();
+    proof[0][0] ^= 1;
+
+    assert!(!verify(&proof, &root, leaves[1]));
+}
@@ -6,18 +6,104 @@ pub const VAULT_SEED: &[u8; 5] = b"vault";
 pub const MINT_SEED: &[u8; 4] = b"mint";
 /// Seed required for constructing the metadata PDA.
 pub const METADATA_SEED: &[u8; 8] = b"metadata";
+/// Seed used for constructing the waitlist PDA.
+pub const WAITLIST_SEED: &[u8; 8] = b"waitlist";
+/// Seed used for constructing a ticket hold PDA.
+pub const HOLD_SEED: &[u8; 4] = b"hold";
+/// Seed used for constructing a ticket receipt PDA.
+pub const RECEIPT_SEED: &[u8; 7] = b"receipt";
+/// Seed used for constructing the platform config PDA.
+pub const PLATFORM_SEED: &[u8; 8] = b"platform";
+/// Seed used for constructing a sales agent PDA.
+pub const AGENT_SEED: &[u8; 5] = b"agent";
+/// Seed used for constructing the proof of attendance mint PDA.
+pub const ATTENDANCE_MINT_SEED: &[u8; 15] = b"attendance_mint";
+/// Seed used for constructing an offline check-in batch PDA.
+pub const CHECK_IN_BATCH_SEED: &[u8; 14] = b"check_in_batch";
+/// Seed used for constructing a resale listing PDA.
+pub const LISTING_SEED: &[u8; 7] = b"listing";
+/// Seed used for constructing a ticket offer PDA.
+pub const OFFER_SEED: &[u8; 5] = b"offer";
+/// Seed used for constructing an organiser profile PDA.
+pub const PROFILE_SEED: &[u8; 7] = b"profile";
+/// Seed used for constructing an affiliate PDA.
+pub const AFFILIATE_SEED: &[u8; 9] = b"affiliate";
+/// Seed used for constructing a staff PDA.
+pub const STAFF_SEED: &[u8; 5] = b"staff";
 
 /// Size of the account holding the event's details (its state).
-pub const EVENT_STATE_SIZE: usize = 126;
+pub const EVENT_STATE_SIZE: usize = 342 + 28 * MAX_SESSIONS + 34 * MAX_PAYEES;
+
+/// Maximum number of sessions a multi-day event can have, bounded by the ticket's session bitmask.
+pub const MAX_SESSIONS: usize = 8;
+
+/// Maximum number of payees an event's proceeds can be split between.
+pub const MAX_PAYEES: usize = 8;
+
+/// Maximum number of wallets that can be queued on a waitlist.
+pub const MAX_WAITLIST_ENTRIES: usize = 64;
+
+/// Size of the account holding an event's waitlist queue.
+pub const WAITLIST_STATE_SIZE: usize = 1 + 32 + 4 + 40 * MAX_WAITLIST_ENTRIES;
+
+/// Size of the account reserving a ticket for a buyer.
+pub const HOLD_STATE_SIZE: usize = 73;
+
+/// Size of the account recording the purchase of a ticket.
+pub const RECEIPT_STATE_SIZE: usize = 199;
+
+/// Size of the account holding the platform wide configuration.
+pub const PLATFORM_STATE_SIZE: usize = 139 + 10 * MAX_FEE_TIERS;
+
+/// Maximum number of volume tiers in the platform's fee table.
+pub const MAX_FEE_TIERS: usize = 8;
+
+/// Size of the account tracking an organiser's lifetime sales.
+pub const PROFILE_STATE_SIZE: usize = 42;
+
+/// Size of the account tracking a box office sales agent.
+pub const AGENT_STATE_SIZE: usize = 81;
+
+/// Size of the account tracking an affiliate's commissions on an event's sales.
+pub const AFFILIATE_STATE_SIZE: usize = 87;
+
+/// Size of the account holding a staff key's permissions for an event.
+pub const STAFF_STATE_SIZE: usize = 66;
+
+/// Size of the account holding a settled batch of offline check-ins.
+pub const CHECK_IN_BATCH_STATE_SIZE: usize = 113;
+
+/// Size of the account listing a ticket for resale.
+pub const LISTING_STATE_SIZE: usize = 73;
+
+/// Size of the account escrowing an offer for a ticket.
+pub const OFFER_STATE_SIZE: usize = 74;
+
+/// Permission to co-sign ticket check-ins.
+pub const PERMISSION_SCANNER: u8 = 1 << 0;
+/// Permission to refund tickets.
+pub const PERMISSION_REFUND_AGENT: u8 = 1 << 1;
+/// Permission to update the ticket token metadata.
+pub const PERMISSION_METADATA_EDITOR: u8 = 1 << 3;
 
 /// The public key of the platform owner
 pub const PLATFORM_OWNER: anchor_lang::solana_program::pubkey::Pubkey =
     anchor_lang::pubkey!("AAFtJwAvDErpcDXVYotdqerUJHRkjNTGChMiAeuKMZVc");
 
-/// Platform fee (as a percentage)
-pub const FEE: u64 = 1;
-
-/// SOL amount to deposit. Used as a guarantee should the event be cancelled
-pub const DEPOSIT_AMOUNT: u64 = 2;
+/// Platform fee in basis points of the proceeds paid out of an event's vault (1%), charged to
+/// organisers below the platform's lowest fee tier
+pub const PLATFORM_FEE_BPS: u16 = 100;
 
 pub const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Denominator for values expressed in basis points
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Default number of seconds a ticket hold lasts for
+pub const DEFAULT_HOLD_TTL: i64 = 600;
+
+/// Maximum number of seconds an organiser can configure a ticket hold to last for
+pub const MAX_HOLD_TTL: i64 = 3_600;
+
+/// Maximum share of the unsold tickets that can be held at once, in basis points
+pub const MAX_HELD_BPS: u16 = 5_000;
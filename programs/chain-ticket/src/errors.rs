@@ -37,4 +37,163 @@ pub enum ChainTicketError {
 
     #[msg("User has already purchased a ticket")]
     AlreadyPurchased,
+
+    #[msg("Tickets are still available")]
+    TicketsAvailable,
+
+    #[msg("Waitlist is full")]
+    WaitlistFull,
+
+    #[msg("Wallet is already on the waitlist")]
+    AlreadyWaitlisted,
+
+    #[msg("Wallet is not on the waitlist")]
+    NotWaitlisted,
+
+    #[msg("No tickets have been freed for the waitlist")]
+    NoWaitlistOffer,
+
+    #[msg("Wallet is not at the head of the waitlist")]
+    NotWaitlistHead,
+
+    #[msg("Waitlist is not empty")]
+    WaitlistNotEmpty,
+
+    #[msg("Hold has expired")]
+    HoldExpired,
+
+    #[msg("Hold has not expired")]
+    HoldNotExpired,
+
+    #[msg("Invalid hold duration")]
+    InvalidHoldTtl,
+
+    #[msg("Invalid receipt payer")]
+    InvalidReceiptPayer,
+
+    #[msg("Relayer fee exceeds the platform cap")]
+    RelayerFeeExceeded,
+
+    #[msg("Complimentary ticket quota exceeded")]
+    CompQuotaExceeded,
+
+    #[msg("Complimentary tickets cannot be refunded")]
+    CompNotRefundable,
+
+    #[msg("Sales agent quota exceeded")]
+    AgentQuotaExceeded,
+
+    #[msg("Ticket has already been checked in")]
+    AlreadyCheckedIn,
+
+    #[msg("Wallet does not hold a ticket")]
+    NoTicket,
+
+    #[msg("Invalid session selection")]
+    InvalidSessions,
+
+    #[msg("Session is sold out")]
+    SessionSoldOut,
+
+    #[msg("Ticket is not valid for this session")]
+    NotEntitled,
+
+    #[msg("Session is not open for check-in")]
+    SessionNotOpen,
+
+    #[msg("Sessions cannot be changed once sales have started")]
+    SaleStarted,
+
+    #[msg("Ticket has not been checked in")]
+    NotCheckedIn,
+
+    #[msg("Proof of attendance already claimed")]
+    AttendanceClaimed,
+
+    #[msg("Invalid check-in proof")]
+    InvalidCheckInProof,
+
+    #[msg("Resale is disabled for this event")]
+    ResaleDisabled,
+
+    #[msg("Price exceeds the resale cap")]
+    ResalePriceExceeded,
+
+    #[msg("Event has already started")]
+    EventStarted,
+
+    #[msg("Basis points exceed the allowed total")]
+    InvalidBasisPoints,
+
+    #[msg("Transfers are disabled for this event")]
+    TransfersDisabled,
+
+    #[msg("Transfers are locked ahead of the event")]
+    TransferLockedOut,
+
+    #[msg("Ticket has reached the maximum number of transfers")]
+    TransferLimitReached,
+
+    #[msg("Swapped tickets are not valid for this instruction")]
+    InvalidSwap,
+
+    #[msg("Event is not set as delegate of the token account")]
+    InvalidDelegate,
+
+    #[msg("Ticket does not match the offer")]
+    OfferMismatch,
+
+    #[msg("Payee shares must add up to the whole of the proceeds")]
+    InvalidPayees,
+
+    #[msg("Amount exceeds the proceeds available for an advance")]
+    AdvanceExceeded,
+
+    #[msg("Event has been flagged by the platform")]
+    EventFlagged,
+
+    #[msg("Event has not been flagged by the platform")]
+    EventNotFlagged,
+
+    #[msg("No vested proceeds to claim")]
+    NothingVested,
+
+    #[msg("Event proceeds have not been withdrawn")]
+    FundsNotWithdrawn,
+
+    #[msg("Minimum deposit exceeds the maximum deposit")]
+    InvalidDepositBounds,
+
+    #[msg("Fee tiers must be in ascending order of volume")]
+    InvalidFeeTiers,
+
+    #[msg("Affiliate does not match the ticket or purchase")]
+    InvalidAffiliate,
+
+    #[msg("No commission to pay out")]
+    NothingAccrued,
+
+    #[msg("The event date cannot be brought forward once sales have started")]
+    EventBroughtForward,
+
+    #[msg("Token account is not the wallet's associated token account")]
+    InvalidTokenAccount,
+
+    #[msg("Price does not cover the royalties and transfer fee")]
+    PriceBelowFees,
+
+    #[msg("Refund exceeds the proceeds left in the vault")]
+    RefundExceedsProceeds,
+
+    #[msg("Offers for the event have not been filled or cancelled")]
+    OffersOpen,
+
+    #[msg("Vault holds less than the ledger records")]
+    InsufficientVaultBalance,
+
+    #[msg("Too many of the event's tickets are held")]
+    HoldLimitReached,
+
+    #[msg("Affiliate commissions are still owed")]
+    CommissionsUnpaid,
 }
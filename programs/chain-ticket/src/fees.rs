@@ -0,0 +1,174 @@
+//! Fee calculations. Amounts are in lamports and rates in basis points of `BPS_DENOMINATOR`,
+//! intermediate products are computed in `u128` so no amount and rate combination can overflow.
+//! Every calculation rounds down, so a party is never charged more than its rate.
+
+use {
+    crate::{
+        constants::{BPS_DENOMINATOR, PLATFORM_FEE_BPS},
+        errors::ChainTicketError,
+        state::FeeTier,
+    },
+    anchor_lang::prelude::*,
+};
+
+/// Applies a basis point rate to `amount`, rounding down. Rates above `BPS_DENOMINATOR` are
+/// allowed, e.g. for resale caps, and fail only if the result does not fit in a `u64`.
+pub fn apply_bps(amount: u64, bps: u16) -> Result<u64> {
+    let value = (amount as u128) * (bps as u128) / BPS_DENOMINATOR as u128;
+    u64::try_from(value).map_err(|_| ChainTicketError::Overflow.into())
+}
+
+/// Fee rate for an organiser with `volume` lifetime gross sales: the rate of the highest tier
+/// the volume reaches, or `PLATFORM_FEE_BPS` below the lowest tier. `tiers` must be sorted by
+/// ascending `min_volume`.
+pub fn tier_fee_bps(tiers: &[FeeTier], volume: u64) -> u16 {
+    tiers
+        .iter()
+        .take_while(|tier| tier.min_volume <= volume)
+        .last()
+        .map_or(PLATFORM_FEE_BPS, |tier| tier.fee_bps)
+}
+
+/// Fee taken by the platform out of `proceeds` paid out of an event's vault at `fee_bps`.
+pub fn platform_fee(proceeds: u64, fee_bps: u16) -> Result<u64> {
+    apply_bps(proceeds, fee_bps)
+}
+
+/// Splits `proceeds` into the platform fee and the amount left for the organiser, the two always
+/// add up to `proceeds`.
+pub fn deduct_platform_fee(proceeds: u64, fee_bps: u16) -> Result<(u64, u64)> {
+    let fee = platform_fee(proceeds, fee_bps)?;
+    Ok((fee, proceeds - fee))
+}
+
+/// Proceeds left in the vault from what was collected less what was refunded and already paid
+/// out, failing if the ledger is inconsistent.
+pub fn net_proceeds(collected: u64, refunded: u64, paid_out: u64) -> Result<u64> {
+    collected
+        .checked_sub(refunded)
+        .and_then(|proceeds| proceeds.checked_sub(paid_out))
+        .ok_or(ChainTicketError::FeeCalculationError.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_bps_rounds_down() {
+        assert_eq!(apply_bps(199, 50).unwrap(), 0);
+        assert_eq!(apply_bps(200, 50).unwrap(), 1);
+        assert_eq!(apply_bps(399, 50).unwrap(), 1);
+        assert_eq!(apply_bps(9_999, 1).unwrap(), 0);
+        assert_eq!(apply_bps(10_000, 1).unwrap(), 1);
+        assert_eq!(apply_bps(10_001, 3_333).unwrap(), 3_333);
+    }
+
+    #[test]
+    fn apply_bps_bounds() {
+        assert_eq!(apply_bps(0, 10_000).unwrap(), 0);
+        assert_eq!(apply_bps(12_345, 0).unwrap(), 0);
+        assert_eq!(apply_bps(12_345, 10_000).unwrap(), 12_345);
+        assert_eq!(apply_bps(12_345, 11_000).unwrap(), 13_579);
+        assert_eq!(apply_bps(10_000, u16::MAX).unwrap(), u16::MAX as u64);
+    }
+
+    #[test]
+    fn apply_bps_does_not_overflow() {
+        assert_eq!(apply_bps(u64::MAX, 10_000).unwrap(), u64::MAX);
+        assert!(apply_bps(u64::MAX, 10_001).is_err());
+        assert_eq!(apply_bps(u64::MAX, 5_000).unwrap(), u64::MAX / 2);
+        assert_eq!(
+            apply_bps(u64::MAX, 1).unwrap(),
+            (u64::MAX as u128 / 10_000) as u64
+        );
+    }
+
+    #[test]
+    fn apply_bps_matches_exact_division() {
+        // Exhaustive over small amounts and every rate, compared against the exact rational
+        for amount in 0..=1_000u64 {
+            for bps in 0..=u16::MAX {
+                let result = apply_bps(amount, bps).unwrap();
+                let exact = amount as u128 * bps as u128;
+                assert!(result as u128 * 10_000 <= exact);
+                assert!(exact < (result as u128 + 1) * 10_000);
+            }
+        }
+    }
+
+    #[test]
+    fn platform_fee_is_one_percent() {
+        assert_eq!(PLATFORM_FEE_BPS, 100);
+        let platform_fee = |proceeds| platform_fee(proceeds, PLATFORM_FEE_BPS);
+        assert_eq!(platform_fee(0).unwrap(), 0);
+        assert_eq!(platform_fee(99).unwrap(), 0);
+        assert_eq!(platform_fee(100).unwrap(), 1);
+        assert_eq!(platform_fee(1_000_000_000).unwrap(), 10_000_000);
+        assert_eq!(platform_fee(1_000_000_099).unwrap(), 10_000_000);
+        assert_eq!(platform_fee(1_000_000_100).unwrap(), 10_000_001);
+    }
+
+    #[test]
+    fn deduct_platform_fee_conserves_proceeds() {
+        for fee_bps in [0, 1, PLATFORM_FEE_BPS, 3_333, 10_000] {
+            for proceeds in (0..100_000u64).chain([u64::MAX - 1, u64::MAX]) {
+                let (fee, net) = deduct_platform_fee(proceeds, fee_bps).unwrap();
+                assert_eq!(fee + net, proceeds);
+                assert_eq!(fee, platform_fee(proceeds, fee_bps).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn split_payouts_never_charge_more_than_whole() {
+        // Paying out in parts, e.g. advances then a withdrawal, rounds down on each part
+        let platform_fee = |proceeds| platform_fee(proceeds, PLATFORM_FEE_BPS);
+        for first in 0..1_000u64 {
+            for second in 0..200u64 {
+                let parts = platform_fee(first).unwrap() + platform_fee(second).unwrap();
+                let whole = platform_fee(first + second).unwrap();
+                assert!(parts <= whole);
+                assert!(whole - parts <= 1);
+            }
+        }
+    }
+
+    #[test]
+    fn tier_fee_bps_picks_highest_tier_reached() {
+        let tiers = [
+            FeeTier { min_volume: 1_000, fee_bps: 75 },
+            FeeTier { min_volume: 10_000, fee_bps: 50 },
+            FeeTier { min_volume: 100_000, fee_bps: 25 },
+        ];
+        assert_eq!(tier_fee_bps(&tiers, 0), PLATFORM_FEE_BPS);
+        assert_eq!(tier_fee_bps(&tiers, 999), PLATFORM_FEE_BPS);
+        assert_eq!(tier_fee_bps(&tiers, 1_000), 75);
+        assert_eq!(tier_fee_bps(&tiers, 9_999), 75);
+        assert_eq!(tier_fee_bps(&tiers, 10_000), 50);
+        assert_eq!(tier_fee_bps(&tiers, u64::MAX), 25);
+    }
+
+    #[test]
+    fn tier_fee_bps_defaults_without_tiers() {
+        assert_eq!(tier_fee_bps(&[], 0), PLATFORM_FEE_BPS);
+        assert_eq!(tier_fee_bps(&[], u64::MAX), PLATFORM_FEE_BPS);
+        let tiers = [FeeTier { min_volume: 0, fee_bps: 60 }];
+        assert_eq!(tier_fee_bps(&tiers, 0), 60);
+    }
+
+    #[test]
+    fn net_proceeds_subtracts_refunds_and_payouts() {
+        assert_eq!(net_proceeds(1_000, 0, 0).unwrap(), 1_000);
+        assert_eq!(net_proceeds(1_000, 300, 200).unwrap(), 500);
+        assert_eq!(net_proceeds(1_000, 1_000, 0).unwrap(), 0);
+        assert_eq!(net_proceeds(u64::MAX, 0, u64::MAX).unwrap(), 0);
+    }
+
+    #[test]
+    fn net_proceeds_rejects_inconsistent_ledger() {
+        assert!(net_proceeds(1_000, 1_001, 0).is_err());
+        assert!(net_proceeds(1_000, 500, 501).is_err());
+        assert!(net_proceeds(0, 0, 1).is_err());
+    }
+}
@@ -0,0 +1,216 @@
+use {
+    crate::{
+        constants::{
+            EVENT_SEED, MINT_SEED, OFFER_SEED, PLATFORM_SEED, RECEIPT_SEED, RECEIPT_STATE_SIZE,
+            VAULT_SEED,
+        },
+        errors::ChainTicketError,
+        state::{Event, Offer, PlatformConfig, Receipt},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::{
+        freeze_account, thaw_account, transfer, FreezeAccount, Mint, ThawAccount, Token,
+        TokenAccount, Transfer,
+    },
+};
+
+#[derive(Accounts)]
+pub struct AcceptOffer<'info> {
+    #[account(mut)]
+    event: Box<Account<'info, Event>>,
+    /// CHECK: Address is derived and is a native vault, receives the organiser royalty and the
+    /// transfer fee
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event.key().as_ref()],
+        bump,
+        address = event.vault @ ChainTicketError::InvalidVault,
+    )]
+    vault: UncheckedAccount<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+    )]
+    platform: Box<Account<'info, PlatformConfig>>,
+    /// CHECK: Checked against the platform config, receives the platform royalty
+    #[account(
+        mut,
+        address = platform.treasury @ ChainTicketError::Unauthorised,
+    )]
+    treasury: UncheckedAccount<'info>,
+    #[account(
+        seeds = [MINT_SEED, event.key().as_ref()],
+        bump,
+        address = event.mint @ ChainTicketError::InvalidMint,
+    )]
+    mint: Box<Account<'info, Mint>>,
+    /// Holder filling the offer, pays the rent of the buyer's receipt
+    #[account(mut)]
+    seller: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = seller,
+    )]
+    seller_ata: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        close = seller_rent_payer,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), seller.key().as_ref()],
+        bump = seller_receipt.bump,
+        has_one = event,
+    )]
+    seller_receipt: Box<Account<'info, Receipt>>,
+    /// CHECK: Checked against the seller's receipt, receives the receipt rent
+    #[account(
+        mut,
+        address = seller_receipt.rent_payer @ ChainTicketError::InvalidReceiptPayer,
+    )]
+    seller_rent_payer: UncheckedAccount<'info>,
+    /// CHECK: Checked against the offer, receives the offer rent
+    #[account(
+        mut,
+        address = offer.buyer @ ChainTicketError::Unauthorised,
+    )]
+    buyer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = buyer,
+    )]
+    buyer_ata: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [OFFER_SEED, event.key().as_ref(), buyer.key().as_ref()],
+        bump = offer.bump,
+        has_one = event,
+    )]
+    offer: Box<Account<'info, Offer>>,
+    #[account(
+        init,
+        payer = seller,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), buyer.key().as_ref()],
+        bump,
+        space = 8 + RECEIPT_STATE_SIZE,
+    )]
+    buyer_receipt: Box<Account<'info, Receipt>>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+}
+
+/// Fills an offer with the seller's ticket, subject to the event's transfer rules. The offer price
+/// is subject to the same resale cap as listings, the royalties and the transfer fee are paid out
+/// of the escrow as in `process_buy_listing` and the rest goes to the seller. The ticket is moved
+/// to the buyer's token account, which was delegated to the event in `process_make_offer`, and
+/// frozen, and the seller's receipt is replaced by one for the buyer.
+pub fn process_accept_offer(ctx: Context<AcceptOffer>) -> Result<()> {
+    let clock = Clock::get()?;
+    let price = ctx.accounts.offer.price;
+
+    require_gt!(ctx.accounts.event.resale_cap_bps, 0, ChainTicketError::ResaleDisabled);
+    require_gt!(
+        ctx.accounts.event.event_date,
+        clock.unix_timestamp,
+        ChainTicketError::EventStarted
+    );
+    ctx.accounts
+        .event
+        .check_transfer(clock.unix_timestamp, ctx.accounts.seller_receipt.transfers)?;
+    require_gte!(
+        ctx.accounts.event.max_resale_price(ctx.accounts.seller_receipt.price)?,
+        price,
+        ChainTicketError::ResalePriceExceeded
+    );
+    require_eq!(
+        ctx.accounts.seller_receipt.sessions,
+        ctx.accounts.offer.sessions,
+        ChainTicketError::OfferMismatch
+    );
+    require_eq!(
+        ctx.accounts.seller_receipt.checked_in_sessions,
+        0,
+        ChainTicketError::AlreadyCheckedIn
+    );
+    require_eq!(ctx.accounts.seller_ata.amount, 1, ChainTicketError::NoTicket);
+    require_eq!(ctx.accounts.buyer_ata.amount, 0, ChainTicketError::AlreadyPurchased);
+    // The buyer may have revoked the approval given when making the offer
+    require!(
+        ctx.accounts.buyer_ata.delegate == Some(ctx.accounts.event.key()).into()
+            && ctx.accounts.buyer_ata.delegated_amount >= 1,
+        ChainTicketError::InvalidDelegate
+    );
+
+    let (organiser_royalty, platform_royalty) = ctx
+        .accounts
+        .event
+        .resale_royalties(price, ctx.accounts.platform.resale_royalty_bps)?;
+
+    let transfer_fee = ctx.accounts.event.transfer_fee;
+    let seller_proceeds = (price - organiser_royalty - platform_royalty)
+        .checked_sub(transfer_fee)
+        .ok_or(ChainTicketError::PriceBelowFees)?;
+
+    // Pay royalties, the transfer fee and the seller out of the escrow
+    **ctx.accounts.offer.to_account_info().try_borrow_mut_lamports()? -= price;
+    **ctx.accounts.vault.try_borrow_mut_lamports()? += organiser_royalty + transfer_fee;
+    **ctx.accounts.treasury.try_borrow_mut_lamports()? += platform_royalty;
+    **ctx.accounts.seller.try_borrow_mut_lamports()? += seller_proceeds;
+
+    let event_seeds: &[&[u8]] = &[
+        EVENT_SEED,
+        ctx.accounts.event.authority.as_ref(),
+        &[ctx.accounts.event.bump],
+    ];
+
+    thaw_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ThawAccount {
+            account: ctx.accounts.seller_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.event.to_account_info(),
+        },
+        &[event_seeds],
+    ))?;
+
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.seller_ata.to_account_info(),
+                to: ctx.accounts.buyer_ata.to_account_info(),
+                authority: ctx.accounts.event.to_account_info(),
+            },
+            &[event_seeds],
+        ),
+        1,
+    )?;
+
+    freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.buyer_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.event.to_account_info(),
+        },
+        &[event_seeds],
+    ))?;
+
+    ctx.accounts
+        .event
+        .record_royalties(organiser_royalty, platform_royalty)?;
+    ctx.accounts.event.record_transfer_fee()?;
+    ctx.accounts.event.open_offers = ctx.accounts.event.open_offers.saturating_sub(1);
+
+    let buyer = ctx.accounts.buyer.key();
+    let seller = ctx.accounts.seller.key();
+    ctx.accounts.buyer_receipt.transfer_from(
+        &ctx.accounts.seller_receipt,
+        ctx.bumps.buyer_receipt,
+        buyer,
+        seller,
+        clock.slot,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use {
+    crate::{
+        constants::{EVENT_SEED, PLATFORM_OWNER, PLATFORM_SEED, PROFILE_SEED, VAULT_SEED},
+        errors::ChainTicketError,
+        instructions::pay_proceeds,
+        state::{Event, OrganiserProfile, PlatformConfig},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct AdvanceFunds<'info> {
+    /// CHECK: Checked with constraint
+    #[account(
+        mut,
+        address = PLATFORM_OWNER @ ChainTicketError::Unauthorised,
+    )]
+    platform_owner: UncheckedAccount<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+    )]
+    platform: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        address = event.authority @ ChainTicketError::Unauthorised,
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [PROFILE_SEED, authority.key().as_ref()],
+        bump = profile.bump,
+    )]
+    profile: Account<'info, OrganiserProfile>,
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump,
+    )]
+    event: Account<'info, Event>,
+    /// CHECK: Address is derived and is a native vault,
+    /// in order to facilitate transfers from the vault
+    /// it must have no data and thus no discriminator.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event.key().as_ref()],
+        bump,
+        address = event.vault @ ChainTicketError::InvalidVault,
+    )]
+    vault: UncheckedAccount<'info>,
+}
+
+/// Withdraws `amount` of the event's proceeds before the refund period has elapsed. Only proceeds
+/// that can no longer be refunded can be advanced, capped at the platform's advance share, see
+/// `Event::available_advance`, and once vesting has started at the vested proceeds. The advance is
+/// paid out like a withdrawal, see `pay_proceeds`, and recorded on the event so that
+/// `withdraw_funds` settles only the remainder.
+pub fn process_advance(ctx: Context<AdvanceFunds>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(!ctx.accounts.event.flagged, ChainTicketError::EventFlagged);
+    if ctx.accounts.event.is_vesting(clock.unix_timestamp) {
+        ctx.accounts.event.start_vesting()?;
+    }
+    require_gte!(
+        ctx.accounts
+            .event
+            .available_advance(ctx.accounts.platform.advance_bps, clock.unix_timestamp)?,
+        amount,
+        ChainTicketError::AdvanceExceeded
+    );
+
+    **ctx.accounts.vault.try_borrow_mut_lamports()? -= amount;
+    let platform_fee = pay_proceeds(
+        &ctx.accounts.event,
+        &ctx.accounts.platform_owner,
+        &ctx.accounts.authority,
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.platform.fee_bps(&ctx.accounts.profile),
+    )?;
+
+    ctx.accounts
+        .event
+        .record_advance(amount, clock.unix_timestamp)?;
+    ctx.accounts
+        .event
+        .record_withdrawal(amount, platform_fee)?;
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use {
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        token::{
+            Token, TokenAccount, Mint, MintTo, mint_to, ApproveChecked, approve_checked,
+            FreezeAccount, freeze_account,
+        },
+        associated_token::AssociatedToken
+    },
+    crate::{
+        errors::ChainTicketError,
+        state::{Event, Receipt, RefundRecipient, SalesAgent},
+        constants::{AGENT_SEED, EVENT_SEED, MINT_SEED, RECEIPT_SEED, RECEIPT_STATE_SIZE},
+    },
+};
+
+#[derive(Accounts)]
+pub struct AgentIssueTicket<'info> {
+    #[account(mut)]
+    event: Account<'info, Event>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED, event.key().as_ref()],
+        bump,
+        address = event.mint @ ChainTicketError::InvalidMint,
+    )]
+    mint: Account<'info, Mint>,
+    #[account(mut)]
+    agent: Signer<'info>,
+    /// Registration of the agent by the event authority, the only authorisation to issue
+    #[account(
+        mut,
+        seeds = [AGENT_SEED, event.key().as_ref(), agent.key().as_ref()],
+        bump = sales_agent.bump,
+        has_one = event,
+        has_one = agent @ ChainTicketError::Unauthorised,
+    )]
+    sales_agent: Account<'info, SalesAgent>,
+    /// The customer only signs to approve the event as delegate on their token account, the
+    /// agent pays for the token account and receipt
+    customer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = agent,
+        associated_token::mint = mint,
+        associated_token::authority = customer,
+    )]
+    customer_ata: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = agent,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), customer.key().as_ref()],
+        bump,
+        space = 8 + RECEIPT_STATE_SIZE,
+    )]
+    receipt: Account<'info, Receipt>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Issues a ticket sold by a box office agent against an off-chain payment. The agent must have
+/// been registered with `register_agent` and have quota left on its `SalesAgent` account, which
+/// the authority revokes by setting the quota to zero. No lamports move, the receipt records a
+/// price of zero so any on-chain refund is a no-op and cash refunds are handled at the box office.
+/// `declared_revenue` is added to the agent's running total for reconciliation with the
+/// organiser.
+pub fn process_agent_issue(
+    ctx: Context<AgentIssueTicket>,
+    sessions: u8,
+    declared_revenue: u64,
+) -> Result<()> {
+    require_eq!(ctx.accounts.event.allow_purchase, true, ChainTicketError::SaleNotStarted);
+    require_gt!(
+        ctx.accounts.event.available_tickets(),
+        0,
+        ChainTicketError::MaxTicketsExceeded
+    );
+    require_gt!(
+        ctx.accounts.sales_agent.quota,
+        ctx.accounts.sales_agent.issued,
+        ChainTicketError::AgentQuotaExceeded
+    );
+    require_eq!(ctx.accounts.customer_ata.amount, 0, ChainTicketError::AlreadyPurchased);
+
+    ctx.accounts.event.sell_sessions(sessions)?;
+
+    // Mint the ticket to the customer
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.customer_ata.to_account_info(),
+                authority: ctx.accounts.event.to_account_info(),
+            },
+            &[&[
+                EVENT_SEED,
+                ctx.accounts.event.authority.as_ref(),
+                &[ctx.accounts.event.bump],
+            ]],
+        ),
+        1,
+    )?;
+
+    // Set the event as delegate, see `process_buy`
+    approve_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            ApproveChecked {
+                to: ctx.accounts.customer_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                delegate: ctx.accounts.event.to_account_info(),
+                authority: ctx.accounts.customer.to_account_info(),
+            },
+        ),
+        1,
+        0,
+    )?;
+
+    // Freeze the ATA so that the customer cannot transfer
+    freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.customer_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.event.to_account_info(),
+        },
+        &[&[
+            EVENT_SEED,
+            ctx.accounts.event.authority.as_ref(),
+            &[ctx.accounts.event.bump],
+        ]],
+    ))?;
+
+    ctx.accounts.receipt.bump = ctx.bumps.receipt;
+    ctx.accounts.receipt.event = ctx.accounts.event.key();
+    ctx.accounts.receipt.payer = ctx.accounts.customer.key();
+    ctx.accounts.receipt.rent_payer = ctx.accounts.agent.key();
+    ctx.accounts.receipt.holder = ctx.accounts.customer.key();
+    ctx.accounts.receipt.price = 0;
+    ctx.accounts.receipt.refund_recipient = RefundRecipient::Holder;
+    ctx.accounts.receipt.comp = false;
+    ctx.accounts.receipt.checked_in_at = 0;
+    ctx.accounts.receipt.sessions = sessions;
+    ctx.accounts.receipt.checked_in_sessions = 0;
+    ctx.accounts.receipt.attendance_claimed = false;
+    ctx.accounts.receipt.transfers = 0;
+    ctx.accounts.receipt.affiliate = Pubkey::default();
+    ctx.accounts.receipt.commission = 0;
+    ctx.accounts.receipt.issued_slot = Clock::get()?.slot;
+
+    ctx.accounts.event.record_sale(0)?;
+
+    ctx.accounts.sales_agent.issued += 1;
+    ctx.accounts.sales_agent.declared_revenue = ctx
+        .accounts
+        .sales_agent
+        .declared_revenue
+        .checked_add(declared_revenue)
+        .ok_or(ChainTicketError::Overflow)?;
+
+    Ok(())
+}
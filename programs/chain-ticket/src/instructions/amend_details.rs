@@ -1,5 +1,10 @@
 use {
-    crate::{constants::EVENT_SEED, state::Event},
+    crate::{
+        constants::{BPS_DENOMINATOR, EVENT_SEED, MAX_HOLD_TTL, PLATFORM_SEED, VAULT_SEED},
+        errors::ChainTicketError,
+        state::{Event, PlatformConfig},
+        utils::transfer_lamports,
+    },
     anchor_lang::prelude::*,
 };
 
@@ -10,9 +15,25 @@ pub struct AmendEvent<'info> {
     #[account(
         mut,
         seeds = [EVENT_SEED, authority.key().as_ref()],
-        bump,
+        bump = event.bump,
     )]
     event: Account<'info, Event>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+    )]
+    platform: Account<'info, PlatformConfig>,
+    /// CHECK: Address is derived and is a native vault,
+    /// in order to facilitate transfers from the vault
+    /// it must have no data and thus no discriminator.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event.key().as_ref()],
+        bump,
+        address = event.vault @ ChainTicketError::InvalidVault,
+    )]
+    vault: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -20,14 +41,32 @@ pub struct AmendEventFields {
     pub event_date: Option<i64>,
     pub ticket_price: Option<u64>,
     pub num_tickets: Option<u32>,
+    pub hold_ttl: Option<i64>,
+    pub comp_quota: Option<u32>,
+    pub resale_cap_bps: Option<u16>,
+    pub resale_royalty_bps: Option<u16>,
+    pub transfers_enabled: Option<bool>,
+    pub transfer_lockout: Option<i64>,
+    pub max_transfers: Option<u8>,
+    pub transfer_fee: Option<u64>,
 }
 
 /// Amend fields that are not passed in as `None`, passing a `None` for any of the fields in
-/// `AmendEventFields` means that field will not be amended.
+/// `AmendEventFields` means that field will not be amended. Once sales have started the event can
+/// only be postponed, bringing it forward would shorten the refund and vesting periods. Raising the
+/// price or capacity once sales have started tops up the deposit from the authority, a lower
+/// deposit is not refunded.
 pub fn process_amend(ctx: Context<AmendEvent>, data: AmendEventFields) -> Result<()> {
     require_keys_eq!(ctx.accounts.authority.key(), ctx.accounts.event.authority);
 
     if let Some(event_date) = data.event_date {
+        if ctx.accounts.event.allow_purchase {
+            require_gte!(
+                event_date,
+                ctx.accounts.event.event_date,
+                ChainTicketError::EventBroughtForward
+            );
+        }
         ctx.accounts.event.event_date = event_date;
     }
 
@@ -39,5 +78,64 @@ pub fn process_amend(ctx: Context<AmendEvent>, data: AmendEventFields) -> Result
         ctx.accounts.event.ticket_price = ticket_price;
     }
 
+    if let Some(hold_ttl) = data.hold_ttl {
+        require!(
+            hold_ttl > 0 && hold_ttl <= MAX_HOLD_TTL,
+            ChainTicketError::InvalidHoldTtl
+        );
+        ctx.accounts.event.hold_ttl = hold_ttl;
+    }
+
+    if let Some(comp_quota) = data.comp_quota {
+        ctx.accounts.event.comp_quota = comp_quota;
+    }
+
+    if let Some(resale_cap_bps) = data.resale_cap_bps {
+        ctx.accounts.event.resale_cap_bps = resale_cap_bps;
+    }
+
+    if let Some(resale_royalty_bps) = data.resale_royalty_bps {
+        require_gte!(
+            BPS_DENOMINATOR,
+            resale_royalty_bps as u64,
+            ChainTicketError::InvalidBasisPoints
+        );
+        ctx.accounts.event.resale_royalty_bps = resale_royalty_bps;
+    }
+
+    if let Some(transfers_enabled) = data.transfers_enabled {
+        ctx.accounts.event.transfers_enabled = transfers_enabled;
+    }
+
+    if let Some(transfer_lockout) = data.transfer_lockout {
+        ctx.accounts.event.transfer_lockout = transfer_lockout;
+    }
+
+    if let Some(max_transfers) = data.max_transfers {
+        ctx.accounts.event.max_transfers = max_transfers;
+    }
+
+    if let Some(transfer_fee) = data.transfer_fee {
+        ctx.accounts.event.transfer_fee = transfer_fee;
+    }
+
+    // Top up the deposit to match the event's new potential gross
+    if ctx.accounts.event.allow_purchase
+        && (data.ticket_price.is_some() || data.num_tickets.is_some())
+    {
+        let deposit = ctx
+            .accounts
+            .event
+            .required_deposit(&ctx.accounts.platform)?
+            .max(ctx.accounts.event.deposit);
+        transfer_lamports(
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            deposit - ctx.accounts.event.deposit,
+        )?;
+        ctx.accounts.event.deposit = deposit;
+    }
+
     Ok(())
 }
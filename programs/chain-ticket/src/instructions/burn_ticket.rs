@@ -1,5 +1,9 @@
 use {
-    crate::{constants::{EVENT_SEED, MINT_SEED}, state::Event},
+    crate::{
+        constants::{EVENT_SEED, MINT_SEED, RECEIPT_SEED},
+        errors::ChainTicketError,
+        state::{Event, Receipt},
+    },
     anchor_lang::prelude::*,
     anchor_spl::token::{
         burn, close_account, thaw_account, Burn, CloseAccount, Mint, ThawAccount, Token,
@@ -9,6 +13,7 @@ use {
 
 #[derive(Accounts)]
 pub struct BurnTicket<'info> {
+    #[account(mut)]
     event: Account<'info, Event>,
     #[account(
         mut,
@@ -16,6 +21,7 @@ pub struct BurnTicket<'info> {
         bump,
     )]
     mint: Account<'info, Mint>,
+    /// Signs for the burn and receives the token account rent
     #[account(mut)]
     ticket_holder: Signer<'info>,
     #[account(
@@ -24,6 +30,20 @@ pub struct BurnTicket<'info> {
         associated_token::authority = ticket_holder,
     )]
     ticket_holder_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), ticket_holder.key().as_ref()],
+        bump = receipt.bump,
+        has_one = event,
+    )]
+    receipt: Account<'info, Receipt>,
+    /// CHECK: Checked against the receipt, receives the receipt rent
+    #[account(
+        mut,
+        address = receipt.rent_payer @ ChainTicketError::InvalidReceiptPayer,
+    )]
+    rent_payer: UncheckedAccount<'info>,
     token_program: Program<'info, Token>,
 }
 
@@ -31,7 +51,10 @@ pub struct BurnTicket<'info> {
 /// can reclaim rent used for the token account. This function is required as the token
 /// account is frozen upon creation to prevent users from transferring tickets. Thus, this function
 /// first thaws the token account, then performs the ticket burn and finally closes the token
-/// account
+/// account. The receipt rent is returned to whoever paid for it, the platform relayer for
+/// sponsored purchases. The token account rent is returned to the holder, the token account
+/// outlives refunds so its rent may have been paid by the holder. The platform relayer can pay the
+/// transaction fee of a burn, the holder then needs no SOL.
 pub fn process_burn(ctx: Context<BurnTicket>) -> Result<()> {
     // Thaw token acount
     thaw_account(CpiContext::new_with_signer(
@@ -71,5 +94,8 @@ pub fn process_burn(ctx: Context<BurnTicket>) -> Result<()> {
         },
     ))?;
 
+    ctx.accounts.event.release_sessions(ctx.accounts.receipt.sessions);
+    ctx.accounts.event.record_burn()?;
+
     Ok(())
 }
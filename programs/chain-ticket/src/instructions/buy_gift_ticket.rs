@@ -0,0 +1,164 @@
+use {
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        token::{
+            Token, TokenAccount, Mint, MintTo, mint_to, ApproveChecked, approve_checked,
+            FreezeAccount, freeze_account,
+        },
+        associated_token::AssociatedToken
+    },
+    crate::{
+        errors::ChainTicketError,
+        state::{Event, Receipt, RefundRecipient},
+        constants::{EVENT_SEED, MINT_SEED, RECEIPT_SEED, RECEIPT_STATE_SIZE, VAULT_SEED},
+    },
+};
+
+#[derive(Accounts)]
+pub struct BuyGiftTicket<'info> {
+    #[account(mut)]
+    event: Account<'info, Event>,
+    /// CHECK: Address is derived and is a native vault,
+    /// in order to facilitate transfers from the vault
+    /// it must have no data and thus no discriminator.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event.key().as_ref()],
+        bump,
+        address = event.vault @ ChainTicketError::InvalidVault,
+    )]
+    vault: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED, event.key().as_ref()],
+        bump,
+        address = event.mint @ ChainTicketError::InvalidMint,
+    )]
+    mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The recipient only signs to approve the event as delegate on their token account, they
+    /// do not pay for anything
+    pub recipient: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+    )]
+    recipient_ata: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), recipient.key().as_ref()],
+        bump,
+        space = 8 + RECEIPT_STATE_SIZE,
+    )]
+    receipt: Account<'info, Receipt>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Purchases a ticket on behalf of another wallet. The payer funds the ticket, the recipient's
+/// token account and the receipt, while the ticket is minted, delegated and frozen exactly as in
+/// `process_buy` but on the recipient's token account. `refund_recipient` decides whether a
+/// refund is returned to the payer or to the holder of the ticket.
+pub fn process_buy_gift(
+    ctx: Context<BuyGiftTicket>,
+    sessions: u8,
+    refund_recipient: RefundRecipient,
+) -> Result<()> {
+    require_eq!(ctx.accounts.event.allow_purchase, true, ChainTicketError::SaleNotStarted);
+    require_gt!(
+        ctx.accounts.event.available_tickets(),
+        0,
+        ChainTicketError::MaxTicketsExceeded
+    );
+    require_eq!(ctx.accounts.recipient_ata.amount, 0, ChainTicketError::AlreadyPurchased);
+
+    let price = ctx.accounts.event.sell_sessions(sessions)?;
+
+    // Transfer sol from the payer to the vault
+    anchor_lang::solana_program::program::invoke(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.payer.key(),
+            &ctx.accounts.vault.key(),
+            price,
+        ),
+        &[
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    // Mint the ticket to the recipient
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.recipient_ata.to_account_info(),
+                authority: ctx.accounts.event.to_account_info(),
+            },
+            &[&[
+                EVENT_SEED,
+                ctx.accounts.event.authority.as_ref(),
+                &[ctx.accounts.event.bump],
+            ]],
+        ),
+        1,
+    )?;
+
+    // Set the event as delegate, see `process_buy`
+    approve_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            ApproveChecked {
+                to: ctx.accounts.recipient_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                delegate: ctx.accounts.event.to_account_info(),
+                authority: ctx.accounts.recipient.to_account_info(),
+            },
+        ),
+        1,
+        0,
+    )?;
+
+    // Freeze the ATA so that the recipient cannot transfer
+    freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.recipient_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.event.to_account_info(),
+        },
+        &[&[
+            EVENT_SEED,
+            ctx.accounts.event.authority.as_ref(),
+            &[ctx.accounts.event.bump],
+        ]],
+    ))?;
+
+    ctx.accounts.receipt.bump = ctx.bumps.receipt;
+    ctx.accounts.receipt.event = ctx.accounts.event.key();
+    ctx.accounts.receipt.payer = ctx.accounts.payer.key();
+    ctx.accounts.receipt.rent_payer = ctx.accounts.payer.key();
+    ctx.accounts.receipt.holder = ctx.accounts.recipient.key();
+    ctx.accounts.receipt.price = price;
+    ctx.accounts.receipt.refund_recipient = refund_recipient;
+    ctx.accounts.receipt.comp = false;
+    ctx.accounts.receipt.checked_in_at = 0;
+    ctx.accounts.receipt.sessions = sessions;
+    ctx.accounts.receipt.checked_in_sessions = 0;
+    ctx.accounts.receipt.attendance_claimed = false;
+    ctx.accounts.receipt.transfers = 0;
+    ctx.accounts.receipt.affiliate = Pubkey::default();
+    ctx.accounts.receipt.commission = 0;
+    ctx.accounts.receipt.issued_slot = Clock::get()?.slot;
+
+    ctx.accounts.event.record_sale(price)?;
+
+    Ok(())
+}
@@ -0,0 +1,239 @@
+use {
+    crate::{
+        constants::{
+            EVENT_SEED, LISTING_SEED, MINT_SEED, PLATFORM_SEED, RECEIPT_SEED, RECEIPT_STATE_SIZE,
+            VAULT_SEED,
+        },
+        errors::ChainTicketError,
+        state::{Event, Listing, PlatformConfig, Receipt},
+        utils::transfer_lamports,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{
+            approve_checked, close_account, freeze_account, transfer, ApproveChecked,
+            CloseAccount, FreezeAccount, Mint, Token, TokenAccount, Transfer,
+        },
+    },
+};
+
+#[derive(Accounts)]
+pub struct BuyListing<'info> {
+    #[account(mut)]
+    event: Account<'info, Event>,
+    /// CHECK: Address is derived and is a native vault, receives the organiser royalty and the
+    /// transfer fee
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event.key().as_ref()],
+        bump,
+        address = event.vault @ ChainTicketError::InvalidVault,
+    )]
+    vault: UncheckedAccount<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+    )]
+    platform: Account<'info, PlatformConfig>,
+    /// CHECK: Checked against the platform config, receives the platform royalty
+    #[account(
+        mut,
+        address = platform.treasury @ ChainTicketError::Unauthorised,
+    )]
+    treasury: UncheckedAccount<'info>,
+    #[account(
+        seeds = [MINT_SEED, event.key().as_ref()],
+        bump,
+        address = event.mint @ ChainTicketError::InvalidMint,
+    )]
+    mint: Account<'info, Mint>,
+    /// CHECK: Checked against the listing, receives the sale proceeds and rent
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = seller,
+        seeds = [LISTING_SEED, event.key().as_ref(), seller.key().as_ref()],
+        bump = listing.bump,
+        has_one = event,
+        has_one = seller,
+    )]
+    listing: Account<'info, Listing>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = listing,
+    )]
+    escrow_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        close = seller_rent_payer,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), seller.key().as_ref()],
+        bump = seller_receipt.bump,
+        has_one = event,
+    )]
+    seller_receipt: Account<'info, Receipt>,
+    /// CHECK: Checked against the seller's receipt, receives the receipt rent
+    #[account(
+        mut,
+        address = seller_receipt.rent_payer @ ChainTicketError::InvalidReceiptPayer,
+    )]
+    seller_rent_payer: UncheckedAccount<'info>,
+    #[account(mut)]
+    buyer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = mint,
+        associated_token::authority = buyer,
+    )]
+    buyer_ata: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = buyer,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), buyer.key().as_ref()],
+        bump,
+        space = 8 + RECEIPT_STATE_SIZE,
+    )]
+    buyer_receipt: Account<'info, Receipt>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Buys a listed ticket, subject to the event's transfer rules at the time of the sale. The
+/// organiser royalty and the event's transfer fee are paid into the vault, the platform royalty to
+/// the platform treasury and the rest of the asking price to the seller. The ticket is moved from
+/// escrow to the buyer's token account which is delegated to the event and frozen as in
+/// `process_buy`, and the seller's receipt is replaced by one for the buyer. The refundable amount
+/// stays the face value held in the vault, not the resale price. Tickets checked in while listed
+/// and tickets of flagged events cannot be bought.
+pub fn process_buy_listing(ctx: Context<BuyListing>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require_gt!(
+        ctx.accounts.event.event_date,
+        clock.unix_timestamp,
+        ChainTicketError::EventStarted
+    );
+    require!(!ctx.accounts.event.flagged, ChainTicketError::EventFlagged);
+    ctx.accounts
+        .event
+        .check_transfer(clock.unix_timestamp, ctx.accounts.seller_receipt.transfers)?;
+    // The ticket may have been checked in since it was listed
+    require_eq!(
+        ctx.accounts.seller_receipt.checked_in_sessions,
+        0,
+        ChainTicketError::AlreadyCheckedIn
+    );
+    require_eq!(ctx.accounts.buyer_ata.amount, 0, ChainTicketError::AlreadyPurchased);
+
+    let event_seeds: &[&[u8]] = &[
+        EVENT_SEED,
+        ctx.accounts.event.authority.as_ref(),
+        &[ctx.accounts.event.bump],
+    ];
+    let event_key = ctx.accounts.event.key();
+    let listing_seeds: &[&[u8]] = &[
+        LISTING_SEED,
+        event_key.as_ref(),
+        ctx.accounts.listing.seller.as_ref(),
+        &[ctx.accounts.listing.bump],
+    ];
+
+    let (organiser_royalty, platform_royalty) = ctx.accounts.event.resale_royalties(
+        ctx.accounts.listing.price,
+        ctx.accounts.platform.resale_royalty_bps,
+    )?;
+    let transfer_fee = ctx.accounts.event.transfer_fee;
+    let seller_proceeds = (ctx.accounts.listing.price - organiser_royalty - platform_royalty)
+        .checked_sub(transfer_fee)
+        .ok_or(ChainTicketError::PriceBelowFees)?;
+
+    // Pay royalties, the transfer fee and the seller
+    let buyer = ctx.accounts.buyer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    transfer_lamports(
+        &buyer,
+        &ctx.accounts.vault.to_account_info(),
+        &system_program,
+        organiser_royalty + transfer_fee,
+    )?;
+    transfer_lamports(
+        &buyer,
+        &ctx.accounts.treasury.to_account_info(),
+        &system_program,
+        platform_royalty,
+    )?;
+    transfer_lamports(
+        &buyer,
+        &ctx.accounts.seller.to_account_info(),
+        &system_program,
+        seller_proceeds,
+    )?;
+    // Move the ticket out of escrow
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow_ata.to_account_info(),
+                to: ctx.accounts.buyer_ata.to_account_info(),
+                authority: ctx.accounts.listing.to_account_info(),
+            },
+            &[listing_seeds],
+        ),
+        1,
+    )?;
+
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow_ata.to_account_info(),
+            destination: ctx.accounts.seller.to_account_info(),
+            authority: ctx.accounts.listing.to_account_info(),
+        },
+        &[listing_seeds],
+    ))?;
+
+    // Set the event as delegate, see `process_buy`
+    approve_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            ApproveChecked {
+                to: ctx.accounts.buyer_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                delegate: ctx.accounts.event.to_account_info(),
+                authority: ctx.accounts.buyer.to_account_info(),
+            },
+        ),
+        1,
+        0,
+    )?;
+
+    freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.buyer_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.event.to_account_info(),
+        },
+        &[event_seeds],
+    ))?;
+
+    ctx.accounts
+        .event
+        .record_royalties(organiser_royalty, platform_royalty)?;
+    ctx.accounts.event.record_transfer_fee()?;
+
+    let buyer = ctx.accounts.buyer.key();
+    ctx.accounts.buyer_receipt.transfer_from(
+        &ctx.accounts.seller_receipt,
+        ctx.bumps.buyer_receipt,
+        buyer,
+        buyer,
+        clock.slot,
+    );
+
+    Ok(())
+}
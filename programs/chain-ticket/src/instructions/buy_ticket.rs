@@ -7,11 +7,19 @@ use {
         }, 
         associated_token::AssociatedToken
     },
-    crate::{errors::ChainTicketError, state::Event, constants::{EVENT_SEED, MINT_SEED, VAULT_SEED}},
+    crate::{
+        errors::ChainTicketError,
+        state::{Affiliate, Event, Hold, PlatformConfig, Receipt, RefundRecipient},
+        constants::{
+            AFFILIATE_SEED, EVENT_SEED, HOLD_SEED, MINT_SEED, PLATFORM_SEED, RECEIPT_SEED,
+            RECEIPT_STATE_SIZE, VAULT_SEED,
+        },
+    },
 };
 
 #[derive(Accounts)]
 pub struct BuyTicket<'info> {
+    #[account(mut)]
 	event: Account<'info, Event>,
     /// CHECK: Address is derived and is a native vault,
     /// in order to facilitate transfers from the vault
@@ -30,15 +38,49 @@ pub struct BuyTicket<'info> {
         address = event.mint @ ChainTicketError::InvalidMint,
     )]
     mint: Account<'info, Mint>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+    )]
+    platform: Account<'info, PlatformConfig>,
     #[account(mut)]
 	pub buyer: Signer<'info>,
+    /// Pays rent for the token account and receipt, either the buyer themselves or the
+    /// platform relayer sponsoring the purchase
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
     #[account(
         init_if_needed, 
-        payer = buyer, 
+        payer = fee_payer, 
         associated_token::mint = mint, 
         associated_token::authority = buyer,
     )]
     buyer_ata: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = fee_payer,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), buyer.key().as_ref()],
+        bump,
+        space = 8 + RECEIPT_STATE_SIZE,
+    )]
+    receipt: Account<'info, Receipt>,
+    /// Optional hold placed by the buyer through `place_hold`, consumed by this purchase
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [HOLD_SEED, event.key().as_ref(), buyer.key().as_ref()],
+        bump = hold.bump,
+        has_one = event,
+    )]
+    hold: Option<Account<'info, Hold>>,
+    /// Optional affiliate the sale was referred by, accrues commission on the price
+    #[account(
+        mut,
+        seeds = [AFFILIATE_SEED, event.key().as_ref(), affiliate.wallet.as_ref()],
+        bump = affiliate.bump,
+        has_one = event,
+    )]
+    affiliate: Option<Account<'info, Affiliate>>,
 	system_program: Program<'info, System>,
 	token_program: Program<'info, Token>,
 	associated_token_program: Program<'info, AssociatedToken>,
@@ -46,14 +88,47 @@ pub struct BuyTicket<'info> {
 
 /// Purchases a ticket by transferring SOL to the event account, and minting a ticket token
 /// to the buyer. The ticket's associated token account is then frozen and the event is set
-/// as delegate. Necessary for refunds and clean-ups.
-pub fn process_buy(ctx: Context<BuyTicket>) -> Result<()> {
+/// as delegate. Necessary for refunds and clean-ups. A receipt recording the price paid is
+/// created alongside the ticket. Passing the buyer's hold purchases the ticket it reserved and
+/// closes the hold. When the platform relayer is the fee payer it can be reimbursed up to the
+/// platform's cap out of the ticket price, the reimbursement is not refunded. For multi-day
+/// events `sessions` selects the sessions the pass is valid for, see `Event::sell_sessions`.
+/// Passing an affiliate accrues its commission on the price kept in the vault, paid out with
+/// `claim_commission`.
+pub fn process_buy(ctx: Context<BuyTicket>, sessions: u8, relayer_fee: u64) -> Result<()> {
     require_eq!(ctx.accounts.event.allow_purchase, true, ChainTicketError::SaleNotStarted);
-    require_gte!(
-        ctx.accounts.event.num_tickets as u64, 
-        ctx.accounts.mint.supply, 
-        ChainTicketError::MaxTicketsExceeded
-    );
+
+    let price = ctx.accounts.event.sell_sessions(sessions)?;
+
+    // Only the platform relayer can be reimbursed, and never beyond the cap or the price
+    if relayer_fee > 0 {
+        require_keys_eq!(
+            ctx.accounts.fee_payer.key(),
+            ctx.accounts.platform.relayer,
+            ChainTicketError::Unauthorised
+        );
+        require_gte!(
+            ctx.accounts.platform.relayer_fee_cap.min(price),
+            relayer_fee,
+            ChainTicketError::RelayerFeeExceeded
+        );
+    }
+
+    // A hold has already reserved capacity, consume it instead of taking from general sale
+    match &ctx.accounts.hold {
+        Some(hold) => {
+            let clock = Clock::get()?;
+            require_gt!(hold.expires_at, clock.unix_timestamp, ChainTicketError::HoldExpired);
+            ctx.accounts.event.held_tickets = ctx.accounts.event.held_tickets.saturating_sub(1);
+        }
+        None => {
+            require_gt!(
+                ctx.accounts.event.available_tickets(),
+                0,
+                ChainTicketError::MaxTicketsExceeded
+            );
+        }
+    }
 
     require_gte!(1, ctx.accounts.buyer_ata.amount, ChainTicketError::AlreadyPurchased);
 
@@ -62,7 +137,7 @@ pub fn process_buy(ctx: Context<BuyTicket>) -> Result<()> {
         &anchor_lang::solana_program::system_instruction::transfer(
             &ctx.accounts.buyer.key(),
             &ctx.accounts.vault.key(),
-            ctx.accounts.event.ticket_price,
+            price,
         ),
         &[
             ctx.accounts.buyer.to_account_info(),
@@ -71,6 +146,12 @@ pub fn process_buy(ctx: Context<BuyTicket>) -> Result<()> {
         ],
     )?;
 
+    // Reimburse the relayer out of the ticket price
+    if relayer_fee > 0 {
+        **ctx.accounts.vault.try_borrow_mut_lamports()? -= relayer_fee;
+        **ctx.accounts.fee_payer.try_borrow_mut_lamports()? += relayer_fee;
+    }
+
     // Mint the token (which is the ticket)
     mint_to(
         CpiContext::new_with_signer(
@@ -124,6 +205,47 @@ pub fn process_buy(ctx: Context<BuyTicket>) -> Result<()> {
         ]],
     ))?;
 
+    ctx.accounts.receipt.bump = ctx.bumps.receipt;
+    ctx.accounts.receipt.event = ctx.accounts.event.key();
+    ctx.accounts.receipt.payer = ctx.accounts.buyer.key();
+    ctx.accounts.receipt.rent_payer = ctx.accounts.fee_payer.key();
+    ctx.accounts.receipt.holder = ctx.accounts.buyer.key();
+    ctx.accounts.receipt.price = price - relayer_fee;
+    ctx.accounts.receipt.refund_recipient = RefundRecipient::Holder;
+    ctx.accounts.receipt.comp = false;
+    ctx.accounts.receipt.checked_in_at = 0;
+    ctx.accounts.receipt.sessions = sessions;
+    ctx.accounts.receipt.checked_in_sessions = 0;
+    ctx.accounts.receipt.attendance_claimed = false;
+    ctx.accounts.receipt.transfers = 0;
+    ctx.accounts.receipt.issued_slot = Clock::get()?.slot;
+
+    ctx.accounts.event.record_sale(price - relayer_fee)?;
+
+    // Accrue the referring affiliate's commission, recorded on the receipt for refunds
+    match &mut ctx.accounts.affiliate {
+        Some(affiliate) => {
+            require_keys_neq!(
+                affiliate.wallet,
+                ctx.accounts.buyer.key(),
+                ChainTicketError::InvalidAffiliate
+            );
+            let commission = affiliate.record_sale(price - relayer_fee)?;
+            ctx.accounts.event.commissions_accrued = ctx
+                .accounts
+                .event
+                .commissions_accrued
+                .checked_add(commission)
+                .ok_or(ChainTicketError::Overflow)?;
+            ctx.accounts.receipt.affiliate = affiliate.key();
+            ctx.accounts.receipt.commission = commission;
+        }
+        None => {
+            ctx.accounts.receipt.affiliate = Pubkey::default();
+            ctx.accounts.receipt.commission = 0;
+        }
+    }
+
     Ok(())
 }
 
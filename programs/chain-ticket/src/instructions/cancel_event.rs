@@ -1,12 +1,10 @@
 use {
     crate::{
-        constants::{DEPOSIT_AMOUNT, EVENT_SEED, MINT_SEED, PLATFORM_OWNER, VAULT_SEED},
+        constants::{EVENT_SEED, PLATFORM_OWNER, VAULT_SEED},
         errors::ChainTicketError,
         state::Event,
-        utils::sol_to_lamports,
     },
     anchor_lang::prelude::*,
-    anchor_spl::token::Mint,
 };
 
 #[derive(Accounts)]
@@ -27,12 +25,6 @@ pub struct CancelEvent<'info> {
         close = authority,
     )]
     event: Account<'info, Event>,
-    #[account(
-        seeds = [MINT_SEED, event.key().as_ref()],
-        bump,
-        address = event.mint @ ChainTicketError::InvalidMint,
-    )]
-    mint: Account<'info, Mint>,
     /// CHECK: Address is derived and is a native vault,
     /// in order to facilitate transfers from the vault
     /// it must have no data and thus no discriminator.
@@ -46,12 +38,23 @@ pub struct CancelEvent<'info> {
     system_program: Program<'info, System>,
 }
 
+/// Cancels the event, forfeiting the deposit to the platform and closing the event and vault
+/// accounts. Every ticket must have been refunded or burned, every queued wallet must have left
+/// the waitlist and every offer must have been filled or cancelled.
 pub fn process_cancel(ctx: Context<CancelEvent>) -> Result<()> {
-    // Ensure token supply == 0 i.e. all tickets have been refunded
-    require_eq!(ctx.accounts.mint.supply, 0, ChainTicketError::NonZeroSupply);
+    // Ensure all tickets have been refunded or burned
+    require_eq!(
+        ctx.accounts.event.outstanding_tickets(),
+        0,
+        ChainTicketError::NonZeroSupply
+    );
+
+    // Ensure no escrow is held for the event
+    require_eq!(ctx.accounts.event.waitlisted, 0, ChainTicketError::WaitlistNotEmpty);
+    require_eq!(ctx.accounts.event.open_offers, 0, ChainTicketError::OffersOpen);
 
     // Forfeit SOL deposit
-    let deposit_amount = sol_to_lamports(DEPOSIT_AMOUNT as f64);
+    let deposit_amount = ctx.accounts.event.deposit;
     **ctx.accounts.vault.try_borrow_mut_lamports()? -= deposit_amount;
     **ctx.accounts.platform_owner.try_borrow_mut_lamports()? += deposit_amount; 
 
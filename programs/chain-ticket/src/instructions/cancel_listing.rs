@@ -0,0 +1,124 @@
+use {
+    crate::{
+        constants::{EVENT_SEED, LISTING_SEED, MINT_SEED},
+        errors::ChainTicketError,
+        state::{Event, Listing},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{
+            approve_checked, close_account, freeze_account, transfer, ApproveChecked,
+            CloseAccount, FreezeAccount, Mint, Token, TokenAccount, Transfer,
+        },
+    },
+};
+
+#[derive(Accounts)]
+pub struct CancelListing<'info> {
+    event: Account<'info, Event>,
+    #[account(
+        seeds = [MINT_SEED, event.key().as_ref()],
+        bump,
+        address = event.mint @ ChainTicketError::InvalidMint,
+    )]
+    mint: Account<'info, Mint>,
+    #[account(mut)]
+    seller: Signer<'info>,
+    /// Re-created if the seller closed it while the ticket was listed
+    #[account(
+        init_if_needed,
+        payer = seller,
+        associated_token::mint = mint,
+        associated_token::authority = seller,
+    )]
+    seller_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        close = seller,
+        seeds = [LISTING_SEED, event.key().as_ref(), seller.key().as_ref()],
+        bump = listing.bump,
+        has_one = event,
+        has_one = seller @ ChainTicketError::Unauthorised,
+    )]
+    listing: Account<'info, Listing>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = listing,
+    )]
+    escrow_ata: Account<'info, TokenAccount>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Withdraws a listing, returning the ticket from escrow to the seller's token account which is
+/// re-delegated to the event and frozen as it was before the listing. The seller's token account
+/// is re-created at the seller's expense if it was closed while the ticket was in escrow.
+pub fn process_cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+    let event_seeds: &[&[u8]] = &[
+        EVENT_SEED,
+        ctx.accounts.event.authority.as_ref(),
+        &[ctx.accounts.event.bump],
+    ];
+    let event_key = ctx.accounts.event.key();
+    let listing_seeds: &[&[u8]] = &[
+        LISTING_SEED,
+        event_key.as_ref(),
+        ctx.accounts.listing.seller.as_ref(),
+        &[ctx.accounts.listing.bump],
+    ];
+
+    // Return the ticket from escrow
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow_ata.to_account_info(),
+                to: ctx.accounts.seller_ata.to_account_info(),
+                authority: ctx.accounts.listing.to_account_info(),
+            },
+            &[listing_seeds],
+        ),
+        1,
+    )?;
+
+    // Close the escrow account
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow_ata.to_account_info(),
+            destination: ctx.accounts.seller.to_account_info(),
+            authority: ctx.accounts.listing.to_account_info(),
+        },
+        &[listing_seeds],
+    ))?;
+
+    // Restore the event as delegate, see `process_buy`
+    approve_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            ApproveChecked {
+                to: ctx.accounts.seller_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                delegate: ctx.accounts.event.to_account_info(),
+                authority: ctx.accounts.seller.to_account_info(),
+            },
+        ),
+        1,
+        0,
+    )?;
+
+    freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.seller_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.event.to_account_info(),
+        },
+        &[event_seeds],
+    ))?;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use {
+    crate::{
+        constants::OFFER_SEED,
+        errors::ChainTicketError,
+        state::Offer,
+        utils::update_open_event,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct CancelOffer<'info> {
+    /// CHECK: Checked against the event stored on the offer, may have been closed since the offer
+    /// was made
+    #[account(mut)]
+    event: UncheckedAccount<'info>,
+    /// The buyer, or anyone once the event has started
+    caller: Signer<'info>,
+    /// CHECK: Checked against the offer, receives the escrow and rent
+    #[account(
+        mut,
+        address = offer.buyer @ ChainTicketError::Unauthorised,
+    )]
+    buyer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [OFFER_SEED, event.key().as_ref(), buyer.key().as_ref()],
+        bump = offer.bump,
+        has_one = event,
+    )]
+    offer: Account<'info, Offer>,
+}
+
+/// Cancels an unfilled offer, returning the escrowed price and rent to the buyer. The buyer can
+/// cancel at any time, once the event has started the offer can no longer be filled and anyone
+/// can crank the refund. The event is read from the address stored on the offer so that the
+/// escrow can still be returned if the event account has been closed.
+pub fn process_cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
+    let clock = Clock::get()?;
+    let buyer_cancelled = ctx.accounts.caller.key() == ctx.accounts.offer.buyer;
+
+    update_open_event(&ctx.accounts.event, |event| {
+        require!(
+            buyer_cancelled || clock.unix_timestamp >= event.event_date,
+            ChainTicketError::Unauthorised
+        );
+        event.open_offers = event.open_offers.saturating_sub(1);
+        Ok(())
+    })
+}
@@ -0,0 +1,98 @@
+use {
+    crate::{
+        constants::{MINT_SEED, PERMISSION_SCANNER, RECEIPT_SEED, SECONDS_PER_DAY, STAFF_SEED},
+        errors::ChainTicketError,
+        state::{Event, Receipt, Staff},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::{Mint, TokenAccount},
+};
+
+#[derive(Accounts)]
+pub struct CheckIn<'info> {
+    #[account(mut)]
+    event: Account<'info, Event>,
+    #[account(
+        seeds = [MINT_SEED, event.key().as_ref()],
+        bump,
+        address = event.mint @ ChainTicketError::InvalidMint,
+    )]
+    mint: Account<'info, Mint>,
+    ticket_holder: Signer<'info>,
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = ticket_holder,
+    )]
+    ticket_holder_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), ticket_holder.key().as_ref()],
+        bump = receipt.bump,
+        has_one = event,
+    )]
+    receipt: Account<'info, Receipt>,
+    scanner: Signer<'info>,
+    /// Staff account of the scanner, not required when the event authority scans
+    #[account(
+        seeds = [STAFF_SEED, event.key().as_ref(), scanner.key().as_ref()],
+        bump = staff.bump,
+        has_one = event,
+    )]
+    staff: Option<Account<'info, Staff>>,
+}
+
+/// Marks a ticket as used for a session. Must be co-signed by the ticket holder and a key
+/// holding the scanner permission for the event, and can only succeed once per ticket and
+/// session. For multi-day events the ticket must be entitled to the session and the check-in
+/// must happen within a day of the session's date, single session events always pass `0`. The
+/// ticket is kept by the holder as a souvenir rather than burnt.
+pub fn process_check_in(ctx: Context<CheckIn>, session: u8) -> Result<()> {
+    require!(
+        ctx.accounts.event.is_permitted(
+            &ctx.accounts.scanner.key(),
+            ctx.accounts.staff.as_deref(),
+            PERMISSION_SCANNER,
+        ),
+        ChainTicketError::Unauthorised
+    );
+    require_eq!(ctx.accounts.ticket_holder_ata.amount, 1, ChainTicketError::NoTicket);
+
+    let clock = Clock::get()?;
+    let index = session as usize;
+    let session_bit = 1u8.checked_shl(session as u32).ok_or(ChainTicketError::InvalidSessions)?;
+
+    if ctx.accounts.event.sessions.is_empty() {
+        require_eq!(session, 0, ChainTicketError::InvalidSessions);
+    } else {
+        require_gt!(
+            ctx.accounts.event.sessions.len(),
+            index,
+            ChainTicketError::InvalidSessions
+        );
+        require!(
+            ctx.accounts.receipt.sessions & session_bit != 0,
+            ChainTicketError::NotEntitled
+        );
+
+        let date = ctx.accounts.event.sessions[index].date;
+        require!(
+            (date - SECONDS_PER_DAY..date + SECONDS_PER_DAY).contains(&clock.unix_timestamp),
+            ChainTicketError::SessionNotOpen
+        );
+    }
+
+    require!(
+        ctx.accounts.receipt.checked_in_sessions & session_bit == 0,
+        ChainTicketError::AlreadyCheckedIn
+    );
+
+    ctx.accounts.event.record_check_in(&ctx.accounts.receipt)?;
+    ctx.accounts.receipt.checked_in_sessions |= session_bit;
+    ctx.accounts.receipt.checked_in_at = clock.unix_timestamp;
+
+    if let Some(session) = ctx.accounts.event.sessions.get_mut(index) {
+        session.attendance += 1;
+    }
+
+    Ok(())
+}
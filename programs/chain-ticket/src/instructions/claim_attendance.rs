@@ -0,0 +1,95 @@
+use {
+    crate::{
+        constants::{ATTENDANCE_MINT_SEED, EVENT_SEED, RECEIPT_SEED},
+        errors::ChainTicketError,
+        state::{Event, Receipt},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{freeze_account, mint_to, FreezeAccount, Mint, MintTo, Token, TokenAccount},
+    },
+};
+
+#[derive(Accounts)]
+pub struct ClaimAttendance<'info> {
+    event: Account<'info, Event>,
+    #[account(mut)]
+    ticket_holder: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), ticket_holder.key().as_ref()],
+        bump = receipt.bump,
+        has_one = event,
+    )]
+    receipt: Account<'info, Receipt>,
+    #[account(
+        mut,
+        seeds = [ATTENDANCE_MINT_SEED, event.key().as_ref()],
+        bump,
+    )]
+    attendance_mint: Account<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = ticket_holder,
+        associated_token::mint = attendance_mint,
+        associated_token::authority = ticket_holder,
+    )]
+    ticket_holder_attendance_ata: Account<'info, TokenAccount>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Mints a proof of attendance token to a ticket holder that has been checked in. Unless the event
+/// made souvenirs transferable the token account is frozen so the souvenir cannot be transferred,
+/// allowing other communities to gate on holding it. Can only be claimed once per ticket.
+pub fn process_claim_attendance(ctx: Context<ClaimAttendance>) -> Result<()> {
+    require_neq!(
+        ctx.accounts.receipt.checked_in_sessions,
+        0,
+        ChainTicketError::NotCheckedIn
+    );
+    require!(
+        !ctx.accounts.receipt.attendance_claimed,
+        ChainTicketError::AttendanceClaimed
+    );
+
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.attendance_mint.to_account_info(),
+                to: ctx.accounts.ticket_holder_attendance_ata.to_account_info(),
+                authority: ctx.accounts.event.to_account_info(),
+            },
+            &[&[
+                EVENT_SEED,
+                ctx.accounts.event.authority.as_ref(),
+                &[ctx.accounts.event.bump],
+            ]],
+        ),
+        1,
+    )?;
+
+    // Freeze the ATA so that the souvenir cannot be transferred
+    if !ctx.accounts.event.attendance_transferable {
+        freeze_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                account: ctx.accounts.ticket_holder_attendance_ata.to_account_info(),
+                mint: ctx.accounts.attendance_mint.to_account_info(),
+                authority: ctx.accounts.event.to_account_info(),
+            },
+            &[&[
+                EVENT_SEED,
+                ctx.accounts.event.authority.as_ref(),
+                &[ctx.accounts.event.bump],
+            ]],
+        ))?;
+    }
+
+    ctx.accounts.receipt.attendance_claimed = true;
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use {
+    crate::{
+        constants::{CHECK_IN_BATCH_SEED, RECEIPT_SEED},
+        errors::ChainTicketError,
+        state::{CheckInBatch, Event, Receipt},
+        utils::{check_in_leaf, verify_merkle_proof},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct ClaimCheckIn<'info> {
+    #[account(mut)]
+    event: Account<'info, Event>,
+    #[account(
+        seeds = [CHECK_IN_BATCH_SEED, event.key().as_ref(), batch.index.to_le_bytes().as_ref()],
+        bump = batch.bump,
+        has_one = event,
+    )]
+    batch: Account<'info, CheckInBatch>,
+    #[account(
+        mut,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), receipt.holder.as_ref()],
+        bump = receipt.bump,
+        has_one = event,
+    )]
+    receipt: Account<'info, Receipt>,
+}
+
+/// Records an offline check-in on the ticket's receipt by proving its leaf is part of a settled
+/// batch. The receipt ends up in the same state as after `check_in`, so the ticket can claim a
+/// proof of attendance and can no longer be refunded. Anyone can submit the proof, typically the
+/// scanner that settled the batch, so holders cannot avoid a recorded check-in.
+pub fn process_claim_check_in(
+    ctx: Context<ClaimCheckIn>,
+    session: u8,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let leaf = check_in_leaf(
+        &ctx.accounts.receipt.key(),
+        ctx.accounts.receipt.issued_slot,
+        session,
+    );
+    require!(
+        verify_merkle_proof(&proof, &ctx.accounts.batch.root, leaf),
+        ChainTicketError::InvalidCheckInProof
+    );
+
+    let index = session as usize;
+    let session_bit = 1u8.checked_shl(session as u32).ok_or(ChainTicketError::InvalidSessions)?;
+
+    if ctx.accounts.event.sessions.is_empty() {
+        require_eq!(session, 0, ChainTicketError::InvalidSessions);
+    } else {
+        require!(
+            ctx.accounts.receipt.sessions & session_bit != 0,
+            ChainTicketError::NotEntitled
+        );
+    }
+
+    require!(
+        ctx.accounts.receipt.checked_in_sessions & session_bit == 0,
+        ChainTicketError::AlreadyCheckedIn
+    );
+
+    ctx.accounts.event.record_check_in(&ctx.accounts.receipt)?;
+    ctx.accounts.receipt.checked_in_sessions |= session_bit;
+    ctx.accounts.receipt.checked_in_at = ctx.accounts.batch.settled_at;
+
+    if let Some(session) = ctx.accounts.event.sessions.get_mut(index) {
+        session.attendance += 1;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use {
+    crate::{
+        constants::{AFFILIATE_SEED, EVENT_SEED, VAULT_SEED},
+        errors::ChainTicketError,
+        state::{Affiliate, Event},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct ClaimCommission<'info> {
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event.authority.as_ref()],
+        bump = event.bump,
+    )]
+    event: Account<'info, Event>,
+    /// CHECK: Address is derived and is a native vault,
+    /// in order to facilitate transfers from the vault
+    /// it must have no data and thus no discriminator.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event.key().as_ref()],
+        bump,
+        address = event.vault @ ChainTicketError::InvalidVault,
+    )]
+    vault: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [AFFILIATE_SEED, event.key().as_ref(), wallet.key().as_ref()],
+        bump = affiliate.bump,
+        has_one = event,
+        has_one = wallet,
+    )]
+    affiliate: Account<'info, Affiliate>,
+    /// CHECK: Checked against the affiliate, receives the commission
+    #[account(mut)]
+    wallet: UncheckedAccount<'info>,
+}
+
+/// Pays the affiliate the commission accrued since its last payout. Commission is paid out of the
+/// vault once the proceeds can be withdrawn, after the refund period has elapsed, so refunded
+/// sales no longer earn commission. Can be called by anyone, flagged events cannot pay out.
+/// `withdraw_funds` also pays out the commission owed to every affiliate.
+pub fn process_claim_commission(ctx: Context<ClaimCommission>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require_gte!(
+        clock.unix_timestamp,
+        ctx.accounts.event.refund_period_end(),
+        ChainTicketError::EventNotEnded
+    );
+    require!(!ctx.accounts.event.flagged, ChainTicketError::EventFlagged);
+
+    require_gt!(
+        ctx.accounts.affiliate.unpaid()?,
+        0,
+        ChainTicketError::NothingAccrued
+    );
+
+    pay_commission(
+        &mut ctx.accounts.event,
+        &ctx.accounts.vault,
+        &mut ctx.accounts.affiliate,
+        &ctx.accounts.wallet,
+    )?;
+
+    Ok(())
+}
+
+/// Pays `affiliate` the commission accrued since its last payout out of the vault to its `wallet`.
+/// Returns the amount paid.
+pub fn pay_commission(
+    event: &mut Event,
+    vault: &AccountInfo,
+    affiliate: &mut Affiliate,
+    wallet: &AccountInfo,
+) -> Result<u64> {
+    let amount = affiliate.unpaid()?;
+
+    **vault.try_borrow_mut_lamports()? -= amount;
+    **wallet.try_borrow_mut_lamports()? += amount;
+
+    affiliate.record_payout(amount)?;
+    event.record_commission_payout(amount)?;
+
+    Ok(amount)
+}
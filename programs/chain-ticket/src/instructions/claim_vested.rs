@@ -0,0 +1,95 @@
+use {
+    crate::{
+        constants::{EVENT_SEED, PLATFORM_OWNER, PLATFORM_SEED, PROFILE_SEED, VAULT_SEED},
+        errors::ChainTicketError,
+        instructions::pay_proceeds,
+        state::{Event, OrganiserProfile, PlatformConfig},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    /// CHECK: Checked with constraint
+    #[account(
+        mut,
+        address = PLATFORM_OWNER @ ChainTicketError::Unauthorised,
+    )]
+    platform_owner: UncheckedAccount<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+    )]
+    platform: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        address = event.authority @ ChainTicketError::Unauthorised,
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [PROFILE_SEED, authority.key().as_ref()],
+        bump = profile.bump,
+    )]
+    profile: Account<'info, OrganiserProfile>,
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump,
+    )]
+    event: Account<'info, Event>,
+    /// CHECK: Address is derived and is a native vault,
+    /// in order to facilitate transfers from the vault
+    /// it must have no data and thus no discriminator.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event.key().as_ref()],
+        bump,
+        address = event.vault @ ChainTicketError::InvalidVault,
+    )]
+    vault: UncheckedAccount<'info>,
+}
+
+/// Releases the proceeds vested since the last claim. Vesting starts once the refund period has
+/// elapsed, the event's proceeds at the first claim then unlock linearly over the event's
+/// vesting period. Claims are paid out like a withdrawal, see `pay_proceeds`, the deposit and
+/// anything left are settled by `withdraw_funds` once vesting ends. Flagged events cannot claim.
+pub fn process_claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require_gte!(
+        clock.unix_timestamp,
+        ctx.accounts.event.refund_period_end(),
+        ChainTicketError::EventNotEnded
+    );
+    require!(!ctx.accounts.event.flagged, ChainTicketError::EventFlagged);
+
+    ctx.accounts.event.start_vesting()?;
+
+    let amount = ctx
+        .accounts
+        .event
+        .claimable_vested(clock.unix_timestamp)?;
+    require_gt!(amount, 0, ChainTicketError::NothingVested);
+
+    **ctx.accounts.vault.try_borrow_mut_lamports()? -= amount;
+    let platform_fee = pay_proceeds(
+        &ctx.accounts.event,
+        &ctx.accounts.platform_owner,
+        &ctx.accounts.authority,
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.platform.fee_bps(&ctx.accounts.profile),
+    )?;
+
+    ctx.accounts.event.vested_claimed = ctx
+        .accounts
+        .event
+        .vested_claimed
+        .checked_add(amount)
+        .ok_or(ChainTicketError::Overflow)?;
+    ctx.accounts
+        .event
+        .record_withdrawal(amount, platform_fee)?;
+
+    Ok(())
+}
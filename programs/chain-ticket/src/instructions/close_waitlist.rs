@@ -0,0 +1,45 @@
+use {
+    crate::{
+        constants::{EVENT_SEED, WAITLIST_SEED},
+        errors::ChainTicketError,
+        state::{Event, Waitlist},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct CloseWaitlist<'info> {
+    #[account(
+        mut,
+        address = event.authority @ ChainTicketError::Unauthorised,
+    )]
+    authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump,
+    )]
+    event: Account<'info, Event>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [WAITLIST_SEED, event.key().as_ref()],
+        bump = waitlist.bump,
+        has_one = event,
+    )]
+    waitlist: Account<'info, Waitlist>,
+}
+
+/// Closes an empty waitlist, reclaiming rent. Every queued wallet must have either been served
+/// or left the queue so that no escrow is swept to the organiser.
+pub fn process_close_waitlist(ctx: Context<CloseWaitlist>) -> Result<()> {
+    require!(
+        ctx.accounts.waitlist.entries.is_empty(),
+        ChainTicketError::WaitlistNotEmpty
+    );
+
+    ctx.accounts.event.waitlisted = 0;
+    ctx.accounts.event.waitlist_offers = 0;
+
+    Ok(())
+}
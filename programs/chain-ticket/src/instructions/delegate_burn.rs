@@ -1,8 +1,8 @@
 use {
     crate::{
-        constants::{EVENT_SEED, MINT_SEED},
+        constants::{EVENT_SEED, MINT_SEED, RECEIPT_SEED},
         errors::ChainTicketError,
-        state::Event,
+        state::{Event, Receipt},
     },
     anchor_lang::prelude::*,
     anchor_spl::token::{burn, thaw_account, Burn, Mint, ThawAccount, Token, TokenAccount},
@@ -16,6 +16,7 @@ pub struct DelegateBurn<'info> {
     )]
     pub authority: Signer<'info>,
     #[account(
+        mut,
         seeds = [
             EVENT_SEED,
             authority.key().as_ref(),
@@ -39,6 +40,20 @@ pub struct DelegateBurn<'info> {
         associated_token::authority = target_wallet,
     )]
     pub target_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), target_wallet.key().as_ref()],
+        bump = receipt.bump,
+        has_one = event,
+    )]
+    pub receipt: Account<'info, Receipt>,
+    /// CHECK: Checked against the receipt, receives the receipt rent
+    #[account(
+        mut,
+        address = receipt.rent_payer @ ChainTicketError::InvalidReceiptPayer,
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
     pub token_program: Program<'info, Token>,
 }
 
@@ -84,5 +99,8 @@ pub fn process_delegate_burn(ctx: Context<DelegateBurn>) -> Result<()> {
         1,
     )?;
 
+    ctx.accounts.event.release_sessions(ctx.accounts.receipt.sessions);
+    ctx.accounts.event.record_burn()?;
+
     Ok(())
 }
@@ -1,13 +1,10 @@
 use {
     crate::{
-        constants::{EVENT_SEED, EVENT_STATE_SIZE, MINT_SEED, VAULT_SEED},
+        constants::{EVENT_SEED, MINT_SEED, VAULT_SEED},
         errors::ChainTicketError,
         state::Event,
     },
-    anchor_lang::{
-        prelude::*,
-        solana_program::{clock::Clock, rent::Rent},
-    },
+    anchor_lang::{prelude::*, solana_program::clock::Clock},
     anchor_spl::token::{Mint, Token},
 };
 
@@ -47,12 +44,12 @@ pub struct EndEvent<'info> {
 }
 
 /// Ends the event by closing the mint and the event accounts relcaiming rent in the process.
-/// Can only be called if the associated mint's supply is 0. I.e. requires burning all tokens.
+/// Can only be called once the proceeds and affiliate commissions have been paid out and every
+/// ticket has been refunded or burned, according to the event's ledger, and once no wallet is
+/// queued on the waitlist and every offer has been filled or cancelled so that no escrow is left
+/// behind.
 pub fn process_end(ctx: Context<EndEvent>) -> Result<()> {
     let clock = Clock::get()?;
-    let rent = Rent::get()?;
-
-    let event_lamports = ctx.accounts.event.get_lamports();
 
     // Check the event has ended
     require_gte!(
@@ -61,11 +58,20 @@ pub fn process_end(ctx: Context<EndEvent>) -> Result<()> {
         ChainTicketError::EventNotEnded,
     );
 
-    // Check that funds have been withdrawn by comparing against the minimum rent amount
-    require_gte!(rent.minimum_balance(8 + EVENT_STATE_SIZE), event_lamports);
+    // Check that the proceeds have been withdrawn
+    require_eq!(ctx.accounts.event.proceeds()?, 0, ChainTicketError::FundsNotWithdrawn);
+    require_eq!(ctx.accounts.event.commissions_owed(), 0, ChainTicketError::CommissionsUnpaid);
 
-    // Check mint supply
-    require_eq!(ctx.accounts.mint.supply, 0, ChainTicketError::NonZeroSupply);
+    // Check no escrow is held for the event
+    require_eq!(ctx.accounts.event.waitlisted, 0, ChainTicketError::WaitlistNotEmpty);
+    require_eq!(ctx.accounts.event.open_offers, 0, ChainTicketError::OffersOpen);
+
+    // Check all tickets have been refunded or burned
+    require_eq!(
+        ctx.accounts.event.outstanding_tickets(),
+        0,
+        ChainTicketError::NonZeroSupply
+    );
 
     // Close vault 
     ctx.accounts
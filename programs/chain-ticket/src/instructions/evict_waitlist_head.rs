@@ -0,0 +1,77 @@
+use {
+    crate::{
+        constants::{RECEIPT_SEED, WAITLIST_SEED},
+        errors::ChainTicketError,
+        state::{Event, Receipt, Waitlist},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{associated_token::get_associated_token_address, token::Token},
+};
+
+#[derive(Accounts)]
+pub struct EvictWaitlistHead<'info> {
+    /// Anyone once the head's token account has been closed, otherwise the event authority
+    operator: Signer<'info>,
+    #[account(mut)]
+    event: Account<'info, Event>,
+    #[account(
+        mut,
+        seeds = [WAITLIST_SEED, event.key().as_ref()],
+        bump = waitlist.bump,
+        has_one = event,
+    )]
+    waitlist: Account<'info, Waitlist>,
+    /// CHECK: Checked against the head of the waitlist, receives the escrow and receipt rent
+    #[account(mut)]
+    wallet: UncheckedAccount<'info>,
+    /// CHECK: Address is derived, may have been closed by the wallet
+    #[account(
+        constraint = wallet_ata.key() == get_associated_token_address(&wallet.key(), &event.mint)
+            @ ChainTicketError::InvalidTokenAccount,
+    )]
+    wallet_ata: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = wallet,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), wallet.key().as_ref()],
+        bump = receipt.bump,
+        has_one = event,
+    )]
+    receipt: Account<'info, Receipt>,
+}
+
+/// Removes the wallet at the head of the waitlist so the queue cannot stall on it. Anyone can
+/// evict the head once its token account has been closed, as `serve_waitlist` can no longer mint
+/// to it, the event authority can evict the head at any time. The escrow and the receipt's rent
+/// are returned to the wallet as in `leave_waitlist`, and a freed ticket held for the queue stays
+/// reserved for the next wallet.
+pub fn process_evict_waitlist_head(ctx: Context<EvictWaitlistHead>) -> Result<()> {
+    require!(
+        !ctx.accounts.waitlist.entries.is_empty(),
+        ChainTicketError::NotWaitlisted
+    );
+    require_keys_eq!(
+        ctx.accounts.waitlist.entries[0].wallet,
+        ctx.accounts.wallet.key(),
+        ChainTicketError::NotWaitlistHead
+    );
+
+    let ata_closed = ctx.accounts.wallet_ata.data_is_empty()
+        || *ctx.accounts.wallet_ata.owner != Token::id();
+    require!(
+        ata_closed || ctx.accounts.operator.key() == ctx.accounts.event.authority,
+        ChainTicketError::Unauthorised
+    );
+
+    let entry = ctx.accounts.waitlist.entries.remove(0);
+
+    // Return escrow
+    **ctx.accounts.waitlist.to_account_info().try_borrow_mut_lamports()? -= entry.escrow;
+    **ctx.accounts.wallet.try_borrow_mut_lamports()? += entry.escrow;
+
+    let event = &mut ctx.accounts.event;
+    event.waitlisted = event.waitlisted.saturating_sub(1);
+    event.waitlist_offers = event.waitlist_offers.min(event.waitlisted);
+
+    Ok(())
+}
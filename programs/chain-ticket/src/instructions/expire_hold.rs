@@ -0,0 +1,39 @@
+use {
+    crate::{constants::HOLD_SEED, errors::ChainTicketError, state::{Event, Hold}},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct ExpireHold<'info> {
+    #[account(mut)]
+    event: Account<'info, Event>,
+    /// CHECK: Checked against the hold, only receives the reclaimed rent
+    #[account(
+        mut,
+        address = hold.holder @ ChainTicketError::Unauthorised,
+    )]
+    holder: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = holder,
+        seeds = [HOLD_SEED, event.key().as_ref(), holder.key().as_ref()],
+        bump = hold.bump,
+        has_one = event,
+    )]
+    hold: Account<'info, Hold>,
+}
+
+/// Permissionless instruction that releases a stale hold back into inventory. Rent for the
+/// hold account is returned to the holder.
+pub fn process_expire_hold(ctx: Context<ExpireHold>) -> Result<()> {
+    let clock = Clock::get()?;
+    require_gte!(
+        clock.unix_timestamp,
+        ctx.accounts.hold.expires_at,
+        ChainTicketError::HoldNotExpired
+    );
+
+    ctx.accounts.event.held_tickets = ctx.accounts.event.held_tickets.saturating_sub(1);
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use {
+    crate::{
+        constants::PLATFORM_SEED,
+        errors::ChainTicketError,
+        state::{Event, PlatformConfig},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct FlagEvent<'info> {
+    #[account(address = platform.authority @ ChainTicketError::Unauthorised)]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+    )]
+    platform: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    event: Account<'info, Event>,
+}
+
+/// Flags or clears an event, callable by the platform authority only. Flagged events cannot pay
+/// out proceeds and holders can reclaim what is left in the vault with `refund_flagged`.
+pub fn process_flag_event(ctx: Context<FlagEvent>, flagged: bool) -> Result<()> {
+    ctx.accounts.event.flagged = flagged;
+
+    Ok(())
+}
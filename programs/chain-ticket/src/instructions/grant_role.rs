@@ -0,0 +1,45 @@
+use {
+    crate::{
+        constants::{EVENT_SEED, STAFF_SEED, STAFF_STATE_SIZE},
+        errors::ChainTicketError,
+        state::{Event, Staff},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    #[account(
+        mut,
+        address = event.authority @ ChainTicketError::Unauthorised,
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump,
+    )]
+    event: Account<'info, Event>,
+    /// CHECK: Only used as the key of the staff member
+    staff_key: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [STAFF_SEED, event.key().as_ref(), staff_key.key().as_ref()],
+        bump,
+        space = 8 + STAFF_STATE_SIZE,
+    )]
+    staff: Account<'info, Staff>,
+    system_program: Program<'info, System>,
+}
+
+/// Grants `permissions` (a combination of `PERMISSION_*` flags) to a staff key on top of any
+/// permissions it already holds, so that the event authority's wallet does not need to be used
+/// for day to day operations.
+pub fn process_grant_role(ctx: Context<GrantRole>, permissions: u8) -> Result<()> {
+    ctx.accounts.staff.bump = ctx.bumps.staff;
+    ctx.accounts.staff.event = ctx.accounts.event.key();
+    ctx.accounts.staff.key = ctx.accounts.staff_key.key();
+    ctx.accounts.staff.permissions |= permissions;
+
+    Ok(())
+}
@@ -9,6 +9,7 @@ use {
     crate::{
         constants::{
             EVENT_SEED, VAULT_SEED, MINT_SEED, METADATA_SEED, EVENT_STATE_SIZE, SECONDS_PER_DAY,
+            DEFAULT_HOLD_TTL,
         },
         errors::ChainTicketError,
         state::Event,
@@ -98,6 +99,7 @@ pub fn process_init(ctx: Context<InitEvent>, data: InitEventFields) -> Result<()
     ctx.accounts.event.event_date = data.event_date;
     ctx.accounts.event.ticket_price = data.ticket_price;
     ctx.accounts.event.num_tickets = data.num_tickets;
+    ctx.accounts.event.hold_ttl = DEFAULT_HOLD_TTL;
 
     // Create token metadata (used for wallets to read name, symbol, and token image)
     create_metadata_accounts_v3(
@@ -0,0 +1,109 @@
+use {
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        metadata::{
+            create_metadata_accounts_v3, mpl_token_metadata::types::DataV2, CreateMetadataAccountsV3, Metadata
+        },
+        token::{Token, Mint},
+    },
+    crate::{
+        constants::{ATTENDANCE_MINT_SEED, EVENT_SEED, METADATA_SEED},
+        errors::ChainTicketError,
+        state::Event,
+    },
+};
+
+#[derive(Accounts)]
+pub struct InitAttendance<'info> {
+    #[account(
+        mut,
+        address = event.authority @ ChainTicketError::Unauthorised,
+    )]
+    authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump,
+    )]
+    event: Account<'info, Event>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [ATTENDANCE_MINT_SEED, event.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = event,
+        mint::freeze_authority = event,
+    )]
+    attendance_mint: Account<'info, Mint>,
+
+    /// CHECK: Safe - PDA
+    #[account(
+        mut,
+        seeds = [
+            METADATA_SEED,
+            token_metadata_program.key().as_ref(),
+            attendance_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    metadata: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    token_metadata_program: Program<'info, Metadata>,
+    rent: Sysvar<'info, Rent>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct InitAttendanceFields {
+    name: String,
+    symbol: String,
+    uri: String,
+    transferable: bool,
+}
+
+/// Creates the event's proof of attendance mint along with its token metadata, see
+/// `process_init`. Checked in ticket holders claim a souvenir from this mint with
+/// `claim_attendance`. Souvenirs are frozen unless the event makes them `transferable`.
+pub fn process_init_attendance(
+    ctx: Context<InitAttendance>,
+    data: InitAttendanceFields,
+) -> Result<()> {
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                mint: ctx.accounts.attendance_mint.to_account_info(),
+                mint_authority: ctx.accounts.event.to_account_info(),
+                update_authority: ctx.accounts.event.to_account_info(),
+                payer: ctx.accounts.authority.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            &[&[
+                EVENT_SEED,
+                ctx.accounts.authority.key().as_ref(),
+                &[ctx.accounts.event.bump],
+            ]],
+        ),
+        DataV2 {
+            name: data.name,
+            symbol: data.symbol,
+            uri: data.uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        false,
+        true,
+        None,
+    )?;
+
+    ctx.accounts.event.attendance_transferable = data.transferable;
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use {
+    crate::{
+        constants::{BPS_DENOMINATOR, PLATFORM_OWNER, PLATFORM_SEED, PLATFORM_STATE_SIZE},
+        errors::ChainTicketError,
+        state::PlatformConfig,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct InitPlatform<'info> {
+    #[account(
+        mut,
+        address = PLATFORM_OWNER @ ChainTicketError::IncorrectPlatformOwner,
+    )]
+    authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [PLATFORM_SEED],
+        bump,
+        space = 8 + PLATFORM_STATE_SIZE,
+    )]
+    platform: Account<'info, PlatformConfig>,
+    system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitPlatformFields {
+    pub relayer: Pubkey,
+    pub relayer_fee_cap: u64,
+    pub treasury: Pubkey,
+    pub resale_royalty_bps: u16,
+    pub advance_bps: u16,
+    pub vesting_period: i64,
+    pub deposit_bps: u16,
+    pub deposit_min: u64,
+    pub deposit_max: u64,
+}
+
+/// Creates the platform wide configuration, can only be called once by the platform owner.
+pub fn process_init_platform(ctx: Context<InitPlatform>, data: InitPlatformFields) -> Result<()> {
+    require_gte!(
+        BPS_DENOMINATOR,
+        data.resale_royalty_bps as u64,
+        ChainTicketError::InvalidBasisPoints
+    );
+    require_gte!(
+        BPS_DENOMINATOR,
+        data.advance_bps as u64,
+        ChainTicketError::InvalidBasisPoints
+    );
+    require_gte!(
+        data.deposit_max,
+        data.deposit_min,
+        ChainTicketError::InvalidDepositBounds
+    );
+
+    ctx.accounts.platform.bump = ctx.bumps.platform;
+    ctx.accounts.platform.authority = ctx.accounts.authority.key();
+    ctx.accounts.platform.relayer = data.relayer;
+    ctx.accounts.platform.relayer_fee_cap = data.relayer_fee_cap;
+    ctx.accounts.platform.treasury = data.treasury;
+    ctx.accounts.platform.resale_royalty_bps = data.resale_royalty_bps;
+    ctx.accounts.platform.advance_bps = data.advance_bps;
+    ctx.accounts.platform.vesting_period = data.vesting_period.max(0);
+    ctx.accounts.platform.deposit_bps = data.deposit_bps;
+    ctx.accounts.platform.deposit_min = data.deposit_min;
+    ctx.accounts.platform.deposit_max = data.deposit_max;
+
+    Ok(())
+}
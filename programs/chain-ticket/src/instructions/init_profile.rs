@@ -0,0 +1,31 @@
+use {
+    crate::{
+        constants::{PROFILE_SEED, PROFILE_STATE_SIZE},
+        state::OrganiserProfile,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct InitProfile<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [PROFILE_SEED, authority.key().as_ref()],
+        bump,
+        space = 8 + PROFILE_STATE_SIZE,
+    )]
+    profile: Account<'info, OrganiserProfile>,
+    system_program: Program<'info, System>,
+}
+
+/// Creates the organiser's profile, accumulating the sales of its events to determine the
+/// platform fee tier. Required to withdraw proceeds.
+pub fn process_init_profile(ctx: Context<InitProfile>) -> Result<()> {
+    ctx.accounts.profile.bump = ctx.bumps.profile;
+    ctx.accounts.profile.authority = ctx.accounts.authority.key();
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use {
+    crate::{
+        constants::{EVENT_SEED, WAITLIST_SEED, WAITLIST_STATE_SIZE},
+        errors::ChainTicketError,
+        state::{Event, Waitlist},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct InitWaitlist<'info> {
+    #[account(
+        mut,
+        address = event.authority @ ChainTicketError::Unauthorised,
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump,
+    )]
+    event: Account<'info, Event>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [WAITLIST_SEED, event.key().as_ref()],
+        bump,
+        space = 8 + WAITLIST_STATE_SIZE,
+    )]
+    waitlist: Account<'info, Waitlist>,
+    system_program: Program<'info, System>,
+}
+
+/// Creates the waitlist queue for an event. The waitlist account also acts as the escrow for
+/// lamports deposited by queued wallets.
+pub fn process_init_waitlist(ctx: Context<InitWaitlist>) -> Result<()> {
+    ctx.accounts.waitlist.bump = ctx.bumps.waitlist;
+    ctx.accounts.waitlist.event = ctx.accounts.event.key();
+    ctx.accounts.waitlist.entries = Vec::new();
+
+    Ok(())
+}
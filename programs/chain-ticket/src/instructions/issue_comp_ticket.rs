@@ -0,0 +1,148 @@
+use {
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        token::{
+            Token, TokenAccount, Mint, MintTo, mint_to, ApproveChecked, approve_checked,
+            FreezeAccount, freeze_account,
+        },
+        associated_token::AssociatedToken
+    },
+    crate::{
+        errors::ChainTicketError,
+        state::{Event, Receipt, RefundRecipient},
+        constants::{EVENT_SEED, MINT_SEED, RECEIPT_SEED, RECEIPT_STATE_SIZE},
+    },
+};
+
+#[derive(Accounts)]
+pub struct IssueCompTicket<'info> {
+    #[account(
+        mut,
+        address = event.authority @ ChainTicketError::Unauthorised,
+    )]
+    authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump,
+    )]
+    event: Account<'info, Event>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED, event.key().as_ref()],
+        bump,
+        address = event.mint @ ChainTicketError::InvalidMint,
+    )]
+    mint: Account<'info, Mint>,
+    /// The guest only signs to approve the event as delegate on their token account, the
+    /// organiser pays for everything
+    guest: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = guest,
+    )]
+    guest_ata: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), guest.key().as_ref()],
+        bump,
+        space = 8 + RECEIPT_STATE_SIZE,
+    )]
+    receipt: Account<'info, Receipt>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Issues a complimentary ticket to a guest at no cost. Comps count against the event's
+/// capacity, are limited by `comp_quota` and are marked on their receipt so that they cannot be
+/// refunded. The ticket is otherwise minted, delegated and frozen exactly as in `process_buy`.
+pub fn process_issue_comp(ctx: Context<IssueCompTicket>, sessions: u8) -> Result<()> {
+    require_gt!(
+        ctx.accounts.event.available_tickets(),
+        0,
+        ChainTicketError::MaxTicketsExceeded
+    );
+    require_gt!(
+        ctx.accounts.event.comp_quota,
+        ctx.accounts.event.comps_issued,
+        ChainTicketError::CompQuotaExceeded
+    );
+    require_eq!(ctx.accounts.guest_ata.amount, 0, ChainTicketError::AlreadyPurchased);
+
+    ctx.accounts.event.sell_sessions(sessions)?;
+
+    // Mint the ticket to the guest
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.guest_ata.to_account_info(),
+                authority: ctx.accounts.event.to_account_info(),
+            },
+            &[&[
+                EVENT_SEED,
+                ctx.accounts.event.authority.as_ref(),
+                &[ctx.accounts.event.bump],
+            ]],
+        ),
+        1,
+    )?;
+
+    // Set the event as delegate, see `process_buy`
+    approve_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            ApproveChecked {
+                to: ctx.accounts.guest_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                delegate: ctx.accounts.event.to_account_info(),
+                authority: ctx.accounts.guest.to_account_info(),
+            },
+        ),
+        1,
+        0,
+    )?;
+
+    // Freeze the ATA so that the guest cannot transfer
+    freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.guest_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.event.to_account_info(),
+        },
+        &[&[
+            EVENT_SEED,
+            ctx.accounts.event.authority.as_ref(),
+            &[ctx.accounts.event.bump],
+        ]],
+    ))?;
+
+    ctx.accounts.receipt.bump = ctx.bumps.receipt;
+    ctx.accounts.receipt.event = ctx.accounts.event.key();
+    ctx.accounts.receipt.payer = ctx.accounts.authority.key();
+    ctx.accounts.receipt.rent_payer = ctx.accounts.authority.key();
+    ctx.accounts.receipt.holder = ctx.accounts.guest.key();
+    ctx.accounts.receipt.price = 0;
+    ctx.accounts.receipt.refund_recipient = RefundRecipient::Holder;
+    ctx.accounts.receipt.comp = true;
+    ctx.accounts.receipt.checked_in_at = 0;
+    ctx.accounts.receipt.sessions = sessions;
+    ctx.accounts.receipt.checked_in_sessions = 0;
+    ctx.accounts.receipt.attendance_claimed = false;
+    ctx.accounts.receipt.transfers = 0;
+    ctx.accounts.receipt.affiliate = Pubkey::default();
+    ctx.accounts.receipt.commission = 0;
+    ctx.accounts.receipt.issued_slot = Clock::get()?.slot;
+
+    ctx.accounts.event.record_sale(0)?;
+
+    ctx.accounts.event.comps_issued += 1;
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use {
+    crate::{
+        constants::{
+            MAX_WAITLIST_ENTRIES, MINT_SEED, RECEIPT_SEED, RECEIPT_STATE_SIZE, WAITLIST_SEED,
+        },
+        errors::ChainTicketError,
+        state::{Event, Receipt, RefundRecipient, Waitlist, WaitlistEntry},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{approve_checked, ApproveChecked, Mint, Token, TokenAccount},
+    },
+};
+
+#[derive(Accounts)]
+pub struct JoinWaitlist<'info> {
+    #[account(mut)]
+    event: Account<'info, Event>,
+    #[account(
+        mut,
+        seeds = [WAITLIST_SEED, event.key().as_ref()],
+        bump = waitlist.bump,
+        has_one = event,
+    )]
+    waitlist: Account<'info, Waitlist>,
+    #[account(
+        seeds = [MINT_SEED, event.key().as_ref()],
+        bump,
+        address = event.mint @ ChainTicketError::InvalidMint,
+    )]
+    mint: Account<'info, Mint>,
+    #[account(mut)]
+    wallet: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        associated_token::mint = mint,
+        associated_token::authority = wallet,
+    )]
+    wallet_ata: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = wallet,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), wallet.key().as_ref()],
+        bump,
+        space = 8 + RECEIPT_STATE_SIZE,
+    )]
+    receipt: Account<'info, Receipt>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Joins the waitlist of a sold out event by escrowing the ticket price in the waitlist account.
+/// The event is approved as delegate on the wallet's token account up front so that the ticket
+/// can later be minted and frozen by `serve_waitlist` without the wallet having to sign again.
+/// The ticket's receipt is created now for the same reason.
+pub fn process_join_waitlist(ctx: Context<JoinWaitlist>) -> Result<()> {
+    require_eq!(ctx.accounts.event.allow_purchase, true, ChainTicketError::SaleNotStarted);
+    // Freed tickets of multi-day events only cover some sessions, the waitlist hands out
+    // single session tickets only
+    require!(ctx.accounts.event.sessions.is_empty(), ChainTicketError::InvalidSessions);
+    require_eq!(
+        ctx.accounts.event.available_tickets(),
+        0,
+        ChainTicketError::TicketsAvailable
+    );
+    require_eq!(ctx.accounts.wallet_ata.amount, 0, ChainTicketError::AlreadyPurchased);
+    require_gt!(
+        MAX_WAITLIST_ENTRIES,
+        ctx.accounts.waitlist.entries.len(),
+        ChainTicketError::WaitlistFull
+    );
+    require!(
+        !ctx.accounts
+            .waitlist
+            .entries
+            .iter()
+            .any(|entry| entry.wallet == ctx.accounts.wallet.key()),
+        ChainTicketError::AlreadyWaitlisted
+    );
+
+    let escrow = ctx.accounts.event.ticket_price;
+
+    // Escrow the ticket price in the waitlist account
+    anchor_lang::solana_program::program::invoke(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.wallet.key(),
+            &ctx.accounts.waitlist.key(),
+            escrow,
+        ),
+        &[
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.waitlist.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    // Approve the event as delegate ahead of the mint, see `process_buy`
+    approve_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            ApproveChecked {
+                to: ctx.accounts.wallet_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                delegate: ctx.accounts.event.to_account_info(),
+                authority: ctx.accounts.wallet.to_account_info(),
+            },
+        ),
+        1,
+        0,
+    )?;
+
+    ctx.accounts.receipt.bump = ctx.bumps.receipt;
+    ctx.accounts.receipt.event = ctx.accounts.event.key();
+    ctx.accounts.receipt.payer = ctx.accounts.wallet.key();
+    ctx.accounts.receipt.rent_payer = ctx.accounts.wallet.key();
+    ctx.accounts.receipt.holder = ctx.accounts.wallet.key();
+    ctx.accounts.receipt.price = escrow;
+    ctx.accounts.receipt.refund_recipient = RefundRecipient::Holder;
+    ctx.accounts.receipt.comp = false;
+    ctx.accounts.receipt.checked_in_at = 0;
+    ctx.accounts.receipt.sessions = 0;
+    ctx.accounts.receipt.checked_in_sessions = 0;
+    ctx.accounts.receipt.attendance_claimed = false;
+    ctx.accounts.receipt.transfers = 0;
+    ctx.accounts.receipt.affiliate = Pubkey::default();
+    ctx.accounts.receipt.commission = 0;
+    ctx.accounts.receipt.issued_slot = Clock::get()?.slot;
+
+    ctx.accounts.waitlist.entries.push(WaitlistEntry {
+        wallet: ctx.accounts.wallet.key(),
+        escrow,
+    });
+    ctx.accounts.event.waitlisted = ctx
+        .accounts
+        .event
+        .waitlisted
+        .checked_add(1)
+        .ok_or(ChainTicketError::Overflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use {
+    crate::{
+        constants::{RECEIPT_SEED, WAITLIST_SEED},
+        errors::ChainTicketError,
+        state::{Receipt, Waitlist},
+        utils::update_open_event,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct LeaveWaitlist<'info> {
+    /// CHECK: Checked against the event stored on the waitlist, may have been closed since the
+    /// wallet joined
+    #[account(mut)]
+    event: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [WAITLIST_SEED, event.key().as_ref()],
+        bump = waitlist.bump,
+        has_one = event,
+    )]
+    waitlist: Account<'info, Waitlist>,
+    #[account(mut)]
+    wallet: Signer<'info>,
+    #[account(
+        mut,
+        close = wallet,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), wallet.key().as_ref()],
+        bump = receipt.bump,
+        has_one = event,
+    )]
+    receipt: Account<'info, Receipt>,
+}
+
+/// Removes a wallet from the waitlist, returning its escrowed lamports and closing the receipt
+/// created when it joined. If a freed ticket was being held for the queue and there are no longer
+/// enough queued wallets to take it, the reservation is released back to general sale. The escrow
+/// can still be returned if the event account has been closed.
+pub fn process_leave_waitlist(ctx: Context<LeaveWaitlist>) -> Result<()> {
+    let position = ctx
+        .accounts
+        .waitlist
+        .entries
+        .iter()
+        .position(|entry| entry.wallet == ctx.accounts.wallet.key())
+        .ok_or(ChainTicketError::NotWaitlisted)?;
+
+    let entry = ctx.accounts.waitlist.entries.remove(position);
+
+    // Return escrow
+    **ctx.accounts.waitlist.to_account_info().try_borrow_mut_lamports()? -= entry.escrow;
+    **ctx.accounts.wallet.try_borrow_mut_lamports()? += entry.escrow;
+
+    update_open_event(&ctx.accounts.event, |event| {
+        event.waitlisted = event.waitlisted.saturating_sub(1);
+        event.waitlist_offers = event.waitlist_offers.min(event.waitlisted);
+        Ok(())
+    })
+}
@@ -0,0 +1,120 @@
+use {
+    crate::{
+        constants::{EVENT_SEED, LISTING_SEED, LISTING_STATE_SIZE, MINT_SEED, RECEIPT_SEED},
+        errors::ChainTicketError,
+        state::{Event, Listing, Receipt},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{thaw_account, transfer, Mint, ThawAccount, Token, TokenAccount, Transfer},
+    },
+};
+
+#[derive(Accounts)]
+pub struct ListTicket<'info> {
+    event: Account<'info, Event>,
+    #[account(
+        seeds = [MINT_SEED, event.key().as_ref()],
+        bump,
+        address = event.mint @ ChainTicketError::InvalidMint,
+    )]
+    mint: Account<'info, Mint>,
+    #[account(mut)]
+    seller: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = seller,
+    )]
+    seller_ata: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [RECEIPT_SEED, event.key().as_ref(), seller.key().as_ref()],
+        bump = receipt.bump,
+        has_one = event,
+    )]
+    receipt: Account<'info, Receipt>,
+    #[account(
+        init,
+        payer = seller,
+        seeds = [LISTING_SEED, event.key().as_ref(), seller.key().as_ref()],
+        bump,
+        space = 8 + LISTING_STATE_SIZE,
+    )]
+    listing: Account<'info, Listing>,
+    #[account(
+        init,
+        payer = seller,
+        associated_token::mint = mint,
+        associated_token::authority = listing,
+    )]
+    escrow_ata: Account<'info, TokenAccount>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Lists a ticket for resale at `price`, which cannot exceed the organiser's resale cap applied
+/// to the ticket's face value. A resale is a transfer so the event's transfer rules apply, see
+/// `Event::check_transfer`. The event uses its delegate approval to move the ticket from the
+/// seller's frozen token account into an escrow account owned by the listing. The emptied token
+/// account is left thawed, as after a refund.
+pub fn process_list_ticket(ctx: Context<ListTicket>, price: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require_gt!(ctx.accounts.event.resale_cap_bps, 0, ChainTicketError::ResaleDisabled);
+    require!(!ctx.accounts.event.flagged, ChainTicketError::EventFlagged);
+    require_gt!(
+        ctx.accounts.event.event_date,
+        clock.unix_timestamp,
+        ChainTicketError::EventStarted
+    );
+    ctx.accounts
+        .event
+        .check_transfer(clock.unix_timestamp, ctx.accounts.receipt.transfers)?;
+    require_gte!(
+        ctx.accounts.event.max_resale_price(ctx.accounts.receipt.price)?,
+        price,
+        ChainTicketError::ResalePriceExceeded
+    );
+    require_eq!(ctx.accounts.receipt.checked_in_sessions, 0, ChainTicketError::AlreadyCheckedIn);
+    require_eq!(ctx.accounts.seller_ata.amount, 1, ChainTicketError::NoTicket);
+
+    let event_seeds: &[&[u8]] = &[
+        EVENT_SEED,
+        ctx.accounts.event.authority.as_ref(),
+        &[ctx.accounts.event.bump],
+    ];
+
+    // Thaw the seller's token account so the ticket can be moved
+    thaw_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ThawAccount {
+            account: ctx.accounts.seller_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.event.to_account_info(),
+        },
+        &[event_seeds],
+    ))?;
+
+    // Move the ticket into escrow using the event's delegate approval
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.seller_ata.to_account_info(),
+                to: ctx.accounts.escrow_ata.to_account_info(),
+                authority: ctx.accounts.event.to_account_info(),
+            },
+            &[event_seeds],
+        ),
+        1,
+    )?;
+
+    ctx.accounts.listing.bump = ctx.bumps.listing;
+    ctx.accounts.listing.event = ctx.accounts.event.key();
+    ctx.accounts.listing.seller = ctx.accounts.seller.key();
+    ctx.accounts.listing.price = price;
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use {
+    crate::{
+        constants::{MINT_SEED, OFFER_SEED, OFFER_STATE_SIZE},
+        errors::ChainTicketError,
+        state::{Event, Offer},
+        utils::transfer_lamports,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{approve_checked, ApproveChecked, Mint, Token, TokenAccount},
+    },
+};
+
+#[derive(Accounts)]
+pub struct MakeOffer<'info> {
+    #[account(mut)]
+    event: Account<'info, Event>,
+    #[account(
+        seeds = [MINT_SEED, event.key().as_ref()],
+        bump,
+        address = event.mint @ ChainTicketError::InvalidMint,
+    )]
+    mint: Account<'info, Mint>,
+    #[account(mut)]
+    buyer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = mint,
+        associated_token::authority = buyer,
+    )]
+    buyer_ata: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = buyer,
+        seeds = [OFFER_SEED, event.key().as_ref(), buyer.key().as_ref()],
+        bump,
+        space = 8 + OFFER_STATE_SIZE,
+    )]
+    offer: Account<'info, Offer>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Posts a standing offer of `price` lamports for a ticket of the event carrying the `sessions`
+/// entitlement. The price is escrowed in the offer account and the event is approved as delegate
+/// on the buyer's token account up front, so that any holder can fill the offer with
+/// `accept_offer` without the buyer having to sign again.
+pub fn process_make_offer(ctx: Context<MakeOffer>, price: u64, sessions: u8) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require_gt!(ctx.accounts.event.resale_cap_bps, 0, ChainTicketError::ResaleDisabled);
+    require_gt!(
+        ctx.accounts.event.event_date,
+        clock.unix_timestamp,
+        ChainTicketError::EventStarted
+    );
+    require_eq!(ctx.accounts.buyer_ata.amount, 0, ChainTicketError::AlreadyPurchased);
+    ctx.accounts.event.check_sessions(sessions)?;
+
+    // Escrow the offered price in the offer account
+    transfer_lamports(
+        &ctx.accounts.buyer.to_account_info(),
+        &ctx.accounts.offer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        price,
+    )?;
+
+    // Approve the event as delegate ahead of the transfer, see `process_buy`
+    approve_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            ApproveChecked {
+                to: ctx.accounts.buyer_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                delegate: ctx.accounts.event.to_account_info(),
+                authority: ctx.accounts.buyer.to_account_info(),
+            },
+        ),
+        1,
+        0,
+    )?;
+
+    ctx.accounts.offer.bump = ctx.bumps.offer;
+    ctx.accounts.offer.event = ctx.accounts.event.key();
+    ctx.accounts.offer.buyer = ctx.accounts.buyer.key();
+    ctx.accounts.offer.price = price;
+    ctx.accounts.offer.sessions = sessions;
+
+    ctx.accounts.event.open_offers = ctx
+        .accounts
+        .event
+        .open_offers
+        .checked_add(1)
+        .ok_or(ChainTicketError::Overflow)?;
+
+    Ok(())
+}
@@ -8,6 +8,48 @@ pub mod delegate_burn;
 pub mod withdraw_funds;
 pub mod cancel_event;
 pub mod end_event;
+pub mod init_waitlist;
+pub mod join_waitlist;
+pub mod leave_waitlist;
+pub mod serve_waitlist;
+pub mod close_waitlist;
+pub mod place_hold;
+pub mod expire_hold;
+pub mod buy_gift_ticket;
+pub mod init_platform;
+pub mod update_platform;
+pub mod issue_comp_ticket;
+pub mod register_agent;
+pub mod agent_issue_ticket;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod check_in;
+pub mod set_sessions;
+pub mod init_attendance;
+pub mod claim_attendance;
+pub mod settle_check_ins;
+pub mod claim_check_in;
+pub mod list_ticket;
+pub mod cancel_listing;
+pub mod buy_listing;
+pub mod transfer_ticket;
+pub mod swap_tickets;
+pub mod swap_sessions;
+pub mod make_offer;
+pub mod accept_offer;
+pub mod cancel_offer;
+pub mod set_payees;
+pub mod advance_funds;
+pub mod claim_vested;
+pub mod flag_event;
+pub mod refund_flagged;
+pub mod init_profile;
+pub mod set_fee_exemption;
+pub mod set_fee_tiers;
+pub mod register_affiliate;
+pub mod claim_commission;
+pub mod evict_waitlist_head;
+pub mod update_metadata;
 
 pub use amend_details::*;
 pub use init::*;
@@ -19,3 +61,45 @@ pub use delegate_burn::*;
 pub use withdraw_funds::*;
 pub use cancel_event::*;
 pub use end_event::*;
+pub use init_waitlist::*;
+pub use join_waitlist::*;
+pub use leave_waitlist::*;
+pub use serve_waitlist::*;
+pub use close_waitlist::*;
+pub use place_hold::*;
+pub use expire_hold::*;
+pub use buy_gift_ticket::*;
+pub use init_platform::*;
+pub use update_platform::*;
+pub use issue_comp_ticket::*;
+pub use register_agent::*;
+pub use agent_issue_ticket::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use check_in::*;
+pub use set_sessions::*;
+pub use init_attendance::*;
+pub use claim_attendance::*;
+pub use settle_check_ins::*;
+pub use claim_check_in::*;
+pub use list_ticket::*;
+pub use cancel_listing::*;
+pub use buy_listing::*;
+pub use transfer_ticket::*;
+pub use swap_tickets::*;
+pub use swap_sessions::*;
+pub use make_offer::*;
+pub use accept_offer::*;
+pub use cancel_offer::*;
+pub use set_payees::*;
+pub use advance_funds::*;
+pub use claim_vested::*;
+pub use flag_event::*;
+pub use refund_flagged::*;
+pub use init_profile::*;
+pub use set_fee_exemption::*;
+pub use set_fee_tiers::*;
+pub use register_affiliate::*;
+pub use claim_commission::*;
+pub use evict_waitlist_head::*;
+pub use update_metadata::*;
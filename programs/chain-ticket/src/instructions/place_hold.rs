@@ -0,0 +1,59 @@
+use {
+    crate::{
+        constants::{HOLD_SEED, HOLD_STATE_SIZE},
+        errors::ChainTicketError,
+        state::{Event, Hold},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct PlaceHold<'info> {
+    #[account(mut)]
+    event: Account<'info, Event>,
+    #[account(mut)]
+    holder: Signer<'info>,
+    #[account(
+        init,
+        payer = holder,
+        seeds = [HOLD_SEED, event.key().as_ref(), holder.key().as_ref()],
+        bump,
+        space = 8 + HOLD_STATE_SIZE,
+    )]
+    hold: Account<'info, Hold>,
+    system_program: Program<'info, System>,
+}
+
+/// Reserves a ticket for the holder for `event.hold_ttl` seconds so that payment can be completed
+/// across multiple transactions. The ticket is bought by passing the hold to `buy_ticket`, if
+/// that does not happen before the hold expires anyone can release it with `expire_hold`. A wallet
+/// holds at most one ticket and holds are capped at a share of the unsold tickets, see
+/// `Event::can_hold`.
+pub fn process_place_hold(ctx: Context<PlaceHold>) -> Result<()> {
+    require_eq!(ctx.accounts.event.allow_purchase, true, ChainTicketError::SaleNotStarted);
+    require_gt!(
+        ctx.accounts.event.available_tickets(),
+        0,
+        ChainTicketError::MaxTicketsExceeded
+    );
+    require!(ctx.accounts.event.can_hold()?, ChainTicketError::HoldLimitReached);
+
+    let clock = Clock::get()?;
+
+    ctx.accounts.hold.bump = ctx.bumps.hold;
+    ctx.accounts.hold.event = ctx.accounts.event.key();
+    ctx.accounts.hold.holder = ctx.accounts.holder.key();
+    ctx.accounts.hold.expires_at = clock
+        .unix_timestamp
+        .checked_add(ctx.accounts.event.hold_ttl)
+        .ok_or(ChainTicketError::Overflow)?;
+
+    ctx.accounts.event.held_tickets = ctx
+        .accounts
+        .event
+        .held_tickets
+        .checked_add(1)
+        .ok_or(ChainTicketError::Overflow)?;
+
+    Ok(())
+}
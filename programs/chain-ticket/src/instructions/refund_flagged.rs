@@ -0,0 +1,137 @@
+use {
+    crate::{
+        constants::{EVENT_SEED, MINT_SEED, RECEIPT_SEED, VAULT_SEED},
+        errors::ChainTicketError,
+        state::{Affiliate, Event, Receipt, RefundRecipient},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::{burn, thaw_account, Burn, Mint, ThawAccount, Token, TokenAccount},
+};
+
+#[derive(Accounts)]
+pub struct RefundFlagged<'info> {
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event.authority.as_ref()],
+        bump = event.bump,
+    )]
+    event: Account<'info, Event>,
+    /// CHECK: Address is derived and is a native vault,
+    /// in order to facilitate transfers from the vault
+    /// it must have no data and thus no discriminator.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event.key().as_ref()],
+        bump,
+        address = event.vault @ ChainTicketError::InvalidVault,
+    )]
+    vault: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED, event.key().as_ref()],
+        bump,
+        address = event.mint @ ChainTicketError::InvalidMint,
+    )]
+    mint: Account<'info, Mint>,
+    #[account(mut)]
+    holder: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = holder,
+    )]
+    holder_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), holder.key().as_ref()],
+        bump = receipt.bump,
+        has_one = event,
+    )]
+    receipt: Account<'info, Receipt>,
+    /// CHECK: Checked against the receipt, may receive the refund
+    #[account(
+        mut,
+        address = receipt.payer @ ChainTicketError::InvalidReceiptPayer,
+    )]
+    payer: UncheckedAccount<'info>,
+    /// CHECK: Checked against the receipt, receives the receipt rent
+    #[account(
+        mut,
+        address = receipt.rent_payer @ ChainTicketError::InvalidReceiptPayer,
+    )]
+    rent_payer: UncheckedAccount<'info>,
+    /// Affiliate credited with the sale, required if the receipt records a commission
+    #[account(
+        mut,
+        address = receipt.affiliate @ ChainTicketError::InvalidAffiliate,
+    )]
+    affiliate: Option<Account<'info, Affiliate>>,
+    token_program: Program<'info, Token>,
+}
+
+/// Lets a holder of a flagged event reclaim their ticket's price from the unvested proceeds left in
+/// the vault, burning the ticket as in `process_refund`. Once the proceeds run out later claims
+/// are paid what remains. The deposit is not available for refunds. The affiliate commission
+/// accrued on the sale is reversed first unless it has been paid out, as in `process_refund`.
+pub fn process_refund_flagged(ctx: Context<RefundFlagged>) -> Result<()> {
+    require!(ctx.accounts.event.flagged, ChainTicketError::EventNotFlagged);
+    require!(!ctx.accounts.receipt.comp, ChainTicketError::CompNotRefundable);
+    require_eq!(ctx.accounts.receipt.checked_in_at, 0, ChainTicketError::AlreadyCheckedIn);
+
+    let event_seeds: &[&[u8]] = &[
+        EVENT_SEED,
+        ctx.accounts.event.authority.as_ref(),
+        &[ctx.accounts.event.bump],
+    ];
+
+    // Thaw token account so ticket can be burnt
+    thaw_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ThawAccount {
+            account: ctx.accounts.holder_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.event.to_account_info(),
+        },
+        &[event_seeds],
+    ))?;
+
+    burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.holder_ata.to_account_info(),
+                authority: ctx.accounts.event.to_account_info(),
+            },
+            &[event_seeds],
+        ),
+        1,
+    )?;
+
+    // Reverse the commission of the referring affiliate, freeing it for the refund
+    ctx.accounts.event.reverse_commission(
+        &ctx.accounts.receipt,
+        ctx.accounts.affiliate.as_deref_mut(),
+    )?;
+
+    let refund = ctx
+        .accounts
+        .receipt
+        .price
+        .min(ctx.accounts.event.proceeds()?);
+
+    let refund_recipient = match ctx.accounts.receipt.refund_recipient {
+        RefundRecipient::Payer => ctx.accounts.payer.to_account_info(),
+        RefundRecipient::Holder => ctx.accounts.holder.to_account_info(),
+    };
+    **ctx.accounts.vault.try_borrow_mut_lamports()? -= refund;
+    **refund_recipient.try_borrow_mut_lamports()? += refund;
+
+    ctx.accounts
+        .event
+        .release_sessions(ctx.accounts.receipt.sessions);
+    ctx.accounts.event.record_refund(refund)?;
+
+    Ok(())
+}
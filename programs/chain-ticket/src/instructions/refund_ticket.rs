@@ -1,8 +1,10 @@
 use {
     crate::{
-        constants::{EVENT_SEED, MINT_SEED, VAULT_SEED},
+        constants::{
+            EVENT_SEED, MINT_SEED, PERMISSION_REFUND_AGENT, RECEIPT_SEED, STAFF_SEED, VAULT_SEED,
+        },
         errors::ChainTicketError,
-        state::Event,
+        state::{Affiliate, Event, Receipt, RefundRecipient, Staff},
     },
     anchor_lang::prelude::*,
     anchor_spl::token::{burn, thaw_account, Burn, Mint, ThawAccount, Token, TokenAccount},
@@ -10,11 +12,19 @@ use {
 
 #[derive(Accounts)]
 pub struct RefundTicket<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    /// The event authority or a staff key holding the refund agent permission
+    pub operator: Signer<'info>,
+    /// Staff account of the operator, not required when the event authority refunds
     #[account(
-        seeds = [EVENT_SEED, authority.key().as_ref()],
-        bump,
+        seeds = [STAFF_SEED, event.key().as_ref(), operator.key().as_ref()],
+        bump = staff.bump,
+        has_one = event,
+    )]
+    pub staff: Option<Account<'info, Staff>>,
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event.authority.as_ref()],
+        bump = event.bump,
     )]
     pub event: Account<'info, Event>,
     /// CHECK: Address is derived and is a native vault,
@@ -43,14 +53,56 @@ pub struct RefundTicket<'info> {
         associated_token::authority = buyer,
     )]
     pub buyer_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), buyer.key().as_ref()],
+        bump = receipt.bump,
+        has_one = event,
+    )]
+    pub receipt: Account<'info, Receipt>,
+    /// CHECK: Checked against the receipt, may receive the refund
+    #[account(
+        mut,
+        address = receipt.payer @ ChainTicketError::InvalidReceiptPayer,
+    )]
+    pub payer: UncheckedAccount<'info>,
+    /// CHECK: Checked against the receipt, receives the receipt rent
+    #[account(
+        mut,
+        address = receipt.rent_payer @ ChainTicketError::InvalidReceiptPayer,
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+    /// Affiliate credited with the sale, required if the receipt records a commission
+    #[account(
+        mut,
+        address = receipt.affiliate @ ChainTicketError::InvalidAffiliate,
+    )]
+    pub affiliate: Option<Account<'info, Affiliate>>,
     pub token_program: Program<'info, Token>,
 }
 
 /// Refunds a ticket purchaser. This instruction requires the event account to be set as a
 /// delegate for the purchaser's associated token account. Refunds will fail to process if this is
-/// not the case. This instruction is called by the authority not by the purchaser hence the need
-/// for the event account to be an approved delegate.
+/// not the case. This instruction is called by the authority or a refund agent not by the
+/// purchaser hence the need for the event account to be an approved delegate. The price recorded
+/// on the receipt is returned to the receipt's refund recipient and the receipt is closed. If
+/// wallets are queued on the waitlist the freed ticket is reserved for the head of the queue, see
+/// `serve_waitlist`. The affiliate commission accrued on the sale is reversed unless it has been
+/// paid out. Refunds fail once the proceeds left after advances and vested claims no longer cover
+/// the price.
 pub fn process_refund(ctx: Context<RefundTicket>) -> Result<()> {
+    require!(
+        ctx.accounts.event.is_permitted(
+            &ctx.accounts.operator.key(),
+            ctx.accounts.staff.as_deref(),
+            PERMISSION_REFUND_AGENT,
+        ),
+        ChainTicketError::Unauthorised
+    );
+    require!(!ctx.accounts.receipt.comp, ChainTicketError::CompNotRefundable);
+    require_eq!(ctx.accounts.receipt.checked_in_at, 0, ChainTicketError::AlreadyCheckedIn);
+
     // Thaw token account so ticket can be burnt
     thaw_account(CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
@@ -61,7 +113,7 @@ pub fn process_refund(ctx: Context<RefundTicket>) -> Result<()> {
         },
         &[&[
             EVENT_SEED,
-            ctx.accounts.authority.key().as_ref(),
+            ctx.accounts.event.authority.as_ref(),
             &[ctx.accounts.event.bump],
         ]],
     ))?;
@@ -77,16 +129,34 @@ pub fn process_refund(ctx: Context<RefundTicket>) -> Result<()> {
             },
             &[&[
                 EVENT_SEED,
-                ctx.accounts.authority.key().as_ref(),
+                ctx.accounts.event.authority.as_ref(),
                 &[ctx.accounts.event.bump],
             ]],
         ),
         1,
     )?;
 
-    // Return sol
-    **ctx.accounts.vault.try_borrow_mut_lamports()? -= ctx.accounts.event.ticket_price;
-    **ctx.accounts.buyer.try_borrow_mut_lamports()? += ctx.accounts.event.ticket_price;
+    let event = &mut ctx.accounts.event;
+    // Reverse the commission of the referring affiliate, freeing it for the refund
+    event.reverse_commission(
+        &ctx.accounts.receipt,
+        ctx.accounts.affiliate.as_deref_mut(),
+    )?;
+    event.release_sessions(ctx.accounts.receipt.sessions);
+    event.record_refund(ctx.accounts.receipt.price)?;
+
+    // Offer the freed ticket to the waitlist
+    if event.waitlisted > event.waitlist_offers {
+        event.waitlist_offers += 1;
+    }
+
+    // Return sol to the party recorded on the receipt
+    let refund_recipient = match ctx.accounts.receipt.refund_recipient {
+        RefundRecipient::Payer => ctx.accounts.payer.to_account_info(),
+        RefundRecipient::Holder => ctx.accounts.buyer.to_account_info(),
+    };
+    **ctx.accounts.vault.try_borrow_mut_lamports()? -= ctx.accounts.receipt.price;
+    **refund_recipient.try_borrow_mut_lamports()? += ctx.accounts.receipt.price;
 
     Ok(())
 }
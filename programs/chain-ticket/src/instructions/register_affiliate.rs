@@ -0,0 +1,54 @@
+use {
+    crate::{
+        constants::{AFFILIATE_SEED, AFFILIATE_STATE_SIZE, BPS_DENOMINATOR, EVENT_SEED},
+        errors::ChainTicketError,
+        state::{Affiliate, Event},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct RegisterAffiliate<'info> {
+    #[account(
+        mut,
+        address = event.authority @ ChainTicketError::Unauthorised,
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump,
+    )]
+    event: Account<'info, Event>,
+    /// CHECK: Only used as the key of the affiliate, receives the commission
+    wallet: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [AFFILIATE_SEED, event.key().as_ref(), wallet.key().as_ref()],
+        bump,
+        space = 8 + AFFILIATE_STATE_SIZE,
+    )]
+    affiliate: Account<'info, Affiliate>,
+    system_program: Program<'info, System>,
+}
+
+/// Registers an affiliate for the event or updates the commission rate of an existing one. The
+/// rate applies to sales referred from then on, commission already accrued is kept. Setting the
+/// rate to zero stops the affiliate from earning commission.
+pub fn process_register_affiliate(
+    ctx: Context<RegisterAffiliate>,
+    commission_bps: u16,
+) -> Result<()> {
+    require_gte!(
+        BPS_DENOMINATOR,
+        commission_bps as u64,
+        ChainTicketError::InvalidBasisPoints
+    );
+
+    ctx.accounts.affiliate.bump = ctx.bumps.affiliate;
+    ctx.accounts.affiliate.event = ctx.accounts.event.key();
+    ctx.accounts.affiliate.wallet = ctx.accounts.wallet.key();
+    ctx.accounts.affiliate.commission_bps = commission_bps;
+
+    Ok(())
+}
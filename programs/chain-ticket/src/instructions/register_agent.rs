@@ -0,0 +1,45 @@
+use {
+    crate::{
+        constants::{AGENT_SEED, AGENT_STATE_SIZE, EVENT_SEED},
+        errors::ChainTicketError,
+        state::{Event, SalesAgent},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct RegisterAgent<'info> {
+    #[account(
+        mut,
+        address = event.authority @ ChainTicketError::Unauthorised,
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump,
+    )]
+    event: Account<'info, Event>,
+    /// CHECK: Only used as the key of the sales agent
+    agent: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [AGENT_SEED, event.key().as_ref(), agent.key().as_ref()],
+        bump,
+        space = 8 + AGENT_STATE_SIZE,
+    )]
+    sales_agent: Account<'info, SalesAgent>,
+    system_program: Program<'info, System>,
+}
+
+/// Registers a box office sales agent for the event or updates the quota of an existing one.
+/// The agent's issued count and declared revenue are kept when the quota is updated, setting the
+/// quota to zero revokes the agent.
+pub fn process_register_agent(ctx: Context<RegisterAgent>, quota: u32) -> Result<()> {
+    ctx.accounts.sales_agent.bump = ctx.bumps.sales_agent;
+    ctx.accounts.sales_agent.event = ctx.accounts.event.key();
+    ctx.accounts.sales_agent.agent = ctx.accounts.agent.key();
+    ctx.accounts.sales_agent.quota = quota;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use {
+    crate::{
+        constants::{EVENT_SEED, STAFF_SEED},
+        errors::ChainTicketError,
+        state::{Event, Staff},
+    },
+    anchor_lang::{prelude::*, AccountsClose},
+};
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(
+        mut,
+        address = event.authority @ ChainTicketError::Unauthorised,
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump,
+    )]
+    event: Account<'info, Event>,
+    #[account(
+        mut,
+        seeds = [STAFF_SEED, event.key().as_ref(), staff.key.as_ref()],
+        bump = staff.bump,
+        has_one = event,
+    )]
+    staff: Account<'info, Staff>,
+}
+
+/// Removes `permissions` from a staff key. Once a key holds no permissions its account is closed
+/// and the rent returned to the authority.
+pub fn process_revoke_role(ctx: Context<RevokeRole>, permissions: u8) -> Result<()> {
+    ctx.accounts.staff.permissions &= !permissions;
+
+    if ctx.accounts.staff.permissions == 0 {
+        ctx.accounts
+            .staff
+            .close(ctx.accounts.authority.to_account_info())?;
+    }
+
+    Ok(())
+}
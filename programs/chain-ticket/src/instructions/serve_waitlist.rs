@@ -0,0 +1,113 @@
+use {
+    crate::{
+        constants::{EVENT_SEED, MINT_SEED, VAULT_SEED, WAITLIST_SEED},
+        errors::ChainTicketError,
+        state::{Event, Waitlist},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::{
+        freeze_account, mint_to, FreezeAccount, Mint, MintTo, Token, TokenAccount,
+    },
+};
+
+#[derive(Accounts)]
+pub struct ServeWaitlist<'info> {
+    #[account(mut)]
+    event: Account<'info, Event>,
+    #[account(
+        mut,
+        seeds = [WAITLIST_SEED, event.key().as_ref()],
+        bump = waitlist.bump,
+        has_one = event,
+    )]
+    waitlist: Account<'info, Waitlist>,
+    /// CHECK: Address is derived and is a native vault,
+    /// in order to facilitate transfers from the vault
+    /// it must have no data and thus no discriminator.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event.key().as_ref()],
+        bump,
+        address = event.vault @ ChainTicketError::InvalidVault,
+    )]
+    vault: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [MINT_SEED, event.key().as_ref()],
+        bump,
+        address = event.mint @ ChainTicketError::InvalidMint,
+    )]
+    mint: Account<'info, Mint>,
+    /// CHECK: Checked against the head of the waitlist
+    wallet: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = wallet,
+    )]
+    wallet_ata: Account<'info, TokenAccount>,
+    token_program: Program<'info, Token>,
+}
+
+/// Permissionless crank that hands a ticket freed by a refund to the wallet at the head of the
+/// waitlist. The escrowed lamports are moved to the vault, the ticket is minted to the wallet's
+/// token account (approved at join time) and the account is frozen as in `process_buy`. A head
+/// whose token account has been closed is removed with `evict_waitlist_head`.
+pub fn process_serve_waitlist(ctx: Context<ServeWaitlist>) -> Result<()> {
+    require_gt!(ctx.accounts.event.waitlist_offers, 0, ChainTicketError::NoWaitlistOffer);
+    require!(
+        !ctx.accounts.waitlist.entries.is_empty(),
+        ChainTicketError::NotWaitlisted
+    );
+    require_keys_eq!(
+        ctx.accounts.waitlist.entries[0].wallet,
+        ctx.accounts.wallet.key(),
+        ChainTicketError::NotWaitlistHead
+    );
+
+    let entry = ctx.accounts.waitlist.entries.remove(0);
+
+    // Move escrow to the vault
+    **ctx.accounts.waitlist.to_account_info().try_borrow_mut_lamports()? -= entry.escrow;
+    **ctx.accounts.vault.try_borrow_mut_lamports()? += entry.escrow;
+
+    // Mint the ticket
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.wallet_ata.to_account_info(),
+                authority: ctx.accounts.event.to_account_info(),
+            },
+            &[&[
+                EVENT_SEED,
+                ctx.accounts.event.authority.as_ref(),
+                &[ctx.accounts.event.bump],
+            ]],
+        ),
+        1,
+    )?;
+
+    // Freeze the ATA so that the ticket cannot be transferred
+    freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.wallet_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.event.to_account_info(),
+        },
+        &[&[
+            EVENT_SEED,
+            ctx.accounts.event.authority.as_ref(),
+            &[ctx.accounts.event.bump],
+        ]],
+    ))?;
+
+    let event = &mut ctx.accounts.event;
+    event.waitlisted = event.waitlisted.saturating_sub(1);
+    event.waitlist_offers = event.waitlist_offers.saturating_sub(1);
+    event.record_sale(entry.escrow)?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use {
+    crate::{
+        constants::{PLATFORM_SEED, PROFILE_SEED},
+        errors::ChainTicketError,
+        state::{OrganiserProfile, PlatformConfig},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct SetFeeExemption<'info> {
+    #[account(address = platform.authority @ ChainTicketError::Unauthorised)]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+    )]
+    platform: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, profile.authority.as_ref()],
+        bump = profile.bump,
+    )]
+    profile: Account<'info, OrganiserProfile>,
+}
+
+/// Grants or revokes an organiser's exemption from platform fees, callable by the platform
+/// authority only.
+pub fn process_set_fee_exemption(ctx: Context<SetFeeExemption>, exempt: bool) -> Result<()> {
+    ctx.accounts.profile.fee_exempt = exempt;
+
+    Ok(())
+}
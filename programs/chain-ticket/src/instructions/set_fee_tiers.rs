@@ -0,0 +1,42 @@
+use {
+    crate::{
+        constants::{BPS_DENOMINATOR, MAX_FEE_TIERS, PLATFORM_SEED},
+        errors::ChainTicketError,
+        state::{FeeTier, PlatformConfig},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct SetFeeTiers<'info> {
+    #[account(address = platform.authority @ ChainTicketError::Unauthorised)]
+    authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+    )]
+    platform: Account<'info, PlatformConfig>,
+}
+
+/// Replaces the platform's fee table. An organiser is charged the rate of the highest tier its
+/// lifetime volume reaches, organisers below the lowest tier, or all organisers if the table is
+/// empty, are charged `PLATFORM_FEE_BPS`.
+pub fn process_set_fee_tiers(ctx: Context<SetFeeTiers>, tiers: Vec<FeeTier>) -> Result<()> {
+    require_gte!(MAX_FEE_TIERS, tiers.len(), ChainTicketError::InvalidFeeTiers);
+    require!(
+        tiers.windows(2).all(|pair| pair[0].min_volume < pair[1].min_volume),
+        ChainTicketError::InvalidFeeTiers
+    );
+    for tier in &tiers {
+        require_gte!(
+            BPS_DENOMINATOR,
+            tier.fee_bps as u64,
+            ChainTicketError::InvalidBasisPoints
+        );
+    }
+
+    ctx.accounts.platform.fee_tiers = tiers;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use {
+    crate::{
+        constants::{BPS_DENOMINATOR, EVENT_SEED, MAX_PAYEES},
+        errors::ChainTicketError,
+        state::{Event, Payee},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct SetPayees<'info> {
+    #[account(address = event.authority @ ChainTicketError::Unauthorised)]
+    authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump,
+    )]
+    event: Account<'info, Event>,
+}
+
+/// Replaces the table of payees the event's proceeds are split between on withdrawal, e.g. the
+/// promoter, artist and venue. Shares must add up to the whole of the proceeds, passing an empty
+/// list sends all proceeds to the authority. The table is locked once sales start.
+pub fn process_set_payees(ctx: Context<SetPayees>, payees: Vec<Payee>) -> Result<()> {
+    require_eq!(ctx.accounts.event.allow_purchase, false, ChainTicketError::SaleStarted);
+    require_gte!(MAX_PAYEES, payees.len(), ChainTicketError::InvalidPayees);
+    if !payees.is_empty() {
+        require_eq!(
+            payees.iter().map(|payee| payee.share_bps as u64).sum::<u64>(),
+            BPS_DENOMINATOR,
+            ChainTicketError::InvalidPayees
+        );
+    }
+
+    ctx.accounts.event.payees = payees;
+
+    Ok(())
+}
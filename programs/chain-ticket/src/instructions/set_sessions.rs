@@ -0,0 +1,52 @@
+use {
+    crate::{
+        constants::{EVENT_SEED, MAX_SESSIONS},
+        errors::ChainTicketError,
+        state::{Event, Session},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct SetSessions<'info> {
+    #[account(
+        mut,
+        address = event.authority @ ChainTicketError::Unauthorised,
+    )]
+    authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump,
+    )]
+    event: Account<'info, Event>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SessionFields {
+    pub date: i64,
+    pub price: u64,
+    pub capacity: u32,
+}
+
+/// Replaces the sessions of a multi-day event, e.g. one session per festival day. Passing an
+/// empty list turns the event back into a single session event. Sessions are fixed once sales
+/// start or a ticket has been issued, e.g. a comp, as issued tickets reference them by index.
+pub fn process_set_sessions(ctx: Context<SetSessions>, sessions: Vec<SessionFields>) -> Result<()> {
+    require_eq!(ctx.accounts.event.allow_purchase, false, ChainTicketError::SaleStarted);
+    require_eq!(ctx.accounts.event.tickets_sold, 0, ChainTicketError::SaleStarted);
+    require_gte!(MAX_SESSIONS, sessions.len(), ChainTicketError::InvalidSessions);
+
+    ctx.accounts.event.sessions = sessions
+        .into_iter()
+        .map(|session| Session {
+            date: session.date,
+            price: session.price,
+            capacity: session.capacity,
+            sold: 0,
+            attendance: 0,
+        })
+        .collect();
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use {
+    crate::{
+        constants::{CHECK_IN_BATCH_SEED, CHECK_IN_BATCH_STATE_SIZE, PERMISSION_SCANNER, STAFF_SEED},
+        errors::ChainTicketError,
+        state::{CheckInBatch, Event, Staff},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct SettleCheckIns<'info> {
+    #[account(mut)]
+    scanner: Signer<'info>,
+    /// Staff account of the scanner, not required when the event authority settles
+    #[account(
+        seeds = [STAFF_SEED, event.key().as_ref(), scanner.key().as_ref()],
+        bump = staff.bump,
+        has_one = event,
+    )]
+    staff: Option<Account<'info, Staff>>,
+    #[account(mut)]
+    event: Account<'info, Event>,
+    #[account(
+        init,
+        payer = scanner,
+        seeds = [
+            CHECK_IN_BATCH_SEED,
+            event.key().as_ref(),
+            event.check_in_batches.to_le_bytes().as_ref(),
+        ],
+        bump,
+        space = 8 + CHECK_IN_BATCH_STATE_SIZE,
+    )]
+    batch: Account<'info, CheckInBatch>,
+    system_program: Program<'info, System>,
+}
+
+/// Settles check-ins a scanner accumulated while offline as a single Merkle root. Holders can
+/// then prove their attendance on-chain with `claim_check_in` without a transaction per scan.
+pub fn process_settle_check_ins(
+    ctx: Context<SettleCheckIns>,
+    root: [u8; 32],
+    count: u32,
+) -> Result<()> {
+    require!(
+        ctx.accounts.event.is_permitted(
+            &ctx.accounts.scanner.key(),
+            ctx.accounts.staff.as_deref(),
+            PERMISSION_SCANNER,
+        ),
+        ChainTicketError::Unauthorised
+    );
+
+    let clock = Clock::get()?;
+
+    ctx.accounts.batch.bump = ctx.bumps.batch;
+    ctx.accounts.batch.event = ctx.accounts.event.key();
+    ctx.accounts.batch.scanner = ctx.accounts.scanner.key();
+    ctx.accounts.batch.root = root;
+    ctx.accounts.batch.count = count;
+    ctx.accounts.batch.settled_at = clock.unix_timestamp;
+    ctx.accounts.batch.index = ctx.accounts.event.check_in_batches;
+
+    let event = &mut ctx.accounts.event;
+    event.check_in_batches = event
+        .check_in_batches
+        .checked_add(1)
+        .ok_or(ChainTicketError::Overflow)?;
+    event.settled_check_ins = event
+        .settled_check_ins
+        .checked_add(count)
+        .ok_or(ChainTicketError::Overflow)?;
+
+    Ok(())
+}
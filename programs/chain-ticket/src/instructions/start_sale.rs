@@ -1,9 +1,9 @@
 use {
     crate::{
-        constants::{DEPOSIT_AMOUNT, EVENT_SEED, VAULT_SEED},
+        constants::{EVENT_SEED, PLATFORM_SEED, VAULT_SEED},
         errors::ChainTicketError,
-        state::Event,
-        utils::sol_to_lamports,
+        state::{Event, PlatformConfig},
+        utils::transfer_lamports,
     },
     anchor_lang::prelude::*,
 };
@@ -21,6 +21,11 @@ pub struct StartSale<'info> {
         bump
     )]
     event: Account<'info, Event>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+    )]
+    platform: Account<'info, PlatformConfig>,
 
     /// CHECK: Address is derived and is a native vault,
     /// in order to facilitate transfers from the vault
@@ -36,7 +41,9 @@ pub struct StartSale<'info> {
 }
 
 /// Changes the field in the event state `allow_purchase` to true, event organiser is charged
-/// the deposit amount, will fail if the event organiser has insufficient balance in their wallet
+/// the deposit amount, will fail if the event organiser has insufficient balance in their wallet.
+/// The deposit is scaled to the event's potential gross, see `Event::required_deposit`.
+/// The platform's vesting period is fixed on the event at this point.
 pub fn process_start(ctx: Context<StartSale>) -> Result<()> {
     require_keys_eq!(
         ctx.accounts.authority.key(),
@@ -44,19 +51,21 @@ pub fn process_start(ctx: Context<StartSale>) -> Result<()> {
         ChainTicketError::Unauthorised
     );
 
-    anchor_lang::solana_program::program::invoke(
-        &anchor_lang::solana_program::system_instruction::transfer(
-            &ctx.accounts.authority.key(),
-            &ctx.accounts.vault.key(),
-            sol_to_lamports(DEPOSIT_AMOUNT as f64),
-        ),
-        &[
-            ctx.accounts.authority.to_account_info(),
-            ctx.accounts.vault.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
+    // Only the shortfall is charged should the sale be started again
+    let deposit = ctx
+        .accounts
+        .event
+        .required_deposit(&ctx.accounts.platform)?
+        .max(ctx.accounts.event.deposit);
+    transfer_lamports(
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        deposit - ctx.accounts.event.deposit,
     )?;
 
+    ctx.accounts.event.deposit = deposit;
+    ctx.accounts.event.vesting_period = ctx.accounts.platform.vesting_period;
     ctx.accounts.event.allow_purchase = true;
     Ok(())
 }
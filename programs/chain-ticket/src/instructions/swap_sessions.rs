@@ -0,0 +1,110 @@
+use {
+    crate::{
+        constants::{MINT_SEED, RECEIPT_SEED, VAULT_SEED},
+        errors::ChainTicketError,
+        state::{Event, Receipt, RefundRecipient},
+        utils::transfer_lamports,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::{Mint, TokenAccount},
+};
+
+#[derive(Accounts)]
+pub struct SwapSessions<'info> {
+    #[account(mut)]
+    event: Account<'info, Event>,
+    /// CHECK: Address is derived and is a native vault, receives the transfer fees
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event.key().as_ref()],
+        bump,
+        address = event.vault @ ChainTicketError::InvalidVault,
+    )]
+    vault: UncheckedAccount<'info>,
+    #[account(
+        seeds = [MINT_SEED, event.key().as_ref()],
+        bump,
+        address = event.mint @ ChainTicketError::InvalidMint,
+    )]
+    mint: Account<'info, Mint>,
+    /// Pays the transfer fee for giving up ticket A
+    #[account(
+        mut,
+        constraint = holder_a.key() != holder_b.key() @ ChainTicketError::InvalidSwap,
+    )]
+    holder_a: Signer<'info>,
+    /// Pays the transfer fee for giving up ticket B
+    #[account(mut)]
+    holder_b: Signer<'info>,
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = holder_a,
+    )]
+    holder_a_ticket: Account<'info, TokenAccount>,
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = holder_b,
+    )]
+    holder_b_ticket: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), holder_a.key().as_ref()],
+        bump = receipt_a.bump,
+        has_one = event,
+    )]
+    receipt_a: Account<'info, Receipt>,
+    #[account(
+        mut,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), holder_b.key().as_ref()],
+        bump = receipt_b.bump,
+        has_one = event,
+    )]
+    receipt_b: Account<'info, Receipt>,
+    system_program: Program<'info, System>,
+}
+
+/// Exchanges two tickets of the same event, for example a Friday pass for a Saturday one. Tickets
+/// of an event share a mint so only the entitlements recorded on the receipts are exchanged: the
+/// sessions, face value, comp flag and affiliate commission. Both holders become the refund
+/// recipients of their new entitlements. The event's transfer rules apply to both tickets and
+/// each holder pays the transfer fee into the vault.
+pub fn process_swap_sessions(ctx: Context<SwapSessions>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    for (receipt, ticket) in [
+        (&ctx.accounts.receipt_a, &ctx.accounts.holder_a_ticket),
+        (&ctx.accounts.receipt_b, &ctx.accounts.holder_b_ticket),
+    ] {
+        ctx.accounts
+            .event
+            .check_transfer(clock.unix_timestamp, receipt.transfers)?;
+        require_eq!(receipt.checked_in_sessions, 0, ChainTicketError::AlreadyCheckedIn);
+        require_eq!(ticket.amount, 1, ChainTicketError::NoTicket);
+    }
+
+    for holder in [&ctx.accounts.holder_a, &ctx.accounts.holder_b] {
+        transfer_lamports(
+            &holder.to_account_info(),
+            &ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.event.transfer_fee,
+        )?;
+        ctx.accounts.event.record_transfer_fee()?;
+    }
+
+    let receipt_a = &mut ctx.accounts.receipt_a;
+    let receipt_b = &mut ctx.accounts.receipt_b;
+    std::mem::swap(&mut receipt_a.price, &mut receipt_b.price);
+    std::mem::swap(&mut receipt_a.comp, &mut receipt_b.comp);
+    std::mem::swap(&mut receipt_a.sessions, &mut receipt_b.sessions);
+    std::mem::swap(&mut receipt_a.affiliate, &mut receipt_b.affiliate);
+    std::mem::swap(&mut receipt_a.commission, &mut receipt_b.commission);
+
+    for receipt in [receipt_a, receipt_b] {
+        receipt.payer = receipt.holder;
+        receipt.refund_recipient = RefundRecipient::Holder;
+        receipt.transfers = receipt.transfers.saturating_add(1);
+    }
+
+    Ok(())
+}
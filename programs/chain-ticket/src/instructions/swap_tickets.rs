@@ -0,0 +1,273 @@
+use {
+    crate::{
+        constants::{EVENT_SEED, MINT_SEED, RECEIPT_SEED, RECEIPT_STATE_SIZE, VAULT_SEED},
+        errors::ChainTicketError,
+        state::{Event, Receipt},
+        utils::transfer_lamports,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{
+            approve_checked, freeze_account, thaw_account, transfer, ApproveChecked,
+            FreezeAccount, Mint, ThawAccount, Token, TokenAccount, Transfer,
+        },
+    },
+};
+
+#[derive(Accounts)]
+pub struct SwapTickets<'info> {
+    #[account(
+        mut,
+        constraint = event_a.key() != event_b.key() @ ChainTicketError::InvalidSwap,
+    )]
+    event_a: Box<Account<'info, Event>>,
+    /// CHECK: Address is derived and is a native vault, receives event A's transfer fee
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event_a.key().as_ref()],
+        bump,
+        address = event_a.vault @ ChainTicketError::InvalidVault,
+    )]
+    vault_a: UncheckedAccount<'info>,
+    #[account(
+        seeds = [MINT_SEED, event_a.key().as_ref()],
+        bump,
+        address = event_a.mint @ ChainTicketError::InvalidMint,
+    )]
+    mint_a: Box<Account<'info, Mint>>,
+    #[account(mut)]
+    event_b: Box<Account<'info, Event>>,
+    /// CHECK: Address is derived and is a native vault, receives event B's transfer fee
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event_b.key().as_ref()],
+        bump,
+        address = event_b.vault @ ChainTicketError::InvalidVault,
+    )]
+    vault_b: UncheckedAccount<'info>,
+    #[account(
+        seeds = [MINT_SEED, event_b.key().as_ref()],
+        bump,
+        address = event_b.mint @ ChainTicketError::InvalidMint,
+    )]
+    mint_b: Box<Account<'info, Mint>>,
+    /// Holds a ticket for event A, pays event A's transfer fee and the rent of the accounts
+    /// receiving ticket B
+    #[account(mut)]
+    holder_a: Signer<'info>,
+    /// Holds a ticket for event B, pays event B's transfer fee and the rent of the accounts
+    /// receiving ticket A
+    #[account(mut)]
+    holder_b: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = holder_a,
+    )]
+    holder_a_ticket: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_b,
+        associated_token::authority = holder_b,
+    )]
+    holder_b_ticket: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        close = rent_payer_a,
+        seeds = [RECEIPT_SEED, event_a.key().as_ref(), holder_a.key().as_ref()],
+        bump = receipt_a.bump,
+        constraint = receipt_a.event == event_a.key(),
+    )]
+    receipt_a: Box<Account<'info, Receipt>>,
+    #[account(
+        mut,
+        close = rent_payer_b,
+        seeds = [RECEIPT_SEED, event_b.key().as_ref(), holder_b.key().as_ref()],
+        bump = receipt_b.bump,
+        constraint = receipt_b.event == event_b.key(),
+    )]
+    receipt_b: Box<Account<'info, Receipt>>,
+    /// CHECK: Checked against receipt A, receives the receipt rent
+    #[account(
+        mut,
+        address = receipt_a.rent_payer @ ChainTicketError::InvalidReceiptPayer,
+    )]
+    rent_payer_a: UncheckedAccount<'info>,
+    /// CHECK: Checked against receipt B, receives the receipt rent
+    #[account(
+        mut,
+        address = receipt_b.rent_payer @ ChainTicketError::InvalidReceiptPayer,
+    )]
+    rent_payer_b: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = holder_a,
+        associated_token::mint = mint_b,
+        associated_token::authority = holder_a,
+    )]
+    holder_a_new_ticket: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = holder_b,
+        associated_token::mint = mint_a,
+        associated_token::authority = holder_b,
+    )]
+    holder_b_new_ticket: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = holder_a,
+        seeds = [RECEIPT_SEED, event_b.key().as_ref(), holder_a.key().as_ref()],
+        bump,
+        space = 8 + RECEIPT_STATE_SIZE,
+    )]
+    holder_a_new_receipt: Box<Account<'info, Receipt>>,
+    #[account(
+        init,
+        payer = holder_b,
+        seeds = [RECEIPT_SEED, event_a.key().as_ref(), holder_b.key().as_ref()],
+        bump,
+        space = 8 + RECEIPT_STATE_SIZE,
+    )]
+    holder_b_new_receipt: Box<Account<'info, Receipt>>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Atomically exchanges tickets of two different events between their holders, subject to each
+/// event's transfer rules. Each ticket is moved as in `process_transfer_ticket` and both receipts
+/// are replaced. Each holder pays the transfer fee of the event whose ticket they give up into
+/// that event's vault. Tickets of the same event are exchanged with `swap_sessions`.
+pub fn process_swap_tickets(ctx: Context<SwapTickets>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    for (event, receipt, ticket) in [
+        (&ctx.accounts.event_a, &ctx.accounts.receipt_a, &ctx.accounts.holder_a_ticket),
+        (&ctx.accounts.event_b, &ctx.accounts.receipt_b, &ctx.accounts.holder_b_ticket),
+    ] {
+        event.check_transfer(clock.unix_timestamp, receipt.transfers)?;
+        require_eq!(receipt.checked_in_sessions, 0, ChainTicketError::AlreadyCheckedIn);
+        require_eq!(ticket.amount, 1, ChainTicketError::NoTicket);
+    }
+    require_eq!(ctx.accounts.holder_a_new_ticket.amount, 0, ChainTicketError::AlreadyPurchased);
+    require_eq!(ctx.accounts.holder_b_new_ticket.amount, 0, ChainTicketError::AlreadyPurchased);
+
+    // Each holder pays the fee of the ticket they give up
+    transfer_lamports(
+        &ctx.accounts.holder_a.to_account_info(),
+        &ctx.accounts.vault_a.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.event_a.transfer_fee,
+    )?;
+    transfer_lamports(
+        &ctx.accounts.holder_b.to_account_info(),
+        &ctx.accounts.vault_b.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.event_b.transfer_fee,
+    )?;
+
+    move_ticket(
+        &ctx.accounts.token_program,
+        &ctx.accounts.event_a,
+        &ctx.accounts.mint_a,
+        &ctx.accounts.holder_a_ticket,
+        &ctx.accounts.holder_b_new_ticket,
+        &ctx.accounts.holder_b,
+    )?;
+    move_ticket(
+        &ctx.accounts.token_program,
+        &ctx.accounts.event_b,
+        &ctx.accounts.mint_b,
+        &ctx.accounts.holder_b_ticket,
+        &ctx.accounts.holder_a_new_ticket,
+        &ctx.accounts.holder_a,
+    )?;
+
+    ctx.accounts.event_a.record_transfer_fee()?;
+    ctx.accounts.event_b.record_transfer_fee()?;
+
+    let holder_a = ctx.accounts.holder_a.key();
+    let holder_b = ctx.accounts.holder_b.key();
+
+    ctx.accounts.holder_b_new_receipt.transfer_from(
+        &ctx.accounts.receipt_a,
+        ctx.bumps.holder_b_new_receipt,
+        holder_b,
+        holder_b,
+        clock.slot,
+    );
+
+    ctx.accounts.holder_a_new_receipt.transfer_from(
+        &ctx.accounts.receipt_b,
+        ctx.bumps.holder_a_new_receipt,
+        holder_a,
+        holder_a,
+        clock.slot,
+    );
+
+    Ok(())
+}
+
+/// Moves a ticket out of a frozen token account using the event's delegate approval, leaving the
+/// emptied account thawed, then delegates the destination to the event and freezes it, see
+/// `process_buy`.
+fn move_ticket<'info>(
+    token_program: &Program<'info, Token>,
+    event: &Account<'info, Event>,
+    mint: &Account<'info, Mint>,
+    from: &Account<'info, TokenAccount>,
+    to: &Account<'info, TokenAccount>,
+    recipient: &Signer<'info>,
+) -> Result<()> {
+    let event_seeds: &[&[u8]] = &[EVENT_SEED, event.authority.as_ref(), &[event.bump]];
+
+    thaw_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        ThawAccount {
+            account: from.to_account_info(),
+            mint: mint.to_account_info(),
+            authority: event.to_account_info(),
+        },
+        &[event_seeds],
+    ))?;
+
+    transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: from.to_account_info(),
+                to: to.to_account_info(),
+                authority: event.to_account_info(),
+            },
+            &[event_seeds],
+        ),
+        1,
+    )?;
+
+    approve_checked(
+        CpiContext::new(
+            token_program.to_account_info(),
+            ApproveChecked {
+                to: to.to_account_info(),
+                mint: mint.to_account_info(),
+                delegate: event.to_account_info(),
+                authority: recipient.to_account_info(),
+            },
+        ),
+        1,
+        0,
+    )?;
+
+    freeze_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        FreezeAccount {
+            account: to.to_account_info(),
+            mint: mint.to_account_info(),
+            authority: event.to_account_info(),
+        },
+        &[event_seeds],
+    ))?;
+
+    Ok(())
+}
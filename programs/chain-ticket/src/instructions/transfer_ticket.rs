@@ -0,0 +1,175 @@
+use {
+    crate::{
+        constants::{EVENT_SEED, MINT_SEED, RECEIPT_SEED, RECEIPT_STATE_SIZE, VAULT_SEED},
+        errors::ChainTicketError,
+        state::{Event, Receipt},
+        utils::transfer_lamports,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{
+            approve_checked, freeze_account, thaw_account, transfer, ApproveChecked,
+            FreezeAccount, Mint, ThawAccount, Token, TokenAccount, Transfer,
+        },
+    },
+};
+
+#[derive(Accounts)]
+pub struct TransferTicket<'info> {
+    #[account(mut)]
+    event: Account<'info, Event>,
+    /// CHECK: Address is derived and is a native vault, receives the transfer fee
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event.key().as_ref()],
+        bump,
+        address = event.vault @ ChainTicketError::InvalidVault,
+    )]
+    vault: UncheckedAccount<'info>,
+    #[account(
+        seeds = [MINT_SEED, event.key().as_ref()],
+        bump,
+        address = event.mint @ ChainTicketError::InvalidMint,
+    )]
+    mint: Account<'info, Mint>,
+    /// Current holder, pays the transfer fee and the rent of the recipient's accounts
+    #[account(mut)]
+    sender: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = sender,
+    )]
+    sender_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        close = sender_rent_payer,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), sender.key().as_ref()],
+        bump = sender_receipt.bump,
+        has_one = event,
+    )]
+    sender_receipt: Account<'info, Receipt>,
+    /// CHECK: Checked against the sender's receipt, receives the receipt rent
+    #[account(
+        mut,
+        address = sender_receipt.rent_payer @ ChainTicketError::InvalidReceiptPayer,
+    )]
+    sender_rent_payer: UncheckedAccount<'info>,
+    /// Signs to approve the event as delegate of their token account
+    recipient: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = sender,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+    )]
+    recipient_ata: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = sender,
+        seeds = [RECEIPT_SEED, event.key().as_ref(), recipient.key().as_ref()],
+        bump,
+        space = 8 + RECEIPT_STATE_SIZE,
+    )]
+    recipient_receipt: Account<'info, Receipt>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Hands a ticket to another wallet without a sale, subject to the event's transfer rules. The
+/// ticket is moved using the event's delegate approval leaving the sender's token account thawed,
+/// the recipient's token account is delegated to the event and frozen as in `process_buy`, and the
+/// sender's receipt is replaced by one for the recipient. The event's transfer fee, if any, is
+/// paid by the sender into the vault.
+pub fn process_transfer_ticket(ctx: Context<TransferTicket>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    ctx.accounts
+        .event
+        .check_transfer(clock.unix_timestamp, ctx.accounts.sender_receipt.transfers)?;
+    require_eq!(
+        ctx.accounts.sender_receipt.checked_in_sessions,
+        0,
+        ChainTicketError::AlreadyCheckedIn
+    );
+    require_eq!(ctx.accounts.sender_ata.amount, 1, ChainTicketError::NoTicket);
+    require_eq!(ctx.accounts.recipient_ata.amount, 0, ChainTicketError::AlreadyPurchased);
+
+    transfer_lamports(
+        &ctx.accounts.sender.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.event.transfer_fee,
+    )?;
+
+    let event_seeds: &[&[u8]] = &[
+        EVENT_SEED,
+        ctx.accounts.event.authority.as_ref(),
+        &[ctx.accounts.event.bump],
+    ];
+
+    // Thaw the sender's token account so the ticket can be moved
+    thaw_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ThawAccount {
+            account: ctx.accounts.sender_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.event.to_account_info(),
+        },
+        &[event_seeds],
+    ))?;
+
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.sender_ata.to_account_info(),
+                to: ctx.accounts.recipient_ata.to_account_info(),
+                authority: ctx.accounts.event.to_account_info(),
+            },
+            &[event_seeds],
+        ),
+        1,
+    )?;
+
+    // Set the event as delegate, see `process_buy`
+    approve_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            ApproveChecked {
+                to: ctx.accounts.recipient_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                delegate: ctx.accounts.event.to_account_info(),
+                authority: ctx.accounts.recipient.to_account_info(),
+            },
+        ),
+        1,
+        0,
+    )?;
+
+    freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.recipient_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.event.to_account_info(),
+        },
+        &[event_seeds],
+    ))?;
+
+    ctx.accounts.event.record_transfer_fee()?;
+
+    let sender = ctx.accounts.sender.key();
+    let recipient = ctx.accounts.recipient.key();
+    ctx.accounts.recipient_receipt.transfer_from(
+        &ctx.accounts.sender_receipt,
+        ctx.bumps.recipient_receipt,
+        recipient,
+        sender,
+        clock.slot,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use {
+    crate::{
+        constants::{EVENT_SEED, METADATA_SEED, MINT_SEED, PERMISSION_METADATA_EDITOR, STAFF_SEED},
+        errors::ChainTicketError,
+        state::{Event, Staff},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        metadata::{
+            mpl_token_metadata::types::DataV2, update_metadata_accounts_v2, Metadata,
+            UpdateMetadataAccountsV2,
+        },
+        token::Mint,
+    },
+};
+
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    editor: Signer<'info>,
+    /// Staff account of the editor, not required when the event authority updates
+    #[account(
+        seeds = [STAFF_SEED, event.key().as_ref(), editor.key().as_ref()],
+        bump = staff.bump,
+        has_one = event,
+    )]
+    staff: Option<Account<'info, Staff>>,
+    #[account(
+        seeds = [EVENT_SEED, event.authority.as_ref()],
+        bump = event.bump,
+        has_one = mint,
+    )]
+    event: Account<'info, Event>,
+    #[account(
+        seeds = [MINT_SEED, event.key().as_ref()],
+        bump,
+    )]
+    mint: Account<'info, Mint>,
+    /// CHECK: Safe - PDA
+    #[account(
+        mut,
+        seeds = [
+            METADATA_SEED,
+            token_metadata_program.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    metadata: UncheckedAccount<'info>,
+    token_metadata_program: Program<'info, Metadata>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateMetadataFields {
+    pub event_name: String,
+    pub event_symbol: String,
+    pub image_uri: String,
+}
+
+/// Replaces the name, symbol and image of the ticket's token metadata. The event is the update
+/// authority, so the event authority or staff holding the metadata editor permission can update
+/// it.
+pub fn process_update_metadata(
+    ctx: Context<UpdateMetadata>,
+    data: UpdateMetadataFields,
+) -> Result<()> {
+    require!(
+        ctx.accounts.event.is_permitted(
+            &ctx.accounts.editor.key(),
+            ctx.accounts.staff.as_deref(),
+            PERMISSION_METADATA_EDITOR,
+        ),
+        ChainTicketError::Unauthorised
+    );
+
+    update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            UpdateMetadataAccountsV2 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                update_authority: ctx.accounts.event.to_account_info(),
+            },
+            &[&[
+                EVENT_SEED,
+                ctx.accounts.event.authority.as_ref(),
+                &[ctx.accounts.event.bump],
+            ]],
+        ),
+        None,
+        Some(DataV2 {
+            name: data.event_name,
+            symbol: data.event_symbol,
+            uri: data.image_uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        }),
+        None,
+        None,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use {
+    crate::{
+        constants::{BPS_DENOMINATOR, PLATFORM_SEED},
+        errors::ChainTicketError, state::PlatformConfig},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct UpdatePlatform<'info> {
+    #[account(address = platform.authority @ ChainTicketError::Unauthorised)]
+    authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+    )]
+    platform: Account<'info, PlatformConfig>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdatePlatformFields {
+    pub relayer: Option<Pubkey>,
+    pub relayer_fee_cap: Option<u64>,
+    pub treasury: Option<Pubkey>,
+    pub resale_royalty_bps: Option<u16>,
+    pub advance_bps: Option<u16>,
+    pub vesting_period: Option<i64>,
+    pub deposit_bps: Option<u16>,
+    pub deposit_min: Option<u64>,
+    pub deposit_max: Option<u64>,
+}
+
+/// Amend fields that are not passed in as `None`, see `process_amend`.
+pub fn process_update_platform(
+    ctx: Context<UpdatePlatform>,
+    data: UpdatePlatformFields,
+) -> Result<()> {
+    if let Some(relayer) = data.relayer {
+        ctx.accounts.platform.relayer = relayer;
+    }
+
+    if let Some(relayer_fee_cap) = data.relayer_fee_cap {
+        ctx.accounts.platform.relayer_fee_cap = relayer_fee_cap;
+    }
+
+    if let Some(treasury) = data.treasury {
+        ctx.accounts.platform.treasury = treasury;
+    }
+
+    if let Some(resale_royalty_bps) = data.resale_royalty_bps {
+        require_gte!(
+            BPS_DENOMINATOR,
+            resale_royalty_bps as u64,
+            ChainTicketError::InvalidBasisPoints
+        );
+        ctx.accounts.platform.resale_royalty_bps = resale_royalty_bps;
+    }
+
+    if let Some(advance_bps) = data.advance_bps {
+        require_gte!(
+            BPS_DENOMINATOR,
+            advance_bps as u64,
+            ChainTicketError::InvalidBasisPoints
+        );
+        ctx.accounts.platform.advance_bps = advance_bps;
+    }
+
+    if let Some(vesting_period) = data.vesting_period {
+        ctx.accounts.platform.vesting_period = vesting_period.max(0);
+    }
+
+    if let Some(deposit_bps) = data.deposit_bps {
+        ctx.accounts.platform.deposit_bps = deposit_bps;
+    }
+
+    if let Some(deposit_min) = data.deposit_min {
+        ctx.accounts.platform.deposit_min = deposit_min;
+    }
+
+    if let Some(deposit_max) = data.deposit_max {
+        ctx.accounts.platform.deposit_max = deposit_max;
+    }
+
+    require_gte!(
+        ctx.accounts.platform.deposit_max,
+        ctx.accounts.platform.deposit_min,
+        ChainTicketError::InvalidDepositBounds
+    );
+
+    Ok(())
+}
@@ -1,9 +1,10 @@
 use {
     crate::{
-        constants::{DEPOSIT_AMOUNT, EVENT_SEED, FEE, PLATFORM_OWNER, VAULT_SEED},
+        constants::{EVENT_SEED, PLATFORM_OWNER, PLATFORM_SEED, PROFILE_SEED, VAULT_SEED},
         errors::ChainTicketError,
-        state::Event,
-        utils::sol_to_lamports,
+        fees::deduct_platform_fee,
+        instructions::claim_commission::pay_commission,
+        state::{Affiliate, Event, OrganiserProfile, PlatformConfig},
     },
     anchor_lang::prelude::*,
 };
@@ -16,12 +17,24 @@ pub struct WithdrawFunds<'info> {
         address = PLATFORM_OWNER @ ChainTicketError::Unauthorised,
     )]
     pub platform_owner: UncheckedAccount<'info>,
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, PlatformConfig>,
     #[account(
         mut,
         address = event.authority @ ChainTicketError::Unauthorised,
     )]
     pub authority: Signer<'info>,
     #[account(
+        mut,
+        seeds = [PROFILE_SEED, authority.key().as_ref()],
+        bump = profile.bump,
+    )]
+    pub profile: Account<'info, OrganiserProfile>,
+    #[account(
+        mut,
         seeds = [EVENT_SEED, authority.key().as_ref()],
         bump,
     )]
@@ -38,35 +51,116 @@ pub struct WithdrawFunds<'info> {
     pub vault: UncheckedAccount<'info>,
 }
 
+/// Withdraws the event's proceeds, as recorded by its ledger rather than the vault balance, once
+/// the refund and vesting periods have elapsed. The platform fee is paid to the platform owner and
+/// the rest is split between the event's payees, passed as remaining accounts in the order of the
+/// payee table, see `pay_proceeds`. The commission owed to affiliates is paid out of the vault, each
+/// affiliate account and its wallet are passed as remaining accounts after the payees and every
+/// owed commission must be paid. The deposit is returned to the authority. Amounts already
+/// advanced with `advance_funds` or claimed with `claim_vested` are no longer part of the proceeds
+/// so only the remainder is settled. The platform fee rate is looked up from the organiser's
+/// profile, see `PlatformConfig::fee_bps`, and the event's net sales are then added to the
+/// organiser's lifetime volume.
 pub fn process_withdraw(ctx: Context<WithdrawFunds>) -> Result<()> {
     let clock = Clock::get()?;
 
-    // Check refund period has elapsed
+    // Check refund and vesting periods have elapsed
     require_gte!(
         clock.unix_timestamp,
-        (ctx.accounts.event.event_date + ctx.accounts.event.refund_period)
+        (ctx.accounts.event.refund_period_end() + ctx.accounts.event.vesting_period)
     );
+    require!(!ctx.accounts.event.flagged, ChainTicketError::EventFlagged);
+
+    let deposit_amount = ctx.accounts.event.deposit;
 
-    let deposit_amount = sol_to_lamports(DEPOSIT_AMOUNT as f64);
+    let proceeds = ctx.accounts.event.proceeds()?;
 
-    let proceeds = ctx
+    // Deduct proceeds and deposit amount, the vault may not hold less than the ledger records
+    let vault_lamports = ctx
         .accounts
         .vault
         .get_lamports()
-        .checked_sub(deposit_amount)
-        .ok_or(ChainTicketError::Overflow)?;
+        .checked_sub(proceeds + deposit_amount)
+        .ok_or(ChainTicketError::InsufficientVaultBalance)?;
+    **ctx.accounts.vault.try_borrow_mut_lamports()? = vault_lamports;
+    // Payees come first in the remaining accounts, followed by affiliate and wallet pairs
+    let (payees, affiliates) = ctx
+        .remaining_accounts
+        .split_at(ctx.accounts.event.payees.len().min(ctx.remaining_accounts.len()));
+    // Pay the platform fee and split the rest between the payees
+    let platform_fee = pay_proceeds(
+        &ctx.accounts.event,
+        &ctx.accounts.platform_owner,
+        &ctx.accounts.authority,
+        payees,
+        proceeds,
+        ctx.accounts.platform.fee_bps(&ctx.accounts.profile),
+    )?;
+    // Return the deposit amount
+    **ctx.accounts.authority.try_borrow_mut_lamports()? += deposit_amount;
 
-    let platform_fee = proceeds
-        .checked_div(FEE * 100)
-        .ok_or(ChainTicketError::Overflow)?;
+    // Pay the commission owed to each affiliate
+    for accounts in affiliates.chunks(2) {
+        let [affiliate_info, wallet] = accounts else {
+            return err!(ChainTicketError::InvalidAffiliate);
+        };
+        require_keys_eq!(*affiliate_info.owner, crate::ID, ChainTicketError::InvalidAffiliate);
+        let mut affiliate = Affiliate::try_deserialize(&mut &affiliate_info.try_borrow_data()?[..])?;
+        require_keys_eq!(
+            affiliate.event,
+            ctx.accounts.event.key(),
+            ChainTicketError::InvalidAffiliate
+        );
+        require_keys_eq!(affiliate.wallet, wallet.key(), ChainTicketError::InvalidAffiliate);
 
-    // Deduct platform fee, proceeds and deposit amount
-    **ctx.accounts.vault.try_borrow_mut_lamports()? -= proceeds + deposit_amount;
-    // Transfer platform fee
-    **ctx.accounts.platform_owner.try_borrow_mut_lamports()? += platform_fee;
-    // Transfer proceeds + deposit amount
-    **ctx.accounts.authority.try_borrow_mut_lamports()? +=
-        (proceeds - platform_fee) + deposit_amount;
+        pay_commission(&mut ctx.accounts.event, &ctx.accounts.vault, &mut affiliate, wallet)?;
+        affiliate.try_serialize(&mut &mut affiliate_info.try_borrow_mut_data()?[..])?;
+    }
+    require_eq!(
+        ctx.accounts.event.commissions_owed(),
+        0,
+        ChainTicketError::CommissionsUnpaid
+    );
+
+    ctx.accounts
+        .event
+        .record_withdrawal(proceeds, platform_fee)?;
+    ctx.accounts.event.deposit = 0;
+
+    // Credit the event's sales to the organiser's volume, applying to its later payouts
+    let volume = ctx.accounts.event.take_uncredited_volume();
+    let profile = &mut ctx.accounts.profile;
+    profile.lifetime_gross = profile.lifetime_gross.saturating_add(volume);
 
     Ok(())
 }
+
+/// Credits `proceeds`, already deducted from the vault, to the platform owner as the platform fee
+/// at `fee_bps` and to the event's payees as their shares of the rest. The rounding dust goes to
+/// the authority, who receives everything after the fee if the event has no payees. `payees` must
+/// hold the payee accounts in the order of the event's payee table. Returns the platform fee.
+pub fn pay_proceeds(
+    event: &Event,
+    platform_owner: &AccountInfo,
+    authority: &AccountInfo,
+    payees: &[AccountInfo],
+    proceeds: u64,
+    fee_bps: u16,
+) -> Result<u64> {
+    let (platform_fee, net) = deduct_platform_fee(proceeds, fee_bps)?;
+
+    let (shares, dust) = event.split_proceeds(net)?;
+    require_eq!(payees.len(), shares.len(), ChainTicketError::InvalidPayees);
+
+    // Transfer platform fee
+    **platform_owner.try_borrow_mut_lamports()? += platform_fee;
+    // Transfer each payee's share
+    for ((payee, account), share) in event.payees.iter().zip(payees).zip(shares) {
+        require_keys_eq!(payee.recipient, account.key(), ChainTicketError::InvalidPayees);
+        **account.try_borrow_mut_lamports()? += share;
+    }
+    // Transfer the rest of the proceeds
+    **authority.try_borrow_mut_lamports()? += dust;
+
+    Ok(platform_fee)
+}
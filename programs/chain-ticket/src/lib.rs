@@ -2,11 +2,13 @@ use anchor_lang::prelude::*;
 
 mod constants;
 mod errors;
+mod fees;
 mod instructions;
 mod state;
 mod utils;
 
 use instructions::*;
+use state::{FeeTier, Payee, RefundRecipient};
 
 declare_id!("4uXFs66eEYQ8u51coqaypjF4wtRL4LQTML2kA5zMXUy4");
 
@@ -29,8 +31,8 @@ pub mod chain_ticket {
         Ok(())
     }
 
-    pub fn buy_ticket(ctx: Context<BuyTicket>) -> Result<()> {
-        instructions::buy_ticket::process_buy(ctx)?;
+    pub fn buy_ticket(ctx: Context<BuyTicket>, sessions: u8, relayer_fee: u64) -> Result<()> {
+        instructions::buy_ticket::process_buy(ctx, sessions, relayer_fee)?;
         Ok(())
     }
 
@@ -63,4 +65,229 @@ pub mod chain_ticket {
         instructions::end_event::process_end(ctx)?;
         Ok(())
     }
+
+    pub fn init_waitlist(ctx: Context<InitWaitlist>) -> Result<()> {
+        instructions::init_waitlist::process_init_waitlist(ctx)?;
+        Ok(())
+    }
+
+    pub fn join_waitlist(ctx: Context<JoinWaitlist>) -> Result<()> {
+        instructions::join_waitlist::process_join_waitlist(ctx)?;
+        Ok(())
+    }
+
+    pub fn leave_waitlist(ctx: Context<LeaveWaitlist>) -> Result<()> {
+        instructions::leave_waitlist::process_leave_waitlist(ctx)?;
+        Ok(())
+    }
+
+    pub fn serve_waitlist(ctx: Context<ServeWaitlist>) -> Result<()> {
+        instructions::serve_waitlist::process_serve_waitlist(ctx)?;
+        Ok(())
+    }
+
+    pub fn close_waitlist(ctx: Context<CloseWaitlist>) -> Result<()> {
+        instructions::close_waitlist::process_close_waitlist(ctx)?;
+        Ok(())
+    }
+
+    pub fn place_hold(ctx: Context<PlaceHold>) -> Result<()> {
+        instructions::place_hold::process_place_hold(ctx)?;
+        Ok(())
+    }
+
+    pub fn expire_hold(ctx: Context<ExpireHold>) -> Result<()> {
+        instructions::expire_hold::process_expire_hold(ctx)?;
+        Ok(())
+    }
+
+    pub fn buy_gift_ticket(
+        ctx: Context<BuyGiftTicket>,
+        sessions: u8,
+        refund_recipient: RefundRecipient,
+    ) -> Result<()> {
+        instructions::buy_gift_ticket::process_buy_gift(ctx, sessions, refund_recipient)?;
+        Ok(())
+    }
+
+    pub fn init_platform(ctx: Context<InitPlatform>, data: InitPlatformFields) -> Result<()> {
+        instructions::init_platform::process_init_platform(ctx, data)?;
+        Ok(())
+    }
+
+    pub fn update_platform(ctx: Context<UpdatePlatform>, data: UpdatePlatformFields) -> Result<()> {
+        instructions::update_platform::process_update_platform(ctx, data)?;
+        Ok(())
+    }
+
+    pub fn issue_comp_ticket(ctx: Context<IssueCompTicket>, sessions: u8) -> Result<()> {
+        instructions::issue_comp_ticket::process_issue_comp(ctx, sessions)?;
+        Ok(())
+    }
+
+    pub fn register_agent(ctx: Context<RegisterAgent>, quota: u32) -> Result<()> {
+        instructions::register_agent::process_register_agent(ctx, quota)?;
+        Ok(())
+    }
+
+    pub fn agent_issue_ticket(
+        ctx: Context<AgentIssueTicket>,
+        sessions: u8,
+        declared_revenue: u64,
+    ) -> Result<()> {
+        instructions::agent_issue_ticket::process_agent_issue(ctx, sessions, declared_revenue)?;
+        Ok(())
+    }
+
+    pub fn grant_role(ctx: Context<GrantRole>, permissions: u8) -> Result<()> {
+        instructions::grant_role::process_grant_role(ctx, permissions)?;
+        Ok(())
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>, permissions: u8) -> Result<()> {
+        instructions::revoke_role::process_revoke_role(ctx, permissions)?;
+        Ok(())
+    }
+
+    pub fn check_in(ctx: Context<CheckIn>, session: u8) -> Result<()> {
+        instructions::check_in::process_check_in(ctx, session)?;
+        Ok(())
+    }
+
+    pub fn set_sessions(ctx: Context<SetSessions>, sessions: Vec<SessionFields>) -> Result<()> {
+        instructions::set_sessions::process_set_sessions(ctx, sessions)?;
+        Ok(())
+    }
+
+    pub fn init_attendance(ctx: Context<InitAttendance>, data: InitAttendanceFields) -> Result<()> {
+        instructions::init_attendance::process_init_attendance(ctx, data)?;
+        Ok(())
+    }
+
+    pub fn claim_attendance(ctx: Context<ClaimAttendance>) -> Result<()> {
+        instructions::claim_attendance::process_claim_attendance(ctx)?;
+        Ok(())
+    }
+
+    pub fn settle_check_ins(ctx: Context<SettleCheckIns>, root: [u8; 32], count: u32) -> Result<()> {
+        instructions::settle_check_ins::process_settle_check_ins(ctx, root, count)?;
+        Ok(())
+    }
+
+    pub fn claim_check_in(
+        ctx: Context<ClaimCheckIn>,
+        session: u8,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_check_in::process_claim_check_in(ctx, session, proof)?;
+        Ok(())
+    }
+
+    pub fn list_ticket(ctx: Context<ListTicket>, price: u64) -> Result<()> {
+        instructions::list_ticket::process_list_ticket(ctx, price)?;
+        Ok(())
+    }
+
+    pub fn cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+        instructions::cancel_listing::process_cancel_listing(ctx)?;
+        Ok(())
+    }
+
+    pub fn buy_listing(ctx: Context<BuyListing>) -> Result<()> {
+        instructions::buy_listing::process_buy_listing(ctx)?;
+        Ok(())
+    }
+
+    pub fn transfer_ticket(ctx: Context<TransferTicket>) -> Result<()> {
+        instructions::transfer_ticket::process_transfer_ticket(ctx)?;
+        Ok(())
+    }
+
+    pub fn swap_tickets(ctx: Context<SwapTickets>) -> Result<()> {
+        instructions::swap_tickets::process_swap_tickets(ctx)?;
+        Ok(())
+    }
+
+    pub fn swap_sessions(ctx: Context<SwapSessions>) -> Result<()> {
+        instructions::swap_sessions::process_swap_sessions(ctx)?;
+        Ok(())
+    }
+
+    pub fn make_offer(ctx: Context<MakeOffer>, price: u64, sessions: u8) -> Result<()> {
+        instructions::make_offer::process_make_offer(ctx, price, sessions)?;
+        Ok(())
+    }
+
+    pub fn accept_offer(ctx: Context<AcceptOffer>) -> Result<()> {
+        instructions::accept_offer::process_accept_offer(ctx)?;
+        Ok(())
+    }
+
+    pub fn cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
+        instructions::cancel_offer::process_cancel_offer(ctx)?;
+        Ok(())
+    }
+
+    pub fn set_payees(ctx: Context<SetPayees>, payees: Vec<Payee>) -> Result<()> {
+        instructions::set_payees::process_set_payees(ctx, payees)?;
+        Ok(())
+    }
+
+    pub fn advance_funds(ctx: Context<AdvanceFunds>, amount: u64) -> Result<()> {
+        instructions::advance_funds::process_advance(ctx, amount)?;
+        Ok(())
+    }
+
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        instructions::claim_vested::process_claim_vested(ctx)?;
+        Ok(())
+    }
+
+    pub fn flag_event(ctx: Context<FlagEvent>, flagged: bool) -> Result<()> {
+        instructions::flag_event::process_flag_event(ctx, flagged)?;
+        Ok(())
+    }
+
+    pub fn refund_flagged(ctx: Context<RefundFlagged>) -> Result<()> {
+        instructions::refund_flagged::process_refund_flagged(ctx)?;
+        Ok(())
+    }
+
+    pub fn init_profile(ctx: Context<InitProfile>) -> Result<()> {
+        instructions::init_profile::process_init_profile(ctx)?;
+        Ok(())
+    }
+
+    pub fn set_fee_exemption(ctx: Context<SetFeeExemption>, exempt: bool) -> Result<()> {
+        instructions::set_fee_exemption::process_set_fee_exemption(ctx, exempt)?;
+        Ok(())
+    }
+
+    pub fn set_fee_tiers(ctx: Context<SetFeeTiers>, tiers: Vec<FeeTier>) -> Result<()> {
+        instructions::set_fee_tiers::process_set_fee_tiers(ctx, tiers)?;
+        Ok(())
+    }
+
+    pub fn register_affiliate(ctx: Context<RegisterAffiliate>, commission_bps: u16) -> Result<()> {
+        instructions::register_affiliate::process_register_affiliate(ctx, commission_bps)?;
+        Ok(())
+    }
+
+    pub fn claim_commission(ctx: Context<ClaimCommission>) -> Result<()> {
+        instructions::claim_commission::process_claim_commission(ctx)?;
+        Ok(())
+    }
+
+    pub fn evict_waitlist_head(ctx: Context<EvictWaitlistHead>) -> Result<()> {
+        instructions::evict_waitlist_head::process_evict_waitlist_head(ctx)?;
+        Ok(())
+    }
+
+    pub fn update_metadata(
+        ctx: Context<UpdateMetadata>,
+        data: UpdateMetadataFields,
+    ) -> Result<()> {
+        instructions::update_metadata::process_update_metadata(ctx, data)?;
+        Ok(())
+    }
 }
@@ -1,4 +1,11 @@
-use anchor_lang::prelude::*;
+use {
+    crate::{
+        constants::MAX_HELD_BPS,
+        errors::ChainTicketError,
+        fees::{apply_bps, net_proceeds, tier_fee_bps},
+    },
+    anchor_lang::prelude::*,
+};
 
 #[account]
 pub struct Event {
@@ -23,4 +30,1351 @@ pub struct Event {
     // Number of tickets that are available for the event, mint supply will be capped to this
     // amount
     pub num_tickets: u32, // 4
+    // Number of wallets currently queued on the event's waitlist
+    pub waitlisted: u32, // 4
+    // Number of freed tickets that are reserved for the head of the waitlist, these cannot be
+    // bought through `buy_ticket` and are handed out by `serve_waitlist`
+    pub waitlist_offers: u32, // 4
+    // Number of tickets currently reserved by unexpired or unreclaimed holds
+    pub held_tickets: u32, // 4
+    // Number of seconds a hold reserves a ticket for before it can be expired
+    pub hold_ttl: i64, // 8
+    // Maximum number of complimentary tickets the organiser can issue, these count against
+    // `num_tickets`
+    pub comp_quota: u32, // 4
+    // Number of complimentary tickets issued so far
+    pub comps_issued: u32, // 4
+    // Number of offline check-in batches settled, used to derive the next batch address
+    pub check_in_batches: u32, // 4
+    // Total number of check-ins declared across all settled batches
+    pub settled_check_ins: u32, // 4
+    // Maximum resale price as basis points of the ticket's face value, zero disables resale
+    pub resale_cap_bps: u16, // 2
+    // Organiser royalty on secondary sales as basis points of the sale price, paid into the vault
+    pub resale_royalty_bps: u16, // 2
+    // Total royalties credited to the vault from secondary sales
+    pub organiser_royalties: u64, // 8
+    // Total royalties paid to the platform treasury from secondary sales
+    pub platform_royalties: u64, // 8
+    // Whether holders can hand their tickets to other wallets with `transfer_ticket`
+    pub transfers_enabled: bool, // 1
+    // Seconds before `event_date` from which transfers are no longer allowed
+    pub transfer_lockout: i64, // 8
+    // Maximum number of times a single ticket can be transferred, zero means no limit
+    pub max_transfers: u8, // 1
+    // Lamports charged to the sender per transfer, paid into the vault
+    pub transfer_fee: u64, // 8
+    // Total transfer fees paid into the vault
+    pub transfer_fees: u64, // 8
+    // Total face value of checked in tickets, these can no longer be refunded
+    pub checked_in_proceeds: u64, // 8
+    // Proceeds withdrawn ahead of `withdraw_funds` with `advance_funds`
+    pub advanced: u64, // 8
+    // Seconds over which proceeds vest linearly once the refund period has elapsed, copied from
+    // the platform config when sales start
+    pub vesting_period: i64, // 8
+    // Proceeds in the vault when vesting started, recorded on the first claim
+    pub vesting_total: u64, // 8
+    // Vested proceeds claimed so far with `claim_vested`
+    pub vested_claimed: u64, // 8
+    // Set by the platform to halt payouts and let holders reclaim the unvested balance
+    pub flagged: bool, // 1
+    // Total lamports kept in the vault from primary ticket sales
+    pub gross_sales: u64, // 8
+    // Total lamports refunded out of the vault
+    pub refunds_paid: u64, // 8
+    // Total proceeds paid out of the vault through advances, vested claims and withdrawals
+    pub withdrawn: u64, // 8
+    // Total platform fees paid out of the vault
+    pub fees_paid: u64, // 8
+    // Number of tickets issued, including complimentary, agent and waitlist tickets
+    pub tickets_sold: u32, // 4
+    // Number of tickets refunded
+    pub tickets_refunded: u32, // 4
+    // Number of tickets burned without a refund
+    pub tickets_burned: u32, // 4
+    // Number of tickets checked in for at least one session
+    pub tickets_checked_in: u32, // 4
+    // Deposit held in the vault since sales started, scaled to the event's potential gross.
+    // Returned on withdrawal and forfeited on cancellation
+    pub deposit: u64, // 8
+    // Net sales already credited to the organiser's lifetime volume, see `OrganiserProfile`
+    pub credited_volume: u64, // 8
+    // Affiliate commissions owed on the event's sales, including those already paid out
+    pub commissions_accrued: u64, // 8
+    // Affiliate commissions paid out of the vault
+    pub commissions_paid: u64, // 8
+    // Offers escrowing lamports for a ticket that have not been filled or cancelled, the event
+    // cannot be closed while any are open
+    pub open_offers: u32, // 4
+    // Whether proof of attendance souvenirs can be transferred, they are frozen otherwise
+    pub attendance_transferable: bool, // 1
+    // Sessions of a multi-day event, empty for single session events. Tickets are entitled to
+    // sessions through a bitmask indexing into this list
+    pub sessions: Vec<Session>, // 4 + 28 * MAX_SESSIONS
+    // Wallets sharing the event's proceeds on withdrawal, empty if all proceeds go to the
+    // authority. Shares add up to `BPS_DENOMINATOR`
+    pub payees: Vec<Payee>, // 4 + 34 * MAX_PAYEES
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct Session {
+    // Session date in unix time
+    pub date: i64, // 8
+    // Price in lamports of entitlement to this session, a pass costs the sum of its sessions
+    pub price: u64, // 8
+    // Number of passes that can be entitled to this session
+    pub capacity: u32, // 4
+    // Number of passes entitled to this session
+    pub sold: u32, // 4
+    // Number of check-ins for this session
+    pub attendance: u32, // 4
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct Payee {
+    // Wallet receiving this share of the proceeds
+    pub recipient: Pubkey, // 32
+    // Share of the proceeds in basis points
+    pub share_bps: u16, // 2
+}
+
+impl Event {
+    /// Number of issued tickets that have not been refunded or burned.
+    pub fn outstanding_tickets(&self) -> u64 {
+        (self.tickets_sold as u64)
+            .saturating_sub(self.tickets_refunded as u64)
+            .saturating_sub(self.tickets_burned as u64)
+    }
+
+    /// Number of tickets that can still be sold given the outstanding tickets, tickets reserved
+    /// for the waitlist or by holds are not considered available.
+    pub fn available_tickets(&self) -> u64 {
+        (self.num_tickets as u64)
+            .saturating_sub(self.outstanding_tickets())
+            .saturating_sub(self.waitlist_offers as u64)
+            .saturating_sub(self.held_tickets as u64)
+    }
+
+    /// Whether another ticket can be held. Holds cost no more than their rent, so at most
+    /// `MAX_HELD_BPS` of the unsold tickets can be held at once, always allowing a single hold.
+    pub fn can_hold(&self) -> Result<bool> {
+        let unsold = (self.num_tickets as u64)
+            .saturating_sub(self.outstanding_tickets())
+            .saturating_sub(self.waitlist_offers as u64);
+        let limit = apply_bps(unsold, MAX_HELD_BPS)?.max(1);
+        Ok((self.held_tickets as u64) < limit)
+    }
+
+    /// Highest price a ticket with the given face value can be resold for.
+    pub fn max_resale_price(&self, face_value: u64) -> Result<u64> {
+        apply_bps(face_value, self.resale_cap_bps)
+    }
+
+    /// Royalties owed on a secondary sale at `price` as `(organiser, platform)` amounts. Their
+    /// sum can never exceed the price, the remainder goes to the seller.
+    pub fn resale_royalties(&self, price: u64, platform_bps: u16) -> Result<(u64, u64)> {
+        let organiser = apply_bps(price, self.resale_royalty_bps)?;
+        let platform = apply_bps(price, platform_bps)?;
+        require_gte!(
+            price,
+            organiser
+                .checked_add(platform)
+                .ok_or(ChainTicketError::Overflow)?,
+            ChainTicketError::InvalidBasisPoints
+        );
+        Ok((organiser, platform))
+    }
+
+    /// Checks the organiser's transfer rules for a ticket that has already been transferred
+    /// `transfers` times.
+    pub fn check_transfer(&self, now: i64, transfers: u8) -> Result<()> {
+        require!(self.transfers_enabled, ChainTicketError::TransfersDisabled);
+        require_gt!(
+            self.event_date.saturating_sub(self.transfer_lockout),
+            now,
+            ChainTicketError::TransferLockedOut
+        );
+        require!(
+            self.max_transfers == 0 || transfers < self.max_transfers,
+            ChainTicketError::TransferLimitReached
+        );
+        Ok(())
+    }
+
+    /// Records the event's transfer fee as collected into the vault, returning the fee.
+    pub fn record_transfer_fee(&mut self) -> Result<u64> {
+        self.transfer_fees = self
+            .transfer_fees
+            .checked_add(self.transfer_fee)
+            .ok_or(ChainTicketError::Overflow)?;
+        Ok(self.transfer_fee)
+    }
+
+    /// Splits `amount` between the payees, returning each payee's share in order and the
+    /// rounding dust left over.
+    pub fn split_proceeds(&self, amount: u64) -> Result<(Vec<u64>, u64)> {
+        let shares = self
+            .payees
+            .iter()
+            .map(|payee| apply_bps(amount, payee.share_bps))
+            .collect::<Result<Vec<u64>>>()?;
+        let dust = amount - shares.iter().sum::<u64>();
+        Ok((shares, dust))
+    }
+
+    /// Revenue if every ticket sold at its current price, saturating at `u64::MAX`. Multi-day
+    /// events are valued at the capacity and price of each session.
+    pub fn potential_gross(&self) -> u64 {
+        let gross = if self.sessions.is_empty() {
+            self.ticket_price as u128 * self.num_tickets as u128
+        } else {
+            self.sessions
+                .iter()
+                .map(|session| session.price as u128 * session.capacity as u128)
+                .sum()
+        };
+        u64::try_from(gross).unwrap_or(u64::MAX)
+    }
+
+    /// Deposit the platform requires for the event's potential gross, bounded by the platform's
+    /// minimum and maximum.
+    pub fn required_deposit(&self, platform: &PlatformConfig) -> Result<u64> {
+        Ok(apply_bps(self.potential_gross(), platform.deposit_bps)?
+            .max(platform.deposit_min)
+            .min(platform.deposit_max))
+    }
+
+    /// Proceeds held in the vault according to the event's ledger: sales, resale royalties and
+    /// transfer fees less refunds, payouts and affiliate commissions. Lamports sent to the vault by
+    /// other means and the deposit are not proceeds.
+    pub fn proceeds(&self) -> Result<u64> {
+        let collected = self
+            .gross_sales
+            .checked_add(self.organiser_royalties)
+            .and_then(|total| total.checked_add(self.transfer_fees))
+            .ok_or(ChainTicketError::Overflow)?;
+        let paid_out = self
+            .withdrawn
+            .checked_add(self.commissions_accrued)
+            .ok_or(ChainTicketError::Overflow)?;
+        net_proceeds(collected, self.refunds_paid, paid_out)
+    }
+
+    /// Reverses the commission `receipt` accrued to `affiliate`, which must be the affiliate
+    /// recorded on the receipt, when its ticket is refunded.
+    pub fn reverse_commission(
+        &mut self,
+        receipt: &Receipt,
+        affiliate: Option<&mut Affiliate>,
+    ) -> Result<()> {
+        if receipt.commission == 0 {
+            return Ok(());
+        }
+        let affiliate = affiliate.ok_or(ChainTicketError::InvalidAffiliate)?;
+        let reversed = affiliate.reverse_commission(receipt.commission)?;
+        self.commissions_accrued = self
+            .commissions_accrued
+            .checked_sub(reversed)
+            .ok_or(ChainTicketError::Overflow)?;
+        Ok(())
+    }
+
+    /// Records `amount` of affiliate commission paid out of the vault.
+    pub fn record_commission_payout(&mut self, amount: u64) -> Result<()> {
+        self.commissions_paid = self
+            .commissions_paid
+            .checked_add(amount)
+            .ok_or(ChainTicketError::Overflow)?;
+        Ok(())
+    }
+
+    /// Affiliate commissions accrued but not yet paid out of the vault.
+    pub fn commissions_owed(&self) -> u64 {
+        self.commissions_accrued.saturating_sub(self.commissions_paid)
+    }
+
+    /// Records a ticket issued and the lamports kept in the vault for it, zero for complimentary
+    /// and agent tickets.
+    pub fn record_sale(&mut self, amount: u64) -> Result<()> {
+        self.tickets_sold = self
+            .tickets_sold
+            .checked_add(1)
+            .ok_or(ChainTicketError::Overflow)?;
+        self.gross_sales = self
+            .gross_sales
+            .checked_add(amount)
+            .ok_or(ChainTicketError::Overflow)?;
+        Ok(())
+    }
+
+    /// Records a ticket refunded and the lamports returned out of the vault. Refunds are bounded by
+    /// the proceeds so that they cannot be paid out of the deposit once proceeds have been advanced
+    /// or claimed.
+    pub fn record_refund(&mut self, amount: u64) -> Result<()> {
+        require_gte!(self.proceeds()?, amount, ChainTicketError::RefundExceedsProceeds);
+        self.tickets_refunded = self
+            .tickets_refunded
+            .checked_add(1)
+            .ok_or(ChainTicketError::Overflow)?;
+        self.refunds_paid = self
+            .refunds_paid
+            .checked_add(amount)
+            .ok_or(ChainTicketError::Overflow)?;
+        Ok(())
+    }
+
+    /// Records a ticket burned without a refund.
+    pub fn record_burn(&mut self) -> Result<()> {
+        self.tickets_burned = self
+            .tickets_burned
+            .checked_add(1)
+            .ok_or(ChainTicketError::Overflow)?;
+        Ok(())
+    }
+
+    /// Records proceeds paid out of the vault and the platform fee taken from them.
+    pub fn record_withdrawal(&mut self, amount: u64, fee: u64) -> Result<()> {
+        self.withdrawn = self
+            .withdrawn
+            .checked_add(amount)
+            .ok_or(ChainTicketError::Overflow)?;
+        self.fees_paid = self
+            .fees_paid
+            .checked_add(fee)
+            .ok_or(ChainTicketError::Overflow)?;
+        Ok(())
+    }
+
+    /// Net sales not yet credited to the organiser's lifetime volume, marking them as credited.
+    pub fn take_uncredited_volume(&mut self) -> u64 {
+        let volume = self.gross_sales.saturating_sub(self.refunds_paid);
+        let uncredited = volume.saturating_sub(self.credited_volume);
+        self.credited_volume = volume.max(self.credited_volume);
+        uncredited
+    }
+
+    /// Proceeds in the vault that can no longer be refunded: the face value of checked in tickets,
+    /// resale royalties and transfer fees.
+    pub fn settled_proceeds(&self) -> Result<u64> {
+        self.checked_in_proceeds
+            .checked_add(self.organiser_royalties)
+            .and_then(|total| total.checked_add(self.transfer_fees))
+            .ok_or(ChainTicketError::Overflow.into())
+    }
+
+    /// Amount that can currently be advanced at `now`: the `advance_bps` share of the settled
+    /// proceeds less what has already been advanced. Once vesting has started advances are capped
+    /// at the vested proceeds not yet claimed so that they cannot bypass vesting.
+    pub fn available_advance(&self, advance_bps: u16, now: i64) -> Result<u64> {
+        let available =
+            apply_bps(self.settled_proceeds()?, advance_bps)?.saturating_sub(self.advanced);
+        if self.is_vesting(now) {
+            return Ok(available.min(self.claimable_vested(now)?));
+        }
+        Ok(available)
+    }
+
+    /// Records an advance of `amount` at `now`, counting it as a vested claim once vesting has
+    /// started.
+    pub fn record_advance(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.advanced = self
+            .advanced
+            .checked_add(amount)
+            .ok_or(ChainTicketError::Overflow)?;
+        if self.is_vesting(now) {
+            self.vested_claimed = self
+                .vested_claimed
+                .checked_add(amount)
+                .ok_or(ChainTicketError::Overflow)?;
+        }
+        Ok(())
+    }
+
+    /// Unix time the refund period elapses and vesting starts.
+    pub fn refund_period_end(&self) -> i64 {
+        self.event_date + self.refund_period
+    }
+
+    /// Whether the proceeds vest rather than being withdrawn at once, from the end of the refund
+    /// period.
+    pub fn is_vesting(&self, now: i64) -> bool {
+        self.vesting_period > 0 && now >= self.refund_period_end()
+    }
+
+    /// Records the proceeds to vest on the first vested claim or advance.
+    pub fn start_vesting(&mut self) -> Result<()> {
+        if self.vested_claimed == 0 {
+            self.vesting_total = self.proceeds()?;
+        }
+        Ok(())
+    }
+
+    /// Portion of `vesting_total` vested at `now`, unlocking linearly over `vesting_period`.
+    pub fn vested_amount(&self, now: i64) -> Result<u64> {
+        let elapsed = now.saturating_sub(self.refund_period_end());
+        if elapsed >= self.vesting_period {
+            return Ok(self.vesting_total);
+        }
+        let vested = (self.vesting_total as u128)
+            .checked_mul(elapsed.max(0) as u128)
+            .ok_or(ChainTicketError::Overflow)?
+            / self.vesting_period as u128;
+        u64::try_from(vested).map_err(|_| ChainTicketError::Overflow.into())
+    }
+
+    /// Vested proceeds not yet claimed at `now`, capped at the proceeds left in the vault since
+    /// refunds and commissions recorded after the first claim are not deducted from
+    /// `vesting_total`.
+    pub fn claimable_vested(&self, now: i64) -> Result<u64> {
+        Ok(self
+            .vested_amount(now)?
+            .saturating_sub(self.vested_claimed)
+            .min(self.proceeds()?))
+    }
+
+    /// Counts a ticket checked in for the first time and adds its face value to the settled
+    /// proceeds.
+    pub fn record_check_in(&mut self, receipt: &Receipt) -> Result<()> {
+        if receipt.checked_in_sessions == 0 {
+            self.tickets_checked_in = self
+                .tickets_checked_in
+                .checked_add(1)
+                .ok_or(ChainTicketError::Overflow)?;
+            self.checked_in_proceeds = self
+                .checked_in_proceeds
+                .checked_add(receipt.price)
+                .ok_or(ChainTicketError::Overflow)?;
+        }
+        Ok(())
+    }
+
+    /// Adds collected royalties to the running totals.
+    pub fn record_royalties(&mut self, organiser: u64, platform: u64) -> Result<()> {
+        self.organiser_royalties = self
+            .organiser_royalties
+            .checked_add(organiser)
+            .ok_or(ChainTicketError::Overflow)?;
+        self.platform_royalties = self
+            .platform_royalties
+            .checked_add(platform)
+            .ok_or(ChainTicketError::Overflow)?;
+        Ok(())
+    }
+
+    /// Whether `key` may perform an action requiring `permission`. The event authority holds
+    /// every permission, any other key must hold it through its staff account.
+    pub fn is_permitted(&self, key: &Pubkey, staff: Option<&Staff>, permission: u8) -> bool {
+        *key == self.authority
+            || staff.is_some_and(|staff| staff.key == *key && staff.has_permission(permission))
+    }
+
+    /// Checks that `sessions` is a valid entitlement for the event: zero for single session
+    /// events, otherwise a non-empty bitmask of existing sessions.
+    pub fn check_sessions(&self, sessions: u8) -> Result<()> {
+        if self.sessions.is_empty() {
+            require_eq!(sessions, 0, ChainTicketError::InvalidSessions);
+        } else {
+            require!(
+                sessions != 0 && (sessions as u16) >> self.sessions.len() == 0,
+                ChainTicketError::InvalidSessions
+            );
+        }
+        Ok(())
+    }
+
+    /// Entitles a new ticket to the sessions in the `sessions` bitmask, returning the price of
+    /// the ticket. Single session events must pass an empty bitmask and are priced at
+    /// `ticket_price`.
+    pub fn sell_sessions(&mut self, sessions: u8) -> Result<u64> {
+        self.check_sessions(sessions)?;
+        if self.sessions.is_empty() {
+            return Ok(self.ticket_price);
+        }
+
+        let mut price: u64 = 0;
+        for (index, session) in self.sessions.iter_mut().enumerate() {
+            if sessions & (1 << index) == 0 {
+                continue;
+            }
+            require_gt!(session.capacity, session.sold, ChainTicketError::SessionSoldOut);
+            session.sold += 1;
+            price = price
+                .checked_add(session.price)
+                .ok_or(ChainTicketError::Overflow)?;
+        }
+
+        Ok(price)
+    }
+
+    /// Releases the session capacity held by a ticket that is being refunded or burned.
+    pub fn release_sessions(&mut self, sessions: u8) {
+        for (index, session) in self.sessions.iter_mut().enumerate() {
+            if sessions & (1 << index) != 0 {
+                session.sold = session.sold.saturating_sub(1);
+            }
+        }
+    }
+}
+
+#[account]
+pub struct Waitlist {
+    // Stored seed to avoid computation on every call
+    pub bump: u8, // 1
+    // The event this waitlist belongs to
+    pub event: Pubkey, // 32
+    // Queued wallets in the order they joined, the first entry is served first
+    pub entries: Vec<WaitlistEntry>, // 4 + 40 * MAX_WAITLIST_ENTRIES
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct WaitlistEntry {
+    // The wallet that will receive the ticket
+    pub wallet: Pubkey, // 32
+    // Lamports escrowed in the waitlist account by this wallet
+    pub escrow: u64, // 8
+}
+
+#[account]
+pub struct Hold {
+    // Stored seed to avoid computation on every call
+    pub bump: u8, // 1
+    // The event the ticket is reserved from
+    pub event: Pubkey, // 32
+    // The wallet the ticket is reserved for, rent is returned to this wallet on close
+    pub holder: Pubkey, // 32
+    // Unix time after which the hold can be expired by anyone
+    pub expires_at: i64, // 8
+}
+
+#[account]
+pub struct Receipt {
+    // Stored seed to avoid computation on every call
+    pub bump: u8, // 1
+    // The event the ticket belongs to
+    pub event: Pubkey, // 32
+    // The wallet that paid for the ticket
+    pub payer: Pubkey, // 32
+    // The wallet that paid the rent for this receipt and the holder's token account, this is
+    // the payer unless the purchase was sponsored by the platform relayer
+    pub rent_payer: Pubkey, // 32
+    // The wallet whose associated token account holds the ticket
+    pub holder: Pubkey, // 32
+    // Lamports paid into the vault for the ticket, this is the amount returned on refund
+    pub price: u64, // 8
+    // Which party receives the refund should the ticket be refunded
+    pub refund_recipient: RefundRecipient, // 1
+    // Whether the ticket is complimentary, complimentary tickets cannot be refunded
+    pub comp: bool, // 1
+    // Unix time the ticket was last checked in at, zero if it has not been used
+    pub checked_in_at: i64, // 8
+    // Bitmask of the event sessions the ticket grants entry to, zero for single session events
+    pub sessions: u8, // 1
+    // Bitmask of the sessions the ticket has been checked in for, single session events use
+    // the first bit
+    pub checked_in_sessions: u8, // 1
+    // Whether the holder has claimed their proof of attendance token
+    pub attendance_claimed: bool, // 1
+    // Number of times the ticket has been transferred with `transfer_ticket` or swapped
+    pub transfers: u8, // 1
+    // The affiliate account credited with the sale, the default key if the sale was not referred
+    pub affiliate: Pubkey, // 32
+    // Commission accrued to the affiliate on the sale, reversed if the ticket is refunded
+    pub commission: u64, // 8
+    // Slot the receipt was created in, tells apart receipts re-created at the same address
+    pub issued_slot: u64, // 8
+}
+
+impl Receipt {
+    /// Initialises the receipt of a wallet receiving a ticket from the holder of `previous`. The
+    /// face value, entitlements and the affiliate commission carry over, the transfer is counted
+    /// and the new holder becomes the refund recipient.
+    pub fn transfer_from(
+        &mut self,
+        previous: &Receipt,
+        bump: u8,
+        holder: Pubkey,
+        rent_payer: Pubkey,
+        issued_slot: u64,
+    ) {
+        self.bump = bump;
+        self.event = previous.event;
+        self.payer = holder;
+        self.rent_payer = rent_payer;
+        self.holder = holder;
+        self.price = previous.price;
+        self.refund_recipient = RefundRecipient::Holder;
+        self.comp = previous.comp;
+        self.checked_in_at = 0;
+        self.sessions = previous.sessions;
+        self.checked_in_sessions = 0;
+        self.attendance_claimed = false;
+        self.transfers = previous.transfers.saturating_add(1);
+        self.affiliate = previous.affiliate;
+        self.commission = previous.commission;
+        self.issued_slot = issued_slot;
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RefundRecipient {
+    Payer,
+    Holder,
+}
+
+#[account]
+pub struct PlatformConfig {
+    // Stored seed to avoid computation on every call
+    pub bump: u8, // 1
+    // The platform owner, the only key able to amend this config
+    pub authority: Pubkey, // 32
+    // Relayer allowed to sponsor purchases and be reimbursed from the ticket price
+    pub relayer: Pubkey, // 32
+    // Maximum lamports the relayer can be reimbursed per purchase
+    pub relayer_fee_cap: u64, // 8
+    // Account receiving platform royalties on secondary sales
+    pub treasury: Pubkey, // 32
+    // Platform royalty on secondary sales as basis points of the sale price
+    pub resale_royalty_bps: u16, // 2
+    // Share of an event's settled proceeds, in basis points, organisers can withdraw ahead of
+    // the end of the refund period
+    pub advance_bps: u16, // 2
+    // Seconds over which event proceeds vest after the refund period, zero pays out at once
+    pub vesting_period: i64, // 8
+    // Deposit required to start sales, in basis points of the event's potential gross
+    pub deposit_bps: u16, // 2
+    // Lower bound on the deposit in lamports
+    pub deposit_min: u64, // 8
+    // Upper bound on the deposit in lamports
+    pub deposit_max: u64, // 8
+    // Platform fee rates by organiser lifetime volume, in ascending order of volume
+    pub fee_tiers: Vec<FeeTier>, // 4 + 10 * MAX_FEE_TIERS
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct FeeTier {
+    // Lifetime gross sales in lamports from which the tier applies
+    pub min_volume: u64, // 8
+    // Platform fee in basis points of the proceeds paid out
+    pub fee_bps: u16, // 2
+}
+
+impl PlatformConfig {
+    /// Platform fee rate charged to the organiser owning `profile`, zero if exempt.
+    pub fn fee_bps(&self, profile: &OrganiserProfile) -> u16 {
+        if profile.fee_exempt {
+            return 0;
+        }
+        tier_fee_bps(&self.fee_tiers, profile.lifetime_gross)
+    }
+}
+
+#[account]
+pub struct OrganiserProfile {
+    // Stored seed to avoid computation on every call
+    pub bump: u8, // 1
+    // The organiser's wallet, the authority of its events
+    pub authority: Pubkey, // 32
+    // Net ticket sales in lamports of the organiser's withdrawn events
+    pub lifetime_gross: u64, // 8
+    // Whether the platform waived its fee for the organiser, e.g. for registered nonprofits
+    pub fee_exempt: bool, // 1
+}
+
+#[account]
+pub struct SalesAgent {
+    // Stored seed to avoid computation on every call
+    pub bump: u8, // 1
+    // The event the agent sells tickets for
+    pub event: Pubkey, // 32
+    // The agent's signing key
+    pub agent: Pubkey, // 32
+    // Maximum number of tickets the agent can issue, setting this to zero revokes the agent
+    pub quota: u32, // 4
+    // Number of tickets issued by the agent
+    pub issued: u32, // 4
+    // Revenue the agent declared as collected off-chain, in the currency agreed with the
+    // organiser, used for reconciliation only
+    pub declared_revenue: u64, // 8
+}
+
+#[account]
+pub struct Affiliate {
+    // Stored seed to avoid computation on every call
+    pub bump: u8, // 1
+    // The event the affiliate refers sales to
+    pub event: Pubkey, // 32
+    // The affiliate's wallet, receives the commission
+    pub wallet: Pubkey, // 32
+    // Commission in basis points of the price of each referred sale
+    pub commission_bps: u16, // 2
+    // Number of tickets sold through the affiliate
+    pub sales: u32, // 4
+    // Commission accrued on referred sales less refunded sales, including what has been paid out
+    pub accrued: u64, // 8
+    // Commission paid out of the vault
+    pub paid: u64, // 8
+}
+
+impl Affiliate {
+    /// Records a referred sale of `amount` lamports, returning the commission accrued on it.
+    pub fn record_sale(&mut self, amount: u64) -> Result<u64> {
+        let commission = apply_bps(amount, self.commission_bps)?;
+        self.sales = self.sales.checked_add(1).ok_or(ChainTicketError::Overflow)?;
+        self.accrued = self
+            .accrued
+            .checked_add(commission)
+            .ok_or(ChainTicketError::Overflow)?;
+        Ok(commission)
+    }
+
+    /// Reverses the `commission` of a refunded sale, returning the amount reversed. Commission
+    /// already paid out cannot be reversed.
+    pub fn reverse_commission(&mut self, commission: u64) -> Result<u64> {
+        let unpaid = self
+            .accrued
+            .checked_sub(self.paid)
+            .ok_or(ChainTicketError::Overflow)?;
+        let reversed = commission.min(unpaid);
+        self.accrued = self
+            .accrued
+            .checked_sub(reversed)
+            .ok_or(ChainTicketError::Overflow)?;
+        Ok(reversed)
+    }
+
+    /// Commission accrued but not yet paid out.
+    pub fn unpaid(&self) -> Result<u64> {
+        self.accrued
+            .checked_sub(self.paid)
+            .ok_or(ChainTicketError::Overflow.into())
+    }
+
+    /// Records `amount` of commission paid out of the vault.
+    pub fn record_payout(&mut self, amount: u64) -> Result<()> {
+        self.paid = self
+            .paid
+            .checked_add(amount)
+            .ok_or(ChainTicketError::Overflow)?;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Staff {
+    // Stored seed to avoid computation on every call
+    pub bump: u8, // 1
+    // The event the staff key is scoped to
+    pub event: Pubkey, // 32
+    // The staff member's signing key
+    pub key: Pubkey, // 32
+    // Bitmask of `PERMISSION_*` flags granted to the key
+    pub permissions: u8, // 1
+}
+
+impl Staff {
+    /// Whether every flag in `permission` has been granted.
+    pub fn has_permission(&self, permission: u8) -> bool {
+        self.permissions & permission == permission
+    }
+}
+
+#[account]
+pub struct CheckInBatch {
+    // Stored seed to avoid computation on every call
+    pub bump: u8, // 1
+    // The event the check-ins belong to
+    pub event: Pubkey, // 32
+    // The scanner that settled the batch
+    pub scanner: Pubkey, // 32
+    // Merkle root of the batch's check-in leaves, see `utils::check_in_leaf`
+    pub root: [u8; 32], // 32
+    // Number of check-ins in the batch as declared by the scanner
+    pub count: u32, // 4
+    // Unix time the batch was settled at
+    pub settled_at: i64, // 8
+    // Position of the batch in the event's batches
+    pub index: u32, // 4
+}
+
+#[account]
+pub struct Listing {
+    // Stored seed to avoid computation on every call that requires a CPI
+    pub bump: u8, // 1
+    // The event the listed ticket belongs to
+    pub event: Pubkey, // 32
+    // The holder selling the ticket, proceeds and rent are returned to this wallet
+    pub seller: Pubkey, // 32
+    // Asking price in lamports
+    pub price: u64, // 8
+}
+
+#[account]
+pub struct Offer {
+    // Stored seed to avoid computation on every call
+    pub bump: u8, // 1
+    // The event the offer is for
+    pub event: Pubkey, // 32
+    // The wallet that made the offer and receives the ticket, the escrow and rent are returned
+    // to this wallet if the offer is cancelled
+    pub buyer: Pubkey, // 32
+    // Lamports escrowed in this account and paid for the ticket
+    pub price: u64, // 8
+    // Session entitlement the ticket must carry, zero for single session events
+    pub sessions: u8, // 1
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::constants::{PERMISSION_METADATA_EDITOR, PERMISSION_REFUND_AGENT, PERMISSION_SCANNER},
+    };
+
+    fn event() -> Event {
+        Event {
+            bump: 0,
+            authority: Pubkey::new_unique(),
+            vault: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            allow_purchase: true,
+            event_date: 1_000_000,
+            ticket_price: 100,
+            refund_period: 1_000,
+            num_tickets: 10,
+            waitlisted: 0,
+            waitlist_offers: 0,
+            held_tickets: 0,
+            hold_ttl: 0,
+            comp_quota: 0,
+            comps_issued: 0,
+            check_in_batches: 0,
+            settled_check_ins: 0,
+            resale_cap_bps: 0,
+            resale_royalty_bps: 0,
+            organiser_royalties: 0,
+            platform_royalties: 0,
+            transfers_enabled: false,
+            transfer_lockout: 0,
+            max_transfers: 0,
+            transfer_fee: 0,
+            transfer_fees: 0,
+            checked_in_proceeds: 0,
+            advanced: 0,
+            vesting_period: 0,
+            vesting_total: 0,
+            vested_claimed: 0,
+            flagged: false,
+            gross_sales: 0,
+            refunds_paid: 0,
+            withdrawn: 0,
+            fees_paid: 0,
+            tickets_sold: 0,
+            tickets_refunded: 0,
+            tickets_burned: 0,
+            tickets_checked_in: 0,
+            deposit: 0,
+            credited_volume: 0,
+            commissions_accrued: 0,
+            commissions_paid: 0,
+            open_offers: 0,
+            attendance_transferable: false,
+            sessions: vec![],
+            payees: vec![],
+        }
+    }
+
+    #[test]
+    fn waitlist_offers_are_not_available() {
+        let mut event = event();
+        event.tickets_sold = 8;
+        event.tickets_refunded = 2;
+        assert_eq!(event.available_tickets(), 4);
+
+        event.waitlist_offers = 3;
+        assert_eq!(event.available_tickets(), 1);
+        event.waitlist_offers = 5;
+        assert_eq!(event.available_tickets(), 0);
+    }
+
+    #[test]
+    fn held_tickets_are_not_available() {
+        let mut event = event();
+        event.tickets_sold = 6;
+        event.waitlist_offers = 1;
+        event.held_tickets = 2;
+        assert_eq!(event.available_tickets(), 1);
+
+        event.held_tickets = 4;
+        assert_eq!(event.available_tickets(), 0);
+    }
+
+    #[test]
+    fn holds_are_capped_at_a_share_of_unsold_tickets() {
+        let mut event = event();
+        event.num_tickets = 10;
+        event.tickets_sold = 2;
+        event.held_tickets = 3;
+        assert!(event.can_hold().unwrap());
+
+        event.held_tickets = 4;
+        assert!(!event.can_hold().unwrap());
+
+        // A single hold is always allowed while a ticket is unsold
+        event.num_tickets = 3;
+        event.held_tickets = 0;
+        assert!(event.can_hold().unwrap());
+        event.held_tickets = 1;
+        assert!(!event.can_hold().unwrap());
+    }
+
+    fn receipt(price: u64) -> Receipt {
+        Receipt {
+            bump: 0,
+            event: Pubkey::new_unique(),
+            payer: Pubkey::new_unique(),
+            rent_payer: Pubkey::new_unique(),
+            holder: Pubkey::new_unique(),
+            price,
+            refund_recipient: RefundRecipient::Payer,
+            comp: false,
+            checked_in_at: 0,
+            sessions: 0,
+            checked_in_sessions: 0,
+            attendance_claimed: false,
+            transfers: 0,
+            affiliate: Pubkey::default(),
+            commission: 0,
+            issued_slot: 0,
+        }
+    }
+
+    #[test]
+    fn check_in_is_counted_once_per_ticket() {
+        let mut event = event();
+        let mut receipt = receipt(100);
+
+        event.record_check_in(&receipt).unwrap();
+        assert_eq!(event.tickets_checked_in, 1);
+        assert_eq!(event.checked_in_proceeds, 100);
+
+        receipt.checked_in_sessions = 1;
+        event.record_check_in(&receipt).unwrap();
+        assert_eq!(event.tickets_checked_in, 1);
+        assert_eq!(event.checked_in_proceeds, 100);
+    }
+
+    #[test]
+    fn staff_needs_every_permission_flag() {
+        let event = event();
+        let key = Pubkey::new_unique();
+        let staff = Staff {
+            bump: 0,
+            event: Pubkey::new_unique(),
+            key,
+            permissions: PERMISSION_SCANNER | PERMISSION_REFUND_AGENT,
+        };
+
+        assert!(staff.has_permission(PERMISSION_SCANNER));
+        assert!(staff.has_permission(PERMISSION_SCANNER | PERMISSION_REFUND_AGENT));
+        assert!(!staff.has_permission(PERMISSION_SCANNER | PERMISSION_METADATA_EDITOR));
+
+        assert!(event.is_permitted(&key, Some(&staff), PERMISSION_SCANNER));
+        assert!(!event.is_permitted(&key, Some(&staff), PERMISSION_METADATA_EDITOR));
+        assert!(!event.is_permitted(&key, None, PERMISSION_SCANNER));
+        assert!(!event.is_permitted(&Pubkey::new_unique(), Some(&staff), PERMISSION_SCANNER));
+        assert!(event.is_permitted(&event.authority, None, PERMISSION_METADATA_EDITOR));
+    }
+
+    fn session(price: u64, capacity: u32) -> Session {
+        Session {
+            date: 1_000_000,
+            price,
+            capacity,
+            sold: 0,
+            attendance: 0,
+        }
+    }
+
+    #[test]
+    fn single_session_events_take_no_sessions() {
+        let mut event = event();
+        assert!(event.check_sessions(0).is_ok());
+        assert!(event.check_sessions(1).is_err());
+        assert_eq!(event.sell_sessions(0).unwrap(), event.ticket_price);
+    }
+
+    #[test]
+    fn sessions_are_priced_and_capped() {
+        let mut event = event();
+        event.sessions = vec![session(30, 2), session(50, 1)];
+
+        assert!(event.check_sessions(0).is_err());
+        assert!(event.check_sessions(0b100).is_err());
+        assert_eq!(event.sell_sessions(0b11).unwrap(), 80);
+        assert_eq!(event.sell_sessions(0b01).unwrap(), 30);
+        assert!(event.sell_sessions(0b10).is_err());
+        assert!(event.sell_sessions(0b01).is_err());
+        assert_eq!(event.sessions[0].sold, 2);
+        assert_eq!(event.sessions[1].sold, 1);
+
+        event.release_sessions(0b11);
+        assert_eq!(event.sessions[0].sold, 1);
+        assert_eq!(event.sessions[1].sold, 0);
+        assert_eq!(event.sell_sessions(0b10).unwrap(), 50);
+    }
+
+    #[test]
+    fn resale_price_is_capped_at_face_value_share() {
+        let mut event = event();
+        assert_eq!(event.max_resale_price(1_000).unwrap(), 0);
+
+        event.resale_cap_bps = 12_000;
+        assert_eq!(event.max_resale_price(1_000).unwrap(), 1_200);
+        assert_eq!(event.max_resale_price(999).unwrap(), 1_198);
+    }
+
+    #[test]
+    fn resale_royalties_never_exceed_price() {
+        let mut event = event();
+        event.resale_royalty_bps = 500;
+        assert_eq!(event.resale_royalties(1_000, 250).unwrap(), (50, 25));
+        assert_eq!(event.resale_royalties(0, 250).unwrap(), (0, 0));
+
+        event.resale_royalty_bps = 9_000;
+        assert_eq!(event.resale_royalties(1_000, 1_000).unwrap(), (900, 100));
+        assert!(event.resale_royalties(1_000, 1_100).is_err());
+    }
+
+    #[test]
+    fn transfer_rules_are_enforced() {
+        let mut event = event();
+        assert!(event.check_transfer(0, 0).is_err());
+
+        event.transfers_enabled = true;
+        event.transfer_lockout = 3_600;
+        event.max_transfers = 2;
+        assert!(event.check_transfer(event.event_date - 3_601, 1).is_ok());
+        assert!(event.check_transfer(event.event_date - 3_600, 1).is_err());
+        assert!(event.check_transfer(event.event_date - 3_601, 2).is_err());
+
+        event.max_transfers = 0;
+        assert!(event.check_transfer(event.event_date - 3_601, u8::MAX).is_ok());
+    }
+
+    #[test]
+    fn transfer_fees_are_recorded() {
+        let mut event = event();
+        event.transfer_fee = 40;
+        assert_eq!(event.record_transfer_fee().unwrap(), 40);
+        assert_eq!(event.record_transfer_fee().unwrap(), 40);
+        assert_eq!(event.transfer_fees, 80);
+        assert_eq!(event.settled_proceeds().unwrap(), 80);
+    }
+
+    #[test]
+    fn transferred_receipt_carries_entitlements() {
+        let mut previous = receipt(100);
+        previous.refund_recipient = RefundRecipient::Payer;
+        previous.comp = true;
+        previous.sessions = 0b101;
+        previous.checked_in_sessions = 0b001;
+        previous.attendance_claimed = true;
+        previous.transfers = 1;
+        previous.affiliate = Pubkey::new_unique();
+        previous.commission = 7;
+
+        let holder = Pubkey::new_unique();
+        let rent_payer = Pubkey::new_unique();
+        let mut next = receipt(0);
+        next.transfer_from(&previous, 254, holder, rent_payer, 42);
+
+        assert_eq!(next.bump, 254);
+        assert_eq!(next.event, previous.event);
+        assert_eq!(next.payer, holder);
+        assert_eq!(next.holder, holder);
+        assert_eq!(next.rent_payer, rent_payer);
+        assert_eq!(next.price, 100);
+        assert_eq!(next.refund_recipient, RefundRecipient::Holder);
+        assert!(next.comp);
+        assert_eq!(next.sessions, 0b101);
+        assert_eq!(next.checked_in_sessions, 0);
+        assert!(!next.attendance_claimed);
+        assert_eq!(next.transfers, 2);
+        assert_eq!(next.affiliate, previous.affiliate);
+        assert_eq!(next.commission, 7);
+        assert_eq!(next.issued_slot, 42);
+    }
+
+    #[test]
+    fn proceeds_split_by_share_with_dust() {
+        let mut event = event();
+        event.payees = vec![
+            Payee { recipient: Pubkey::new_unique(), share_bps: 3_333 },
+            Payee { recipient: Pubkey::new_unique(), share_bps: 3_333 },
+            Payee { recipient: Pubkey::new_unique(), share_bps: 3_334 },
+        ];
+
+        assert_eq!(event.split_proceeds(1_000).unwrap(), (vec![333, 333, 333], 1));
+        assert_eq!(event.split_proceeds(10_000).unwrap(), (vec![3_333, 3_333, 3_334], 0));
+        assert_eq!(event.split_proceeds(0).unwrap(), (vec![0, 0, 0], 0));
+    }
+
+    #[test]
+    fn advances_are_limited_to_settled_proceeds() {
+        let mut event = event();
+        event.checked_in_proceeds = 600;
+        event.organiser_royalties = 300;
+        event.transfer_fees = 100;
+        assert_eq!(event.settled_proceeds().unwrap(), 1_000);
+        assert_eq!(event.available_advance(5_000, 0).unwrap(), 500);
+
+        event.advanced = 200;
+        assert_eq!(event.available_advance(5_000, 0).unwrap(), 300);
+        event.advanced = 600;
+        assert_eq!(event.available_advance(5_000, 0).unwrap(), 0);
+        assert_eq!(event.available_advance(0, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn advances_are_capped_at_vested_proceeds() {
+        let mut event = event();
+        let end = event.refund_period_end();
+        event.vesting_period = 1_000;
+        event.gross_sales = 1_000;
+        event.checked_in_proceeds = 1_000;
+
+        assert_eq!(event.available_advance(5_000, end - 1).unwrap(), 500);
+        event.start_vesting().unwrap();
+        assert_eq!(event.vesting_total, 1_000);
+        assert_eq!(event.available_advance(5_000, end).unwrap(), 0);
+        assert_eq!(event.available_advance(5_000, end + 100).unwrap(), 100);
+
+        // Advances taken while vesting count as vested claims
+        event.record_advance(100, end + 100).unwrap();
+        event.withdrawn = 100;
+        assert_eq!(event.vested_claimed, 100);
+        assert_eq!(event.claimable_vested(end + 100).unwrap(), 0);
+        assert_eq!(event.available_advance(5_000, end + 300).unwrap(), 200);
+
+        // Advances taken before vesting do not
+        event.record_advance(50, end - 1).unwrap();
+        assert_eq!(event.advanced, 150);
+        assert_eq!(event.vested_claimed, 100);
+    }
+
+    #[test]
+    fn proceeds_vest_linearly_after_refund_period() {
+        let mut event = event();
+        let end = event.refund_period_end();
+        event.vesting_period = 1_000;
+        event.vesting_total = 1_000;
+        event.gross_sales = 1_000;
+
+        assert_eq!(event.vested_amount(end - 1).unwrap(), 0);
+        assert_eq!(event.vested_amount(end).unwrap(), 0);
+        assert_eq!(event.vested_amount(end + 250).unwrap(), 250);
+        assert_eq!(event.vested_amount(end + 1_000).unwrap(), 1_000);
+        assert_eq!(event.vested_amount(end + 5_000).unwrap(), 1_000);
+
+        event.vested_claimed = 100;
+        assert_eq!(event.claimable_vested(end + 250).unwrap(), 150);
+    }
+
+    #[test]
+    fn claimable_vested_is_capped_at_proceeds() {
+        let mut event = event();
+        let end = event.refund_period_end();
+        event.vesting_period = 1_000;
+        event.vesting_total = 1_000;
+        event.gross_sales = 1_000;
+        event.vested_claimed = 500;
+        event.withdrawn = 500;
+
+        // Refunds recorded after the first claim are not deducted from the vesting total
+        event.refunds_paid = 400;
+        assert_eq!(event.claimable_vested(end + 1_000).unwrap(), 100);
+    }
+
+    #[test]
+    fn ledger_tracks_tickets_and_proceeds() {
+        let mut event = event();
+        event.transfer_fee = 20;
+        event.record_sale(100).unwrap();
+        event.record_sale(100).unwrap();
+        event.record_sale(0).unwrap();
+        event.record_refund(100).unwrap();
+        event.record_burn().unwrap();
+        event.record_royalties(30, 10).unwrap();
+        event.record_transfer_fee().unwrap();
+        event.record_withdrawal(50, 5).unwrap();
+
+        assert_eq!(event.tickets_sold, 3);
+        assert_eq!(event.tickets_refunded, 1);
+        assert_eq!(event.tickets_burned, 1);
+        assert_eq!(event.outstanding_tickets(), 1);
+        assert_eq!(event.gross_sales, 200);
+        assert_eq!(event.refunds_paid, 100);
+        assert_eq!(event.platform_royalties, 10);
+        assert_eq!(event.fees_paid, 5);
+        assert_eq!(event.proceeds().unwrap(), 200 - 100 + 30 + 20 - 50);
+    }
+
+    #[test]
+    fn refunds_are_bounded_by_proceeds() {
+        // An advance on a checked in ticket leaves only the other ticket's price
+        let mut advanced = event();
+        advanced.record_sale(100).unwrap();
+        advanced.record_sale(100).unwrap();
+        advanced.record_withdrawal(100, 1).unwrap();
+        advanced.record_refund(100).unwrap();
+        assert!(advanced.record_refund(100).is_err());
+
+        // Vested claims likewise leave less than the price to refund
+        let mut vested = event();
+        vested.record_sale(100).unwrap();
+        vested.record_withdrawal(50, 0).unwrap();
+        assert!(vested.record_refund(100).is_err());
+        assert_eq!(vested.tickets_refunded, 0);
+    }
+
+    #[test]
+    fn ledger_rejects_overflow() {
+        let mut event = event();
+        event.gross_sales = u64::MAX;
+        assert!(event.record_sale(1).is_err());
+        assert!(event.record_sale(0).is_ok());
+
+        event.refunds_paid = 1;
+        event.organiser_royalties = 1;
+        assert!(event.proceeds().is_err());
+    }
+
+    fn platform() -> PlatformConfig {
+        PlatformConfig {
+            bump: 0,
+            authority: Pubkey::new_unique(),
+            relayer: Pubkey::new_unique(),
+            relayer_fee_cap: 0,
+            treasury: Pubkey::new_unique(),
+            resale_royalty_bps: 0,
+            advance_bps: 0,
+            vesting_period: 0,
+            deposit_bps: 100,
+            deposit_min: 50,
+            deposit_max: 5_000,
+            fee_tiers: vec![],
+        }
+    }
+
+    #[test]
+    fn deposit_scales_with_potential_gross() {
+        let mut event = event();
+        let platform = platform();
+        event.ticket_price = 1_000;
+        event.num_tickets = 200;
+        assert_eq!(event.potential_gross(), 200_000);
+        assert_eq!(event.required_deposit(&platform).unwrap(), 2_000);
+
+        event.num_tickets = 1;
+        assert_eq!(event.required_deposit(&platform).unwrap(), 50);
+        event.num_tickets = 10_000;
+        assert_eq!(event.required_deposit(&platform).unwrap(), 5_000);
+
+        event.ticket_price = u64::MAX;
+        assert_eq!(event.potential_gross(), u64::MAX);
+        assert_eq!(event.required_deposit(&platform).unwrap(), 5_000);
+    }
+
+    #[test]
+    fn multi_day_gross_is_valued_per_session() {
+        let mut event = event();
+        event.sessions = vec![session(30, 100), session(50, 40)];
+        assert_eq!(event.potential_gross(), 30 * 100 + 50 * 40);
+    }
+
+    #[test]
+    fn fee_rate_follows_organiser_volume() {
+        let mut platform = platform();
+        platform.fee_tiers = vec![
+            FeeTier { min_volume: 0, fee_bps: 300 },
+            FeeTier { min_volume: 1_000, fee_bps: 200 },
+        ];
+        let mut profile = OrganiserProfile {
+            bump: 0,
+            authority: Pubkey::new_unique(),
+            lifetime_gross: 999,
+            fee_exempt: false,
+        };
+
+        assert_eq!(platform.fee_bps(&profile), 300);
+        profile.lifetime_gross = 1_000;
+        assert_eq!(platform.fee_bps(&profile), 200);
+        profile.fee_exempt = true;
+        assert_eq!(platform.fee_bps(&profile), 0);
+    }
+
+    #[test]
+    fn volume_is_credited_once() {
+        let mut event = event();
+        event.gross_sales = 1_000;
+        event.refunds_paid = 100;
+        assert_eq!(event.take_uncredited_volume(), 900);
+        assert_eq!(event.take_uncredited_volume(), 0);
+
+        event.gross_sales = 1_500;
+        assert_eq!(event.take_uncredited_volume(), 500);
+        event.refunds_paid = 300;
+        assert_eq!(event.take_uncredited_volume(), 0);
+        assert_eq!(event.credited_volume, 1_400);
+    }
+
+    fn affiliate() -> Affiliate {
+        Affiliate {
+            bump: 0,
+            event: Pubkey::new_unique(),
+            wallet: Pubkey::new_unique(),
+            commission_bps: 1_000,
+            sales: 0,
+            accrued: 0,
+            paid: 0,
+        }
+    }
+
+    #[test]
+    fn affiliate_commission_accrues_and_is_paid() {
+        let mut affiliate = affiliate();
+        assert_eq!(affiliate.record_sale(1_000).unwrap(), 100);
+        assert_eq!(affiliate.record_sale(555).unwrap(), 55);
+        assert_eq!(affiliate.sales, 2);
+        assert_eq!(affiliate.unpaid().unwrap(), 155);
+
+        affiliate.record_payout(100).unwrap();
+        assert_eq!(affiliate.unpaid().unwrap(), 55);
+        // Paid commission cannot be reversed
+        assert_eq!(affiliate.reverse_commission(100).unwrap(), 55);
+        assert_eq!(affiliate.unpaid().unwrap(), 0);
+    }
+
+    #[test]
+    fn refund_reverses_receipt_commission() {
+        let mut event = event();
+        let mut affiliate = affiliate();
+        let unreferred = receipt(1_000);
+        let mut referred = receipt(1_000);
+        referred.affiliate = Pubkey::new_unique();
+        referred.commission = affiliate.record_sale(1_000).unwrap();
+        event.commissions_accrued = referred.commission;
+
+        assert!(event.reverse_commission(&referred, None).is_err());
+        event
+            .reverse_commission(&referred, Some(&mut affiliate))
+            .unwrap();
+        assert_eq!(event.commissions_accrued, 0);
+        assert_eq!(event.commissions_owed(), 0);
+        assert_eq!(affiliate.accrued, 0);
+
+        // Unreferred sales need no affiliate
+        assert!(event.reverse_commission(&unreferred, None).is_ok());
+    }
 }
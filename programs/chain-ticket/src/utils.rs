@@ -1,6 +1,105 @@
-// Utility so we don't have to add the `solana_sdk` crate as a dependency
-const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+use {
+    crate::state::Event,
+    anchor_lang::{
+        prelude::*,
+        solana_program::{hash::hashv, program::invoke, system_instruction},
+    },
+};
 
-pub fn sol_to_lamports(sol: f64) -> u64 {
-    (sol * LAMPORTS_PER_SOL as f64) as u64
+/// Transfers lamports from a signing system account through the system program, zero amounts
+/// are skipped.
+pub fn transfer_lamports<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    invoke(
+        &system_instruction::transfer(from.key, to.key, amount),
+        &[from.clone(), to.clone(), system_program.clone()],
+    )?;
+    Ok(())
+}
+
+/// Applies `update` to the event stored in `account` and writes it back, unless the event account
+/// has been closed. Used by instructions returning escrow, which must keep working once the event
+/// is gone.
+pub fn update_open_event(
+    account: &AccountInfo,
+    update: impl FnOnce(&mut Event) -> Result<()>,
+) -> Result<()> {
+    if account.owner != &crate::ID || account.data_is_empty() {
+        return Ok(());
+    }
+    let mut event = Event::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    update(&mut event)?;
+    event.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])
+}
+
+/// Domain separators so that a leaf can never be passed off as an internal node
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Leaf of an offline check-in Merkle tree: a ticket, identified by its receipt address and the
+/// slot the receipt was issued in, checked in for a session. The slot keeps a proof from being
+/// replayed against a receipt re-created at the same address. Must match
+/// `entry_pass::merkle::leaf`.
+pub fn check_in_leaf(receipt: &Pubkey, issued_slot: u64, session: u8) -> [u8; 32] {
+    hashv(&[
+        LEAF_PREFIX,
+        receipt.as_ref(),
+        &issued_slot.to_le_bytes(),
+        &[session],
+    ])
+    .to_bytes()
+}
+
+/// Verifies a Merkle proof built from sorted pairs, so sibling order does not need to be encoded.
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        let (left, right) = if node <= *sibling {
+            (node, *sibling)
+        } else {
+            (*sibling, node)
+        };
+        hashv(&[NODE_PREFIX, &left, &right]).to_bytes()
+    });
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[NODE_PREFIX, &left, &right]).to_bytes()
+    }
+
+    #[test]
+    fn proof_verifies_either_leaf() {
+        let a = check_in_leaf(&Pubkey::new_unique(), 10, 0);
+        let b = check_in_leaf(&Pubkey::new_unique(), 20, 1);
+        let root = root(a, b);
+
+        assert!(verify_merkle_proof(&[b], &root, a));
+        assert!(verify_merkle_proof(&[a], &root, b));
+        assert!(!verify_merkle_proof(&[a], &root, a));
+        assert!(!verify_merkle_proof(&[], &root, a));
+    }
+
+    #[test]
+    fn proof_is_bound_to_issued_slot_and_session() {
+        let receipt = Pubkey::new_unique();
+        let other = check_in_leaf(&Pubkey::new_unique(), 10, 0);
+        let root = root(check_in_leaf(&receipt, 10, 0), other);
+
+        assert!(verify_merkle_proof(&[other], &root, check_in_leaf(&receipt, 10, 0)));
+        // A receipt re-created at the same address is issued in a later slot
+        assert!(!verify_merkle_proof(&[other], &root, check_in_leaf(&receipt, 11, 0)));
+        assert!(!verify_merkle_proof(&[other], &root, check_in_leaf(&receipt, 10, 1)));
+    }
 }
@@ -4,14 +4,92 @@ import {
     InitEventFields,
     AmendEventFields,
     ChainTicketProgram,
+    checkInLeaf,
+    checkInNode,
+    PERMISSION_METADATA_EDITOR,
+    PERMISSION_REFUND_AGENT,
+    PERMISSION_SCANNER,
+    getAffiliateAddress,
+    getAgentAddress,
+    getAttendanceMintAddress,
+    getCheckInBatchAddress,
     getEventAddress,
+    getHoldAddress,
+    getListingAddress,
+    getMetadataAddress,
     getMintAddress,
+    getOfferAddress,
+    getProfileAddress,
+    getPlatformAddress,
+    getReceiptAddress,
+    getStaffAddress,
     getVaultAddress,
+    getWaitlistAddress,
     idl,
 } from "../app/lib/program";
-import { TOKEN_PROGRAM_ID, MintLayout, AccountLayout, getAssociatedTokenAddressSync } from "@solana/spl-token";
-import { PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+    TOKEN_PROGRAM_ID,
+    MintLayout,
+    AccountLayout,
+    getAssociatedTokenAddressSync,
+    createCloseAccountInstruction,
+} from "@solana/spl-token";
+import { Connection, Keypair, PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import * as fs from "fs";
+
+async function airdrop(connection: Connection, address: PublicKey, sol: number) {
+    const signature = await connection.requestAirdrop(address, sol * LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(signature, "confirmed");
+}
+
+// A funded wallet and a client signing with it
+async function fundedWallet(connection: Connection): Promise<[Keypair, ChainTicketProgram]> {
+    const keypair = Keypair.generate();
+    await airdrop(connection, keypair.publicKey, 10);
+    return [keypair, new ChainTicketProgram(connection, new anchor.Wallet(keypair))];
+}
+
+// Creates and starts the sale of an event organised by `organiser`'s wallet
+async function startEvent(
+    organiser: ChainTicketProgram,
+    fields: Partial<InitEventFields> = {},
+): Promise<PublicKey> {
+    const init = await organiser.getInitEventIx({
+        eventName: "test",
+        eventSymbol: "TST",
+        imageUri: "https://test.com/",
+        metadataUri: "https://testmetadata.com/",
+        eventDate: Math.floor(Date.now() / 1000) + 86400,
+        ticketPrice: 0.1,
+        numTickets: 10,
+        refundPeriod: 72000,
+        ...fields,
+    });
+    await organiser.sendTransaction([init]);
+    await organiser.sendTransaction([await organiser.getStartSaleIx()]);
+    return getEventAddress(organiser.program.provider.publicKey)[0];
+}
+
+// Asserts that a transaction fails with the program error `code`
+async function assertFails(transaction: Promise<string>, code: string) {
+    try {
+        await transaction;
+    } catch (error) {
+        const logs: string[] = error.logs ?? [];
+        assert.ok(
+            error.toString().includes(code) || logs.some(log => log.includes(code)),
+            error.toString(),
+        );
+        return;
+    }
+    assert.fail(`Transaction did not fail with ${code}`);
+}
 
+async function ticketBalance(connection: Connection, event: PublicKey, wallet: PublicKey): Promise<string> {
+    const ata = getAssociatedTokenAddressSync(getMintAddress(event)[0], wallet);
+    const ataInfo = await connection.getAccountInfo(ata);
+    return AccountLayout.decode(ataInfo.data).amount.toString();
+}
 
 describe("chain-ticket", () => {
     let chainTicket: ChainTicketProgram;
@@ -23,6 +101,13 @@ describe("chain-ticket", () => {
             provider.connection,
             provider.wallet as anchor.Wallet
         );
+
+        // The platform config is loaded from `fixtures/platform.json`, its owner and treasury
+        // receive fees so must be rent exempt
+        const platform = await chainTicket.program.account.platformConfig
+            .fetch(getPlatformAddress()[0]);
+        await airdrop(provider.connection, platform.authority, 1);
+        await airdrop(provider.connection, platform.treasury, 1);
     });
 
     it("init", async () => {
@@ -118,6 +203,12 @@ describe("chain-ticket", () => {
         const accountInfo = await chainTicket.program.account.event
             .fetch(eventAddress);
         assert.strictEqual(accountInfo.allowPurchase, true);
+
+        const vaultBalance = await chainTicket.program.provider.connection
+            .getBalance(getVaultAddress(eventAddress)[0]);
+        assert.ok(accountInfo.deposit.toNumber() > 0);
+        assert.ok(vaultBalance >= accountInfo.deposit.toNumber());
+        console.log("Deposit: OK");
     });
 
     it("buy", async () => {
@@ -195,11 +286,24 @@ describe("chain-ticket", () => {
 
     });
 
-    //it("withdraw", async () => {
-    //    const ix = await chainTicket.getWithdrawFundsIx();
-    //    const txid = await chainTicket.sendTransaction([ix]);
-    //    console.log("TXID:", txid);
-    //});
+    it("withdraw", async () => {
+        const profile = await chainTicket.getInitProfileIx();
+        const ix = await chainTicket.getWithdrawFundsIx();
+        await chainTicket.sendTransaction([profile, ix]);
+
+        const eventAddress = getEventAddress(chainTicket.program.provider.publicKey)[0];
+        const accountInfo = await chainTicket.program.account.event
+            .fetch(eventAddress);
+        const profileInfo = await chainTicket.program.account.organiserProfile
+            .fetch(getProfileAddress(chainTicket.program.provider.publicKey)[0]);
+
+        // Two tickets were burned rather than refunded, their price is the event's net sales
+        assert.strictEqual(accountInfo.withdrawn.toString(), accountInfo.ticketPrice.muln(2).toString());
+        // The deposit is returned
+        assert.strictEqual(accountInfo.deposit.toNumber(), 0);
+        assert.strictEqual(profileInfo.lifetimeGross.toString(), accountInfo.withdrawn.toString());
+        console.log("Withdrawal: OK");
+    });
 
     //it("cancel", async () => {
     //    const ix = await chainTicket.getCancelEventIx();
@@ -212,3 +316,1693 @@ describe("chain-ticket", () => {
         await chainTicket.sendTransaction([ix]);
     });
 });
+
+describe("waitlist", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("serves a refunded ticket to the head of the queue", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [buyer, buyerProgram] = await fundedWallet(connection);
+        const [waiting, waitingProgram] = await fundedWallet(connection);
+
+        const event = await startEvent(organiser, { numTickets: 1 });
+        await organiser.sendTransaction([await organiser.getInitWaitlistIx()]);
+
+        // Wallets can only queue once the event is sold out
+        await assertFails(
+            waitingProgram.sendTransaction([await waitingProgram.getJoinWaitlistIx(event)]),
+            "TicketsAvailable",
+        );
+        await buyerProgram.sendTransaction([await buyerProgram.getBuyTicketIx(event)]);
+
+        const escrow = (await organiser.program.account.event.fetch(event)).ticketPrice.toNumber();
+        const waitlistAddress = getWaitlistAddress(event)[0];
+        const before = await connection.getBalance(waitlistAddress);
+        await waitingProgram.sendTransaction([await waitingProgram.getJoinWaitlistIx(event)]);
+        assert.strictEqual(await connection.getBalance(waitlistAddress) - before, escrow);
+
+        let eventInfo = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(eventInfo.waitlisted, 1);
+
+        await organiser.sendTransaction([await organiser.getRefundTicketIx(buyer.publicKey)]);
+        eventInfo = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(eventInfo.waitlistOffers, 1);
+        console.log("Waitlist offer: OK");
+
+        // The freed ticket is reserved for the queue
+        const [, otherProgram] = await fundedWallet(connection);
+        await assertFails(
+            otherProgram.sendTransaction([await otherProgram.getBuyTicketIx(event)]),
+            "MaxTicketsExceeded",
+        );
+
+        await organiser.sendTransaction([
+            await organiser.getServeWaitlistIx(event, waiting.publicKey),
+        ]);
+
+        eventInfo = await organiser.program.account.event.fetch(event);
+        const waitlist = await organiser.program.account.waitlist.fetch(waitlistAddress);
+        assert.strictEqual(await ticketBalance(connection, event, waiting.publicKey), "1");
+        assert.strictEqual(eventInfo.waitlisted, 0);
+        assert.strictEqual(eventInfo.waitlistOffers, 0);
+        assert.strictEqual(waitlist.entries.length, 0);
+        // The escrow is moved to the vault as the ticket's payment
+        assert.strictEqual(await connection.getBalance(waitlistAddress), before);
+        console.log("Waitlist served: OK");
+    });
+
+    it("returns the escrow to a wallet leaving the queue", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [, buyerProgram] = await fundedWallet(connection);
+        const [waiting, waitingProgram] = await fundedWallet(connection);
+
+        const event = await startEvent(organiser, { numTickets: 1 });
+        await organiser.sendTransaction([await organiser.getInitWaitlistIx()]);
+        await buyerProgram.sendTransaction([await buyerProgram.getBuyTicketIx(event)]);
+
+        const waitlistAddress = getWaitlistAddress(event)[0];
+        const before = await connection.getBalance(waitlistAddress);
+        await waitingProgram.sendTransaction([await waitingProgram.getJoinWaitlistIx(event)]);
+        await waitingProgram.sendTransaction([await waitingProgram.getLeaveWaitlistIx(event)]);
+
+        const eventInfo = await organiser.program.account.event.fetch(event);
+        const waitlist = await organiser.program.account.waitlist.fetch(waitlistAddress);
+        assert.strictEqual(await connection.getBalance(waitlistAddress), before);
+        assert.strictEqual(eventInfo.waitlisted, 0);
+        assert.ok(!waitlist.entries.some(entry => entry.wallet.equals(waiting.publicKey)));
+        console.log("Waitlist left: OK");
+    });
+
+    it("evicts a head whose token account has been closed", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [, buyerProgram] = await fundedWallet(connection);
+        const [waiting, waitingProgram] = await fundedWallet(connection);
+        const [, otherProgram] = await fundedWallet(connection);
+
+        const event = await startEvent(organiser, { numTickets: 1 });
+        await organiser.sendTransaction([await organiser.getInitWaitlistIx()]);
+        await buyerProgram.sendTransaction([await buyerProgram.getBuyTicketIx(event)]);
+
+        const waitlistAddress = getWaitlistAddress(event)[0];
+        const before = await connection.getBalance(waitlistAddress);
+        await waitingProgram.sendTransaction([await waitingProgram.getJoinWaitlistIx(event)]);
+
+        // Only the event authority can evict a head that can still be served
+        await assertFails(
+            otherProgram.sendTransaction([
+                await otherProgram.getEvictWaitlistHeadIx(event, waiting.publicKey),
+            ]),
+            "Unauthorised",
+        );
+
+        const walletAta = getAssociatedTokenAddressSync(getMintAddress(event)[0], waiting.publicKey);
+        await waitingProgram.sendTransaction([
+            createCloseAccountInstruction(walletAta, waiting.publicKey, waiting.publicKey),
+        ]);
+        await otherProgram.sendTransaction([
+            await otherProgram.getEvictWaitlistHeadIx(event, waiting.publicKey),
+        ]);
+
+        const eventInfo = await organiser.program.account.event.fetch(event);
+        const waitlist = await organiser.program.account.waitlist.fetch(waitlistAddress);
+        assert.strictEqual(await connection.getBalance(waitlistAddress), before);
+        assert.strictEqual(eventInfo.waitlisted, 0);
+        assert.strictEqual(waitlist.entries.length, 0);
+        console.log("Waitlist eviction: OK");
+    });
+
+    it("keeps the event open while wallets are queued", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [holder, holderProgram] = await fundedWallet(connection);
+        const [, waitingProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser, { numTickets: 1 });
+        await organiser.sendTransaction([await organiser.getInitWaitlistIx()]);
+
+        // The cancelled event's deposit is forfeited to the platform owner
+        const platform = await organiser.program.account.platformConfig
+            .fetch(getPlatformAddress()[0]);
+        await airdrop(connection, platform.authority, 1);
+
+        await holderProgram.sendTransaction([await holderProgram.getBuyTicketIx(event)]);
+        await waitingProgram.sendTransaction([await waitingProgram.getJoinWaitlistIx(event)]);
+        await organiser.sendTransaction([await organiser.getRefundTicketIx(holder.publicKey)]);
+        await assertFails(
+            organiser.sendTransaction([await organiser.getCancelEventIx()]),
+            "WaitlistNotEmpty",
+        );
+
+        await waitingProgram.sendTransaction([await waitingProgram.getLeaveWaitlistIx(event)]);
+        await organiser.sendTransaction([await organiser.getCancelEventIx()]);
+        assert.isNull(await connection.getAccountInfo(event));
+    });
+});
+
+describe("holds", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("reserves a ticket until it is bought", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [buyer, buyerProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser, { numTickets: 1 });
+
+        await buyerProgram.sendTransaction([await buyerProgram.getPlaceHoldIx(event)]);
+        let eventInfo = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(eventInfo.heldTickets, 1);
+        console.log("Hold placed: OK");
+
+        // The only ticket is held, it can no longer be bought without the hold
+        const [, otherProgram] = await fundedWallet(connection);
+        await assertFails(
+            otherProgram.sendTransaction([await otherProgram.getBuyTicketIx(event)]),
+            "MaxTicketsExceeded",
+        );
+        await assertFails(
+            otherProgram.sendTransaction([await otherProgram.getPlaceHoldIx(event)]),
+            "MaxTicketsExceeded",
+        );
+
+        await buyerProgram.sendTransaction([
+            await buyerProgram.getBuyTicketIx(event, { hold: true }),
+        ]);
+        eventInfo = await organiser.program.account.event.fetch(event);
+        const hold = await connection.getAccountInfo(getHoldAddress(event, buyer.publicKey)[0]);
+        assert.strictEqual(eventInfo.heldTickets, 0);
+        assert.strictEqual(hold, null);
+        assert.strictEqual(await ticketBalance(connection, event, buyer.publicKey), "1");
+        console.log("Hold consumed: OK");
+    });
+
+    it("releases an expired hold and returns its rent", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [holder, holderProgram] = await fundedWallet(connection);
+        const [, cranker] = await fundedWallet(connection);
+        const event = await startEvent(organiser, { numTickets: 1 });
+
+        await holderProgram.sendTransaction([await holderProgram.getPlaceHoldIx(event)]);
+        await assertFails(
+            cranker.sendTransaction([await cranker.getExpireHoldIx(event, holder.publicKey)]),
+            "HoldNotExpired",
+        );
+
+        // A hold placed with a one second time to live, half of the unsold tickets can be held
+        const [shortHolder, shortHolderProgram] = await fundedWallet(connection);
+        await organiser.sendTransaction([
+            await organiser.getAmendEventIx({ numTickets: 4, holdTtl: 1 }),
+        ]);
+        await shortHolderProgram.sendTransaction([await shortHolderProgram.getPlaceHoldIx(event)]);
+        const rent = await connection.getBalance(getHoldAddress(event, shortHolder.publicKey)[0]);
+        await new Promise(resolve => setTimeout(resolve, 3000));
+
+        const before = await connection.getBalance(shortHolder.publicKey);
+        await cranker.sendTransaction([
+            await cranker.getExpireHoldIx(event, shortHolder.publicKey),
+        ]);
+        const eventInfo = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(await connection.getBalance(shortHolder.publicKey) - before, rent);
+        assert.strictEqual(eventInfo.heldTickets, 1);
+        console.log("Hold expired: OK");
+    });
+
+    it("caps the share of unsold tickets that can be held", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [, firstProgram] = await fundedWallet(connection);
+        const [, secondProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser, { numTickets: 2 });
+
+        await firstProgram.sendTransaction([await firstProgram.getPlaceHoldIx(event)]);
+        await assertFails(
+            secondProgram.sendTransaction([await secondProgram.getPlaceHoldIx(event)]),
+            "HoldLimitReached",
+        );
+        // The unheld ticket can still be bought
+        await secondProgram.sendTransaction([await secondProgram.getBuyTicketIx(event)]);
+    });
+});
+
+describe("gifts", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("refunds a gifted ticket to the payer", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [payer, payerProgram] = await fundedWallet(connection);
+        const recipient = Keypair.generate();
+        const event = await startEvent(organiser);
+
+        const ix = await payerProgram.getBuyGiftTicketIx(event, recipient.publicKey, "payer");
+        await payerProgram.sendTransaction([ix], [recipient]);
+
+        const receipt = await organiser.program.account.receipt
+            .fetch(getReceiptAddress(event, recipient.publicKey)[0]);
+        assert.strictEqual(await ticketBalance(connection, event, recipient.publicKey), "1");
+        assert.ok(receipt.payer.equals(payer.publicKey));
+        assert.ok(receipt.holder.equals(recipient.publicKey));
+        assert.ok(receipt.rentPayer.equals(payer.publicKey));
+        console.log("Gift: OK");
+
+        const before = await connection.getBalance(payer.publicKey);
+        await organiser.sendTransaction([await organiser.getRefundTicketIx(recipient.publicKey)]);
+        const after = await connection.getBalance(payer.publicKey);
+
+        // The payer receives the price and the receipt rent, the recipient holds no SOL
+        assert.ok(after - before >= receipt.price.toNumber());
+        assert.strictEqual(await connection.getBalance(recipient.publicKey), 0);
+        const eventData = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(eventData.grossSales.toNumber(), receipt.price.toNumber());
+        assert.strictEqual(eventData.refundsPaid.toNumber(), receipt.price.toNumber());
+        console.log("Gift refund: OK");
+    });
+
+    it("refunds a gifted ticket to the holder when asked to", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [payer, payerProgram] = await fundedWallet(connection);
+        const recipient = Keypair.generate();
+        const event = await startEvent(organiser);
+
+        const ix = await payerProgram.getBuyGiftTicketIx(event, recipient.publicKey, "holder");
+        await payerProgram.sendTransaction([ix], [recipient]);
+        const receipt = await organiser.program.account.receipt
+            .fetch(getReceiptAddress(event, recipient.publicKey)[0]);
+
+        const before = await connection.getBalance(payer.publicKey);
+        await organiser.sendTransaction([await organiser.getRefundTicketIx(recipient.publicKey)]);
+
+        // The price goes to the recipient, the rent the payer funded goes back to the payer
+        assert.strictEqual(await connection.getBalance(recipient.publicKey), receipt.price.toNumber());
+        assert.ok(await connection.getBalance(payer.publicKey) > before);
+        console.log("Gift refund to holder: OK");
+    });
+});
+
+describe("relayer", () => {
+    let connection: Connection;
+    let relayer: ChainTicketProgram;
+
+    before(async () => {
+        connection = anchor.AnchorProvider.env().connection;
+
+        // The relayer configured in `fixtures/platform.json`
+        const keypair = Keypair.fromSecretKey(
+            Uint8Array.from(JSON.parse(fs.readFileSync("fixtures/relayer.json", "utf8")))
+        );
+        await airdrop(connection, keypair.publicKey, 10);
+        relayer = new ChainTicketProgram(connection, new anchor.Wallet(keypair));
+    });
+
+    it("sponsors a purchase and its burn", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [buyer] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+        const eventInfo = await organiser.program.account.event.fetch(event);
+
+        const before = await connection.getBalance(buyer.publicKey);
+        const buy = await relayer.getBuyTicketIx(event, {
+            buyer: buyer.publicKey,
+            relayerFee: 5000,
+        });
+        await relayer.sendTransaction([buy], [buyer]);
+
+        const receipt = await relayer.program.account.receipt
+            .fetch(getReceiptAddress(event, buyer.publicKey)[0]);
+        const price = eventInfo.ticketPrice.toNumber();
+        assert.strictEqual(before - await connection.getBalance(buyer.publicKey), price);
+        assert.ok(receipt.rentPayer.equals(relayer.program.provider.publicKey));
+        // The reimbursement is kept out of the refundable price
+        assert.strictEqual(receipt.price.toNumber(), price - 5000);
+        console.log("Sponsored purchase: OK");
+
+        // The relayer pays the transaction fee and the receipt rent is returned to it, the token
+        // account rent is returned to the holder
+        const receiptAddress = getReceiptAddress(event, buyer.publicKey)[0];
+        const receiptRent = await connection.getBalance(receiptAddress);
+        const relayerBefore = await connection.getBalance(relayer.program.provider.publicKey);
+        const ataRent = await connection.getMinimumBalanceForRentExemption(AccountLayout.span);
+        const burn = await relayer.getBurnTicketIx(event, buyer.publicKey);
+        await relayer.sendTransaction([burn], [buyer]);
+        assert.strictEqual(before - await connection.getBalance(buyer.publicKey), price - ataRent);
+        // Less the fee of the transaction's two signatures
+        assert.strictEqual(
+            await connection.getBalance(relayer.program.provider.publicKey) - relayerBefore,
+            receiptRent - 10_000,
+        );
+        console.log("Sponsored burn: OK");
+    });
+
+    it("only reimburses the platform relayer up to the cap", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [, sponsor] = await fundedWallet(connection);
+        const [buyer] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+
+        await assertFails(
+            sponsor.sendTransaction([
+                await sponsor.getBuyTicketIx(event, { buyer: buyer.publicKey, relayerFee: 5000 }),
+            ], [buyer]),
+            "Unauthorised",
+        );
+        await assertFails(
+            relayer.sendTransaction([
+                await relayer.getBuyTicketIx(event, { buyer: buyer.publicKey, relayerFee: 10001 }),
+            ], [buyer]),
+            "RelayerFeeExceeded",
+        );
+    });
+});
+
+describe("complimentary tickets", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("issues comps within the quota and never refunds them", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const guest = Keypair.generate();
+        const event = await startEvent(organiser);
+
+        const vault = getVaultAddress(event)[0];
+        const before = await connection.getBalance(vault);
+        await organiser.sendTransaction([await organiser.getAmendEventIx({ compQuota: 1 })]);
+        await organiser.sendTransaction(
+            [await organiser.getIssueCompTicketIx(guest.publicKey)],
+            [guest],
+        );
+
+        const eventInfo = await organiser.program.account.event.fetch(event);
+        const receipt = await organiser.program.account.receipt
+            .fetch(getReceiptAddress(event, guest.publicKey)[0]);
+        assert.strictEqual(await ticketBalance(connection, event, guest.publicKey), "1");
+        assert.strictEqual(eventInfo.compsIssued, 1);
+        // Comps count as sold but add nothing to the gross sales
+        assert.strictEqual(eventInfo.ticketsSold, 1);
+        assert.strictEqual(eventInfo.grossSales.toNumber(), 0);
+        assert.strictEqual(await connection.getBalance(vault), before);
+        assert.ok(receipt.comp);
+        assert.strictEqual(receipt.price.toNumber(), 0);
+        console.log("Comp issued: OK");
+
+        const other = Keypair.generate();
+        await assertFails(
+            organiser.sendTransaction(
+                [await organiser.getIssueCompTicketIx(other.publicKey)],
+                [other],
+            ),
+            "CompQuotaExceeded",
+        );
+        await assertFails(
+            organiser.sendTransaction([await organiser.getRefundTicketIx(guest.publicKey)]),
+            "CompNotRefundable",
+        );
+    });
+});
+
+describe("sales agents", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("issues tickets against off-chain payment within the quota", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [agent, agentProgram] = await fundedWallet(connection);
+        const customer = Keypair.generate();
+        const event = await startEvent(organiser);
+
+        // Only registered agents can issue
+        await assertFails(
+            agentProgram.sendTransaction(
+                [await agentProgram.getAgentIssueTicketIx(event, customer.publicKey, 2500)],
+                [customer],
+            ),
+            "AccountNotInitialized",
+        );
+        await organiser.sendTransaction([await organiser.getRegisterAgentIx(agent.publicKey, 1)]);
+
+        const vault = getVaultAddress(event)[0];
+        const before = await connection.getBalance(vault);
+        await agentProgram.sendTransaction(
+            [await agentProgram.getAgentIssueTicketIx(event, customer.publicKey, 2500)],
+            [customer],
+        );
+
+        const salesAgent = await organiser.program.account.salesAgent
+            .fetch(getAgentAddress(event, agent.publicKey)[0]);
+        assert.strictEqual(await ticketBalance(connection, event, customer.publicKey), "1");
+        assert.strictEqual(salesAgent.issued, 1);
+        assert.strictEqual(salesAgent.declaredRevenue.toNumber(), 2500);
+        const eventInfo = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(eventInfo.ticketsSold, 1);
+        assert.strictEqual(eventInfo.grossSales.toNumber(), 0);
+        // The customer paid off-chain
+        assert.strictEqual(await connection.getBalance(vault), before);
+        console.log("Agent issue: OK");
+
+        const other = Keypair.generate();
+        await assertFails(
+            agentProgram.sendTransaction(
+                [await agentProgram.getAgentIssueTicketIx(event, other.publicKey, 2500)],
+                [other],
+            ),
+            "AgentQuotaExceeded",
+        );
+    });
+});
+
+describe("check-in", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("checks in a ticket once with a staff scanner", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [holder, holderProgram] = await fundedWallet(connection);
+        const [scanner, scannerProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+        await holderProgram.sendTransaction([await holderProgram.getBuyTicketIx(event)]);
+
+        // Staff need the scanner permission
+        await organiser.sendTransaction([
+            await organiser.getGrantRoleIx(scanner.publicKey, PERMISSION_REFUND_AGENT),
+        ]);
+        await assertFails(
+            scannerProgram.sendTransaction(
+                [await scannerProgram.getCheckInIx(event, holder.publicKey, 0, true)],
+                [holder],
+            ),
+            "Unauthorised",
+        );
+        await organiser.sendTransaction([
+            await organiser.getGrantRoleIx(scanner.publicKey, PERMISSION_SCANNER),
+        ]);
+
+        await scannerProgram.sendTransaction(
+            [await scannerProgram.getCheckInIx(event, holder.publicKey, 0, true)],
+            [holder],
+        );
+        const receipt = await organiser.program.account.receipt
+            .fetch(getReceiptAddress(event, holder.publicKey)[0]);
+        assert.ok(receipt.checkedInAt.toNumber() > 0);
+        // The ticket is kept as a souvenir
+        assert.strictEqual(await ticketBalance(connection, event, holder.publicKey), "1");
+        console.log("Check-in: OK");
+
+        await assertFails(
+            organiser.sendTransaction(
+                [await organiser.getCheckInIx(event, holder.publicKey)],
+                [holder],
+            ),
+            "AlreadyCheckedIn",
+        );
+        await assertFails(
+            organiser.sendTransaction([await organiser.getRefundTicketIx(holder.publicKey)]),
+            "AlreadyCheckedIn",
+        );
+    });
+});
+
+describe("offline check-in", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("claims check-ins settled as a Merkle batch", async () => {
+        const [organiserKeypair, organiser] = await fundedWallet(connection);
+        const [holder, holderProgram] = await fundedWallet(connection);
+        const [other, otherProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+        await holderProgram.sendTransaction([await holderProgram.getBuyTicketIx(event)]);
+        await otherProgram.sendTransaction([await otherProgram.getBuyTicketIx(event)]);
+
+        // Leaves are bound to the slot each receipt was issued in
+        const leafFor = async (wallet: PublicKey, session: number) => {
+            const receiptAddress = getReceiptAddress(event, wallet)[0];
+            const receipt = await organiser.program.account.receipt.fetch(receiptAddress);
+            return checkInLeaf(receiptAddress, receipt.issuedSlot, session);
+        };
+        const holderLeaf = await leafFor(holder.publicKey, 0);
+        const otherLeaf = await leafFor(other.publicKey, 0);
+        const root = checkInNode(holderLeaf, otherLeaf);
+
+        // Only the authority or staff scanners can settle
+        await assertFails(
+            holderProgram.sendTransaction([await holderProgram.getSettleCheckInsIx(event, root, 2)]),
+            "Unauthorised",
+        );
+        await organiser.sendTransaction([await organiser.getSettleCheckInsIx(event, root, 2)]);
+
+        const batch = await organiser.program.account.checkInBatch
+            .fetch(getCheckInBatchAddress(event, 0)[0]);
+        assert.deepStrictEqual(Buffer.from(batch.root), root);
+        assert.ok(batch.scanner.equals(organiserKeypair.publicKey));
+        const eventData = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(eventData.checkInBatches, 1);
+        assert.strictEqual(eventData.settledCheckIns, 2);
+
+        // A leaf for another session is not part of the batch
+        await assertFails(
+            holderProgram.sendTransaction([
+                await holderProgram.getClaimCheckInIx(event, holder.publicKey, 0, 1, [otherLeaf]),
+            ]),
+            "InvalidCheckInProof",
+        );
+
+        // Claims are permissionless so the scanner can record the check-in for the holder
+        await organiser.sendTransaction([
+            await organiser.getClaimCheckInIx(event, holder.publicKey, 0, 0, [otherLeaf]),
+        ]);
+        const receipt = await organiser.program.account.receipt
+            .fetch(getReceiptAddress(event, holder.publicKey)[0]);
+        assert.strictEqual(receipt.checkedInSessions, 1);
+        assert.ok(receipt.checkedInAt.eq(batch.settledAt));
+        console.log("Offline check-in claimed: OK");
+
+        await assertFails(
+            holderProgram.sendTransaction([
+                await holderProgram.getClaimCheckInIx(event, holder.publicKey, 0, 0, [otherLeaf]),
+            ]),
+            "AlreadyCheckedIn",
+        );
+        await assertFails(
+            organiser.sendTransaction([await organiser.getRefundTicketIx(holder.publicKey)]),
+            "AlreadyCheckedIn",
+        );
+    });
+});
+
+describe("resale listings", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("escrows a listed ticket until it is bought or withdrawn", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [seller, sellerProgram] = await fundedWallet(connection);
+        const [buyer, buyerProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+        await sellerProgram.sendTransaction([await sellerProgram.getBuyTicketIx(event)]);
+
+        // Resale is disabled until the organiser sets a cap
+        await assertFails(
+            sellerProgram.sendTransaction([await sellerProgram.getListTicketIx(event, 0.1)]),
+            "ResaleDisabled",
+        );
+        await organiser.sendTransaction([await organiser.getAmendEventIx({ resaleCapBps: 20_000 })]);
+        // Resales are transfers and follow the event's transfer rules
+        await assertFails(
+            sellerProgram.sendTransaction([await sellerProgram.getListTicketIx(event, 0.2)]),
+            "TransfersDisabled",
+        );
+        await organiser.sendTransaction([await organiser.getAmendEventIx({ transfersEnabled: true })]);
+        await assertFails(
+            sellerProgram.sendTransaction([await sellerProgram.getListTicketIx(event, 0.3)]),
+            "ResalePriceExceeded",
+        );
+
+        await sellerProgram.sendTransaction([await sellerProgram.getListTicketIx(event, 0.2)]);
+        assert.strictEqual(await ticketBalance(connection, event, seller.publicKey), "0");
+        const listing = await organiser.program.account.listing
+            .fetch(getListingAddress(event, seller.publicKey)[0]);
+        assert.strictEqual(listing.price.toNumber(), 0.2 * LAMPORTS_PER_SOL);
+
+        await sellerProgram.sendTransaction([await sellerProgram.getCancelListingIx(event)]);
+        assert.strictEqual(await ticketBalance(connection, event, seller.publicKey), "1");
+        assert.strictEqual(
+            await connection.getAccountInfo(getListingAddress(event, seller.publicKey)[0]),
+            null,
+        );
+        console.log("Listing cancelled: OK");
+
+        await sellerProgram.sendTransaction([await sellerProgram.getListTicketIx(event, 0.2)]);
+        const sellerBefore = await connection.getBalance(seller.publicKey);
+        await buyerProgram.sendTransaction([await buyerProgram.getBuyListingIx(event, seller.publicKey)]);
+
+        assert.strictEqual(await ticketBalance(connection, event, buyer.publicKey), "1");
+        // The seller is paid the asking price on top of the returned rent
+        assert.ok(await connection.getBalance(seller.publicKey) - sellerBefore > 0.2 * LAMPORTS_PER_SOL);
+        assert.strictEqual(
+            await connection.getAccountInfo(getReceiptAddress(event, seller.publicKey)[0]),
+            null,
+        );
+        // The refundable amount stays the face value
+        const receipt = await organiser.program.account.receipt
+            .fetch(getReceiptAddress(event, buyer.publicKey)[0]);
+        assert.strictEqual(receipt.price.toNumber(), 0.1 * LAMPORTS_PER_SOL);
+        assert.ok(receipt.holder.equals(buyer.publicKey));
+        assert.strictEqual(receipt.transfers, 1);
+        console.log("Listing bought: OK");
+    });
+
+    it("returns a listing to a closed token account and rejects checked in listings", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [seller, sellerProgram] = await fundedWallet(connection);
+        const [, buyerProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+        await organiser.sendTransaction([await organiser.getAmendEventIx({
+            resaleCapBps: 10_000,
+            transfersEnabled: true,
+        })]);
+        await sellerProgram.sendTransaction([await sellerProgram.getBuyTicketIx(event)]);
+        await sellerProgram.sendTransaction([await sellerProgram.getListTicketIx(event, 0.1)]);
+
+        // The emptied token account is re-created when the listing is withdrawn
+        const sellerAta = getAssociatedTokenAddressSync(getMintAddress(event)[0], seller.publicKey);
+        await sellerProgram.sendTransaction([
+            createCloseAccountInstruction(sellerAta, seller.publicKey, seller.publicKey),
+        ]);
+        await sellerProgram.sendTransaction([await sellerProgram.getCancelListingIx(event)]);
+        assert.strictEqual(await ticketBalance(connection, event, seller.publicKey), "1");
+        console.log("Listing returned: OK");
+
+        // A ticket checked in while listed can no longer be bought
+        await sellerProgram.sendTransaction([await sellerProgram.getListTicketIx(event, 0.1)]);
+        const receiptAddress = getReceiptAddress(event, seller.publicKey)[0];
+        const receipt = await organiser.program.account.receipt.fetch(receiptAddress);
+        const leaf = checkInLeaf(receiptAddress, receipt.issuedSlot, 0);
+        await organiser.sendTransaction([await organiser.getSettleCheckInsIx(event, leaf, 1)]);
+        await organiser.sendTransaction([
+            await organiser.getClaimCheckInIx(event, seller.publicKey, 0, 0, []),
+        ]);
+        await assertFails(
+            buyerProgram.sendTransaction([await buyerProgram.getBuyListingIx(event, seller.publicKey)]),
+            "AlreadyCheckedIn",
+        );
+    });
+
+    it("pays organiser and platform royalties out of the asking price", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [seller, sellerProgram] = await fundedWallet(connection);
+        const [, buyerProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+        await organiser.sendTransaction([await organiser.getAmendEventIx({
+            resaleCapBps: 20_000,
+            resaleRoyaltyBps: 1_000,
+            transfersEnabled: true,
+            transferFee: 0.01,
+        })]);
+        await sellerProgram.sendTransaction([await sellerProgram.getBuyTicketIx(event)]);
+        await sellerProgram.sendTransaction([await sellerProgram.getListTicketIx(event, 0.2)]);
+
+        const platform = await organiser.program.account.platformConfig
+            .fetch(getPlatformAddress()[0]);
+        const vault = getVaultAddress(event)[0];
+        const vaultBefore = await connection.getBalance(vault);
+        const treasuryBefore = await connection.getBalance(platform.treasury);
+        await buyerProgram.sendTransaction([await buyerProgram.getBuyListingIx(event, seller.publicKey)]);
+
+        const organiserRoyalty = 0.2 * LAMPORTS_PER_SOL * 1_000 / 10_000;
+        const platformRoyalty = 0.2 * LAMPORTS_PER_SOL * platform.resaleRoyaltyBps / 10_000;
+        const transferFee = 0.01 * LAMPORTS_PER_SOL;
+        assert.strictEqual(
+            await connection.getBalance(vault) - vaultBefore,
+            organiserRoyalty + transferFee,
+        );
+        assert.strictEqual(
+            await connection.getBalance(platform.treasury) - treasuryBefore,
+            platformRoyalty,
+        );
+        const eventData = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(eventData.organiserRoyalties.toNumber(), organiserRoyalty);
+        assert.strictEqual(eventData.platformRoyalties.toNumber(), platformRoyalty);
+        assert.strictEqual(eventData.transferFees.toNumber(), transferFee);
+        console.log("Resale royalties: OK");
+    });
+});
+
+describe("transfers", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("transfers tickets within the organiser's rules", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [sender, senderProgram] = await fundedWallet(connection);
+        const [recipient, recipientProgram] = await fundedWallet(connection);
+        const [third] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+        await senderProgram.sendTransaction([await senderProgram.getBuyTicketIx(event)]);
+
+        await assertFails(
+            senderProgram.sendTransaction(
+                [await senderProgram.getTransferTicketIx(event, recipient.publicKey)],
+                [recipient],
+            ),
+            "TransfersDisabled",
+        );
+        // The event is a day away
+        await organiser.sendTransaction([await organiser.getAmendEventIx({
+            transfersEnabled: true,
+            transferLockout: 2 * 86400,
+        })]);
+        await assertFails(
+            senderProgram.sendTransaction(
+                [await senderProgram.getTransferTicketIx(event, recipient.publicKey)],
+                [recipient],
+            ),
+            "TransferLockedOut",
+        );
+        await organiser.sendTransaction([await organiser.getAmendEventIx({
+            transferLockout: 3600,
+            maxTransfers: 1,
+            transferFee: 0.1,
+        })]);
+
+        const vault = getVaultAddress(event)[0];
+        const vaultBefore = await connection.getBalance(vault);
+        await senderProgram.sendTransaction(
+            [await senderProgram.getTransferTicketIx(event, recipient.publicKey)],
+            [recipient],
+        );
+
+        assert.strictEqual(await ticketBalance(connection, event, sender.publicKey), "0");
+        assert.strictEqual(await ticketBalance(connection, event, recipient.publicKey), "1");
+        assert.strictEqual(await connection.getBalance(vault) - vaultBefore, 0.1 * LAMPORTS_PER_SOL);
+        assert.strictEqual(
+            await connection.getAccountInfo(getReceiptAddress(event, sender.publicKey)[0]),
+            null,
+        );
+        const receipt = await organiser.program.account.receipt
+            .fetch(getReceiptAddress(event, recipient.publicKey)[0]);
+        assert.ok(receipt.holder.equals(recipient.publicKey));
+        assert.ok(receipt.rentPayer.equals(sender.publicKey));
+        assert.strictEqual(receipt.price.toNumber(), 0.1 * LAMPORTS_PER_SOL);
+        assert.strictEqual(receipt.transfers, 1);
+        console.log("Ticket transferred: OK");
+
+        await assertFails(
+            recipientProgram.sendTransaction(
+                [await recipientProgram.getTransferTicketIx(event, third.publicKey)],
+                [third],
+            ),
+            "TransferLimitReached",
+        );
+    });
+});
+
+describe("swaps", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("swaps tickets of two events atomically", async () => {
+        const [, organiserA] = await fundedWallet(connection);
+        const [, organiserB] = await fundedWallet(connection);
+        const [holderA, holderAProgram] = await fundedWallet(connection);
+        const [holderB, holderBProgram] = await fundedWallet(connection);
+        const eventA = await startEvent(organiserA);
+        const eventB = await startEvent(organiserB, { ticketPrice: 0.2 });
+        await holderAProgram.sendTransaction([await holderAProgram.getBuyTicketIx(eventA)]);
+        await holderBProgram.sendTransaction([await holderBProgram.getBuyTicketIx(eventB)]);
+        await organiserA.sendTransaction([await organiserA.getAmendEventIx({ transfersEnabled: true })]);
+
+        // Both events must allow transfers
+        await assertFails(
+            holderAProgram.sendTransaction(
+                [await holderAProgram.getSwapTicketsIx(eventA, eventB, holderB.publicKey)],
+                [holderB],
+            ),
+            "TransfersDisabled",
+        );
+        await organiserB.sendTransaction([await organiserB.getAmendEventIx({
+            transfersEnabled: true,
+            transferFee: 0.01,
+        })]);
+
+        // Holder B pays event B's transfer fee for giving up its ticket
+        const vaultB = getVaultAddress(eventB)[0];
+        const vaultBefore = await connection.getBalance(vaultB);
+        await holderAProgram.sendTransaction(
+            [await holderAProgram.getSwapTicketsIx(eventA, eventB, holderB.publicKey)],
+            [holderB],
+        );
+        assert.strictEqual(await connection.getBalance(vaultB) - vaultBefore, 0.01 * LAMPORTS_PER_SOL);
+        const eventBInfo = await organiserB.program.account.event.fetch(eventB);
+        assert.strictEqual(eventBInfo.transferFees.toNumber(), 0.01 * LAMPORTS_PER_SOL);
+
+        assert.strictEqual(await ticketBalance(connection, eventA, holderA.publicKey), "0");
+        assert.strictEqual(await ticketBalance(connection, eventA, holderB.publicKey), "1");
+        assert.strictEqual(await ticketBalance(connection, eventB, holderB.publicKey), "0");
+        assert.strictEqual(await ticketBalance(connection, eventB, holderA.publicKey), "1");
+        const receiptA = await holderAProgram.program.account.receipt
+            .fetch(getReceiptAddress(eventB, holderA.publicKey)[0]);
+        const receiptB = await holderAProgram.program.account.receipt
+            .fetch(getReceiptAddress(eventA, holderB.publicKey)[0]);
+        assert.strictEqual(receiptA.price.toNumber(), 0.2 * LAMPORTS_PER_SOL);
+        assert.strictEqual(receiptB.price.toNumber(), 0.1 * LAMPORTS_PER_SOL);
+        assert.strictEqual(receiptA.transfers, 1);
+        assert.strictEqual(
+            await connection.getAccountInfo(getReceiptAddress(eventA, holderA.publicKey)[0]),
+            null,
+        );
+        console.log("Tickets swapped: OK");
+    });
+
+    it("swaps the sessions of two tickets to the same event", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [holderA, holderAProgram] = await fundedWallet(connection);
+        const [holderB, holderBProgram] = await fundedWallet(connection);
+        const now = Math.floor(Date.now() / 1000);
+
+        await organiser.sendTransaction([await organiser.getInitEventIx({
+            eventName: "test",
+            eventSymbol: "TST",
+            imageUri: "https://test.com/",
+            metadataUri: "https://testmetadata.com/",
+            eventDate: now + 3 * 86400,
+            ticketPrice: 0.1,
+            numTickets: 10,
+            refundPeriod: 72000,
+        })]);
+        await organiser.sendTransaction([await organiser.getSetSessionsIx([
+            { date: now + 2 * 86400, price: 0.1, capacity: 5 },
+            { date: now + 3 * 86400, price: 0.2, capacity: 5 },
+        ])]);
+        await organiser.sendTransaction([await organiser.getStartSaleIx()]);
+        await organiser.sendTransaction([await organiser.getAmendEventIx({ transfersEnabled: true })]);
+        const event = getEventAddress(organiser.program.provider.publicKey)[0];
+
+        await holderAProgram.sendTransaction([await holderAProgram.getBuyTicketIx(event, { sessions: 0b01 })]);
+        await holderBProgram.sendTransaction([await holderBProgram.getBuyTicketIx(event, { sessions: 0b10 })]);
+        await holderAProgram.sendTransaction(
+            [await holderAProgram.getSwapSessionsIx(event, holderB.publicKey)],
+            [holderB],
+        );
+
+        const receiptA = await organiser.program.account.receipt
+            .fetch(getReceiptAddress(event, holderA.publicKey)[0]);
+        const receiptB = await organiser.program.account.receipt
+            .fetch(getReceiptAddress(event, holderB.publicKey)[0]);
+        assert.strictEqual(receiptA.sessions, 0b10);
+        assert.strictEqual(receiptA.price.toNumber(), 0.2 * LAMPORTS_PER_SOL);
+        assert.strictEqual(receiptB.sessions, 0b01);
+        assert.strictEqual(receiptB.price.toNumber(), 0.1 * LAMPORTS_PER_SOL);
+        // The tokens themselves do not move
+        assert.strictEqual(await ticketBalance(connection, event, holderA.publicKey), "1");
+        assert.strictEqual(await ticketBalance(connection, event, holderB.publicKey), "1");
+        console.log("Sessions swapped: OK");
+    });
+});
+
+describe("offers", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("fills an offer with a holder's ticket", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [holder, holderProgram] = await fundedWallet(connection);
+        const [buyer, buyerProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+        const price = 0.1;
+
+        // Resale is disabled by default
+        await assertFails(
+            buyerProgram.sendTransaction([await buyerProgram.getMakeOfferIx(event, price)]),
+            "ResaleDisabled",
+        );
+        await organiser.sendTransaction([await organiser.getAmendEventIx({ resaleCapBps: 10_000 })]);
+
+        await holderProgram.sendTransaction([await holderProgram.getBuyTicketIx(event)]);
+        await buyerProgram.sendTransaction([await buyerProgram.getMakeOfferIx(event, price)]);
+
+        // Accepting an offer is a transfer and follows the event's transfer rules
+        await assertFails(
+            holderProgram.sendTransaction(
+                [await holderProgram.getAcceptOfferIx(event, buyer.publicKey)],
+            ),
+            "TransfersDisabled",
+        );
+        await organiser.sendTransaction([await organiser.getAmendEventIx({ transfersEnabled: true })]);
+
+        const before = await connection.getBalance(holder.publicKey);
+        await holderProgram.sendTransaction(
+            [await holderProgram.getAcceptOfferIx(event, buyer.publicKey)],
+        );
+        const after = await connection.getBalance(holder.publicKey);
+
+        assert.strictEqual(await ticketBalance(connection, event, holder.publicKey), "0");
+        assert.strictEqual(await ticketBalance(connection, event, buyer.publicKey), "1");
+        assert.isNull(await connection.getAccountInfo(getOfferAddress(event, buyer.publicKey)[0]));
+        // The platform's 2.5% royalty is deducted, both receipts have the same rent
+        const lamports = price * LAMPORTS_PER_SOL;
+        assert.strictEqual(after - before, lamports - lamports * 250 / 10_000 - 5000);
+        const receipt = await buyerProgram.program.account.receipt
+            .fetch(getReceiptAddress(event, buyer.publicKey)[0]);
+        assert.ok(receipt.holder.equals(buyer.publicKey));
+        assert.strictEqual(receipt.transfers, 1);
+        assert.strictEqual((await buyerProgram.program.account.event.fetch(event)).openOffers, 0);
+        console.log("Offer accepted: OK");
+    });
+
+    it("returns the escrow of a cancelled offer", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [buyer, buyerProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+        await organiser.sendTransaction([await organiser.getAmendEventIx({ resaleCapBps: 10_000 })]);
+
+        await buyerProgram.sendTransaction([await buyerProgram.getMakeOfferIx(event, 0.1)]);
+        await buyerProgram.sendTransaction(
+            [await buyerProgram.getCancelOfferIx(event, buyer.publicKey)],
+        );
+
+        assert.isNull(await connection.getAccountInfo(getOfferAddress(event, buyer.publicKey)[0]));
+    });
+
+    it("keeps the event open while offers hold escrow", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [buyer, buyerProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+        await organiser.sendTransaction([await organiser.getAmendEventIx({ resaleCapBps: 10_000 })]);
+
+        // The cancelled event's deposit is forfeited to the platform owner
+        const platform = await organiser.program.account.platformConfig
+            .fetch(getPlatformAddress()[0]);
+        await airdrop(connection, platform.authority, 1);
+
+        await buyerProgram.sendTransaction([await buyerProgram.getMakeOfferIx(event, 0.1)]);
+        assert.strictEqual((await organiser.program.account.event.fetch(event)).openOffers, 1);
+        await assertFails(
+            organiser.sendTransaction([await organiser.getCancelEventIx()]),
+            "OffersOpen",
+        );
+
+        await buyerProgram.sendTransaction(
+            [await buyerProgram.getCancelOfferIx(event, buyer.publicKey)],
+        );
+        assert.strictEqual((await organiser.program.account.event.fetch(event)).openOffers, 0);
+        await organiser.sendTransaction([await organiser.getCancelEventIx()]);
+        assert.isNull(await connection.getAccountInfo(event));
+    });
+});
+
+describe("payees", () => {
+    let connection: Connection;
+
+    before(async () => {
+        connection = anchor.AnchorProvider.env().connection;
+
+        // The platform owner receives the fee so must be rent exempt
+        const [, client] = await fundedWallet(connection);
+        const platform = await client.program.account.platformConfig
+            .fetch(getPlatformAddress()[0]);
+        await airdrop(connection, platform.authority, 1);
+    });
+
+    it("splits withdrawn proceeds between payees", async () => {
+        const [organiserKeypair, organiser] = await fundedWallet(connection);
+        const [promoter] = await fundedWallet(connection);
+        const [artist] = await fundedWallet(connection);
+        const [, buyerProgram] = await fundedWallet(connection);
+        const [, otherProgram] = await fundedWallet(connection);
+
+        // The event has passed and has no refund period so proceeds can be withdrawn at once
+        await organiser.sendTransaction([await organiser.getInitEventIx({
+            eventName: "test",
+            eventSymbol: "TST",
+            imageUri: "https://test.com/",
+            metadataUri: "https://testmetadata.com/",
+            eventDate: Math.floor(Date.now() / 1000) - 60,
+            ticketPrice: 0.1,
+            numTickets: 10,
+            refundPeriod: 0,
+        })]);
+        await assertFails(
+            organiser.sendTransaction([await organiser.getSetPayeesIx([
+                { recipient: promoter.publicKey, shareBps: 6_000 },
+                { recipient: artist.publicKey, shareBps: 3_000 },
+            ])]),
+            "InvalidPayees",
+        );
+        await organiser.sendTransaction([await organiser.getSetPayeesIx([
+            { recipient: promoter.publicKey, shareBps: 6_000 },
+            { recipient: artist.publicKey, shareBps: 4_000 },
+        ])]);
+        await organiser.sendTransaction([await organiser.getStartSaleIx()]);
+        const event = getEventAddress(organiserKeypair.publicKey)[0];
+
+        // The table is locked once sales start
+        await assertFails(
+            organiser.sendTransaction([await organiser.getSetPayeesIx([])]),
+            "SaleStarted",
+        );
+
+        await buyerProgram.sendTransaction([await buyerProgram.getBuyTicketIx(event)]);
+        await otherProgram.sendTransaction([await otherProgram.getBuyTicketIx(event)]);
+
+        const promoterBefore = await connection.getBalance(promoter.publicKey);
+        const artistBefore = await connection.getBalance(artist.publicKey);
+        await organiser.sendTransaction([
+            await organiser.getInitProfileIx(),
+            await organiser.getWithdrawFundsIx(),
+        ]);
+
+        // 1% platform fee on the 0.2 SOL of sales, the rest split 60/40
+        const net = 0.2 * LAMPORTS_PER_SOL * 99 / 100;
+        assert.strictEqual(await connection.getBalance(promoter.publicKey) - promoterBefore, net * 0.6);
+        assert.strictEqual(await connection.getBalance(artist.publicKey) - artistBefore, net * 0.4);
+        assert.strictEqual(await connection.getBalance(getVaultAddress(event)[0]), 0);
+        // The ledger records the sales as paid out in full
+        const eventData = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(eventData.grossSales.toNumber(), 0.2 * LAMPORTS_PER_SOL);
+        assert.strictEqual(eventData.withdrawn.toNumber(), 0.2 * LAMPORTS_PER_SOL);
+        assert.strictEqual(eventData.feesPaid.toNumber(), 0.2 * LAMPORTS_PER_SOL / 100);
+        assert.strictEqual(eventData.ticketsSold, 2);
+        console.log("Payees paid: OK");
+    });
+});
+
+describe("advances", () => {
+    let connection: Connection;
+
+    before(async () => {
+        connection = anchor.AnchorProvider.env().connection;
+
+        // The platform owner receives the fee so must be rent exempt
+        const [, client] = await fundedWallet(connection);
+        const platform = await client.program.account.platformConfig
+            .fetch(getPlatformAddress()[0]);
+        await airdrop(connection, platform.authority, 1);
+    });
+
+    it("advances a share of the proceeds of checked in tickets", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [holder, holderProgram] = await fundedWallet(connection);
+        const [, otherProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+        await holderProgram.sendTransaction([await holderProgram.getBuyTicketIx(event)]);
+        await otherProgram.sendTransaction([await otherProgram.getBuyTicketIx(event)]);
+        await organiser.sendTransaction([await organiser.getInitProfileIx()]);
+
+        // Nothing is settled until a ticket is used
+        await assertFails(
+            organiser.sendTransaction([await organiser.getAdvanceFundsIx(0.01)]),
+            "AdvanceExceeded",
+        );
+        await organiser.sendTransaction(
+            [await organiser.getCheckInIx(event, holder.publicKey)],
+            [holder],
+        );
+
+        const platform = await organiser.program.account.platformConfig
+            .fetch(getPlatformAddress()[0]);
+        const available = 0.1 * platform.advanceBps / 10_000;
+        await assertFails(
+            organiser.sendTransaction([await organiser.getAdvanceFundsIx(available * 2)]),
+            "AdvanceExceeded",
+        );
+
+        const vault = getVaultAddress(event)[0];
+        const vaultBefore = await connection.getBalance(vault);
+        await organiser.sendTransaction([await organiser.getAdvanceFundsIx(available)]);
+
+        assert.strictEqual(vaultBefore - await connection.getBalance(vault), available * LAMPORTS_PER_SOL);
+        const eventData = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(eventData.checkedInProceeds.toNumber(), 0.1 * LAMPORTS_PER_SOL);
+        assert.strictEqual(eventData.advanced.toNumber(), available * LAMPORTS_PER_SOL);
+        console.log("Advance: OK");
+
+        // The advance is used up
+        await assertFails(
+            organiser.sendTransaction([await organiser.getAdvanceFundsIx(0.01)]),
+            "AdvanceExceeded",
+        );
+    });
+});
+
+describe("vesting", () => {
+    let connection: Connection;
+
+    before(async () => {
+        connection = anchor.AnchorProvider.env().connection;
+
+        // The platform owner receives the fee so must be rent exempt
+        const [, client] = await fundedWallet(connection);
+        const platform = await client.program.account.platformConfig
+            .fetch(getPlatformAddress()[0]);
+        await airdrop(connection, platform.authority, 1);
+    });
+
+    it("only lets a started event be postponed", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const eventDate = Math.floor(Date.now() / 1000) + 86400;
+        const event = await startEvent(organiser, { eventDate });
+
+        await assertFails(
+            organiser.sendTransaction([await organiser.getAmendEventIx({ eventDate: eventDate - 3600 })]),
+            "EventBroughtForward",
+        );
+        await organiser.sendTransaction([await organiser.getAmendEventIx({ eventDate: eventDate + 3600 })]);
+        const postponed = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(postponed.eventDate.toNumber(), eventDate + 3600);
+        console.log("Event date lock: OK");
+    });
+
+    it("vests proceeds after the refund period", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [buyer, buyerProgram] = await fundedWallet(connection);
+        const [, pendingOrganiser] = await fundedWallet(connection);
+
+        // Nothing vests during the refund period
+        await startEvent(pendingOrganiser);
+        await pendingOrganiser.sendTransaction([await pendingOrganiser.getInitProfileIx()]);
+        await assertFails(
+            pendingOrganiser.sendTransaction([await pendingOrganiser.getClaimVestedIx()]),
+            "EventNotEnded",
+        );
+
+        const event = await startEvent(organiser, {
+            eventDate: Math.floor(Date.now() / 1000) - 60,
+            refundPeriod: 0,
+        });
+        await buyerProgram.sendTransaction([await buyerProgram.getBuyTicketIx(event)]);
+        await organiser.sendTransaction([await organiser.getInitProfileIx()]);
+
+        const platform = await organiser.program.account.platformConfig
+            .fetch(getPlatformAddress()[0]);
+        const eventData = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(eventData.vestingPeriod.toNumber(), platform.vestingPeriod.toNumber());
+
+        const vault = getVaultAddress(event)[0];
+        const vaultBefore = await connection.getBalance(vault);
+        await organiser.sendTransaction([await organiser.getClaimVestedIx()]);
+
+        // The platform's vesting period has elapsed so the proceeds vest at once
+        assert.strictEqual(vaultBefore - await connection.getBalance(vault), 0.1 * LAMPORTS_PER_SOL);
+        const claimed = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(claimed.vestingTotal.toNumber(), 0.1 * LAMPORTS_PER_SOL);
+        assert.strictEqual(claimed.vestedClaimed.toNumber(), 0.1 * LAMPORTS_PER_SOL);
+        console.log("Vested claim: OK");
+
+        await assertFails(
+            organiser.sendTransaction([await organiser.getClaimVestedIx()]),
+            "NothingVested",
+        );
+        // The claimed proceeds can no longer be refunded out of the deposit
+        await assertFails(
+            organiser.sendTransaction([await organiser.getRefundTicketIx(buyer.publicKey)]),
+            "RefundExceedsProceeds",
+        );
+        // Only the deposit is left to withdraw
+        await organiser.sendTransaction([await organiser.getWithdrawFundsIx()]);
+        assert.strictEqual(await connection.getBalance(vault), 0);
+    });
+
+    it("only lets the platform authority flag events", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [, holderProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+        await holderProgram.sendTransaction([await holderProgram.getBuyTicketIx(event)]);
+
+        await assertFails(
+            organiser.sendTransaction([await organiser.getFlagEventIx(event, true)]),
+            "Unauthorised",
+        );
+        await assertFails(
+            holderProgram.sendTransaction([await holderProgram.getRefundFlaggedIx(event)]),
+            "EventNotFlagged",
+        );
+    });
+});
+
+describe("deposits", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("scales the deposit to the event's potential gross", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+        const platform = await organiser.program.account.platformConfig
+            .fetch(getPlatformAddress()[0]);
+        const vault = getVaultAddress(event)[0];
+
+        // Ten tickets at 0.1 SOL
+        const expected = (gross: number) => Math.min(
+            Math.max(gross * platform.depositBps / 10_000, platform.depositMin.toNumber()),
+            platform.depositMax.toNumber(),
+        );
+        const started = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(started.deposit.toNumber(), expected(LAMPORTS_PER_SOL));
+        assert.strictEqual(await connection.getBalance(vault), started.deposit.toNumber());
+
+        // Raising the capacity tops up the deposit
+        await organiser.sendTransaction([await organiser.getAmendEventIx({ numTickets: 100 })]);
+        const amended = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(amended.deposit.toNumber(), expected(10 * LAMPORTS_PER_SOL));
+        assert.strictEqual(await connection.getBalance(vault), amended.deposit.toNumber());
+
+        // Lowering it does not refund the difference
+        await organiser.sendTransaction([await organiser.getAmendEventIx({ numTickets: 10 })]);
+        const lowered = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(lowered.deposit.toNumber(), amended.deposit.toNumber());
+        console.log("Deposit scaling: OK");
+    });
+});
+
+describe("fee tiers", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("requires a profile to withdraw and the platform authority to set fees", async () => {
+        const [organiserKeypair, organiser] = await fundedWallet(connection);
+        await startEvent(organiser, {
+            eventDate: Math.floor(Date.now() / 1000) - 60,
+            refundPeriod: 0,
+        });
+
+        await assertFails(
+            organiser.sendTransaction([await organiser.getWithdrawFundsIx()]),
+            "AccountNotInitialized",
+        );
+        await organiser.sendTransaction([await organiser.getInitProfileIx()]);
+        const profile = await organiser.program.account.organiserProfile
+            .fetch(getProfileAddress(organiserKeypair.publicKey)[0]);
+        assert.ok(profile.authority.equals(organiserKeypair.publicKey));
+        assert.strictEqual(profile.lifetimeGross.toNumber(), 0);
+        assert.strictEqual(profile.feeExempt, false);
+
+        // Organisers cannot waive their own fees
+        await assertFails(
+            organiser.sendTransaction(
+                [await organiser.getSetFeeExemptionIx(organiserKeypair.publicKey, true)],
+            ),
+            "Unauthorised",
+        );
+        await assertFails(
+            organiser.sendTransaction([await organiser.getSetFeeTiersIx([{ minVolume: 0, feeBps: 0 }])]),
+            "Unauthorised",
+        );
+        console.log("Fee administration: OK");
+    });
+});
+
+describe("affiliates", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("reverses the commission of refunded sales", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [affiliate, affiliateProgram] = await fundedWallet(connection);
+        const [buyer, buyerProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+        await organiser.sendTransaction([await organiser.getRegisterAffiliateIx(affiliate.publicKey, 1_000)]);
+
+        // Affiliates cannot earn commission on their own purchases
+        await assertFails(
+            affiliateProgram.sendTransaction([
+                await affiliateProgram.getBuyTicketIx(event, { affiliate: affiliate.publicKey }),
+            ]),
+            "InvalidAffiliate",
+        );
+
+        await buyerProgram.sendTransaction([
+            await buyerProgram.getBuyTicketIx(event, { affiliate: affiliate.publicKey }),
+        ]);
+        const affiliateAddress = getAffiliateAddress(event, affiliate.publicKey)[0];
+        let affiliateData = await organiser.program.account.affiliate.fetch(affiliateAddress);
+        assert.strictEqual(affiliateData.sales, 1);
+        assert.strictEqual(affiliateData.accrued.toNumber(), 0.1 * LAMPORTS_PER_SOL / 10);
+        const receipt = await organiser.program.account.receipt
+            .fetch(getReceiptAddress(event, buyer.publicKey)[0]);
+        assert.ok(receipt.affiliate.equals(affiliateAddress));
+
+        // Commission is paid once the refund period has elapsed
+        await assertFails(
+            organiser.sendTransaction([await organiser.getClaimCommissionIx(event, affiliate.publicKey)]),
+            "EventNotEnded",
+        );
+
+        await organiser.sendTransaction([await organiser.getRefundTicketIx(buyer.publicKey)]);
+        affiliateData = await organiser.program.account.affiliate.fetch(affiliateAddress);
+        assert.strictEqual(affiliateData.accrued.toNumber(), 0);
+        const eventData = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(eventData.commissionsAccrued.toNumber(), 0);
+        console.log("Commission reversed: OK");
+    });
+
+    it("pays accrued commission out of the vault", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [affiliate] = await fundedWallet(connection);
+        const [, buyerProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser, {
+            eventDate: Math.floor(Date.now() / 1000) - 60,
+            refundPeriod: 0,
+        });
+        await organiser.sendTransaction([await organiser.getRegisterAffiliateIx(affiliate.publicKey, 1_000)]);
+        await buyerProgram.sendTransaction([
+            await buyerProgram.getBuyTicketIx(event, { affiliate: affiliate.publicKey }),
+        ]);
+
+        const commission = 0.1 * LAMPORTS_PER_SOL / 10;
+        const vault = getVaultAddress(event)[0];
+        const vaultBefore = await connection.getBalance(vault);
+        const before = await connection.getBalance(affiliate.publicKey);
+        // Anyone can pay the affiliate
+        await buyerProgram.sendTransaction([await buyerProgram.getClaimCommissionIx(event, affiliate.publicKey)]);
+
+        assert.strictEqual(await connection.getBalance(affiliate.publicKey) - before, commission);
+        assert.strictEqual(vaultBefore - await connection.getBalance(vault), commission);
+        const eventData = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(eventData.commissionsPaid.toNumber(), commission);
+        console.log("Commission paid: OK");
+
+        await assertFails(
+            buyerProgram.sendTransaction([await buyerProgram.getClaimCommissionIx(event, affiliate.publicKey)]),
+            "NothingAccrued",
+        );
+    });
+
+    it("pays owed commission when the proceeds are withdrawn", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [affiliate] = await fundedWallet(connection);
+        const [, buyerProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser, {
+            eventDate: Math.floor(Date.now() / 1000) - 60,
+            refundPeriod: 0,
+        });
+        await organiser.sendTransaction([
+            await organiser.getRegisterAffiliateIx(affiliate.publicKey, 1_000),
+            await organiser.getInitProfileIx(),
+        ]);
+        await buyerProgram.sendTransaction([
+            await buyerProgram.getBuyTicketIx(event, { affiliate: affiliate.publicKey }),
+        ]);
+
+        const commission = 0.1 * LAMPORTS_PER_SOL / 10;
+        const before = await connection.getBalance(affiliate.publicKey);
+        await organiser.sendTransaction([await organiser.getWithdrawFundsIx()]);
+
+        assert.strictEqual(await connection.getBalance(affiliate.publicKey) - before, commission);
+        const eventData = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(eventData.commissionsPaid.toNumber(), commission);
+        // Nothing is left in the vault
+        assert.strictEqual(await connection.getBalance(getVaultAddress(event)[0]), 0);
+        console.log("Commission paid on withdrawal: OK");
+    });
+});
+
+describe("staff", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("grants and revokes permissions", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [staff] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+        const staffAddress = getStaffAddress(event, staff.publicKey)[0];
+
+        await organiser.sendTransaction([
+            await organiser.getGrantRoleIx(staff.publicKey, PERMISSION_SCANNER),
+            await organiser.getGrantRoleIx(staff.publicKey, PERMISSION_REFUND_AGENT),
+        ]);
+        let staffInfo = await organiser.program.account.staff.fetch(staffAddress);
+        assert.strictEqual(staffInfo.permissions, PERMISSION_SCANNER | PERMISSION_REFUND_AGENT);
+
+        await organiser.sendTransaction([
+            await organiser.getRevokeRoleIx(staff.publicKey, PERMISSION_SCANNER),
+        ]);
+        staffInfo = await organiser.program.account.staff.fetch(staffAddress);
+        assert.strictEqual(staffInfo.permissions, PERMISSION_REFUND_AGENT);
+
+        // The staff account is closed once it holds no permission
+        await organiser.sendTransaction([
+            await organiser.getRevokeRoleIx(staff.publicKey, PERMISSION_REFUND_AGENT),
+        ]);
+        assert.isNull(await connection.getAccountInfo(staffAddress));
+        console.log("Roles: OK");
+    });
+
+    it("lets metadata editors update the ticket metadata", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [editor, editorClient] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+        const fields = { eventName: "renamed", eventSymbol: "RNM", imageUri: "https://renamed.com/" };
+
+        await assertFails(
+            editorClient.sendTransaction([await editorClient.getUpdateMetadataIx(event, fields)]),
+            "Unauthorised",
+        );
+        await organiser.sendTransaction([
+            await organiser.getGrantRoleIx(editor.publicKey, PERMISSION_SCANNER),
+        ]);
+        await assertFails(
+            editorClient.sendTransaction([await editorClient.getUpdateMetadataIx(event, fields, true)]),
+            "Unauthorised",
+        );
+
+        await organiser.sendTransaction([
+            await organiser.getGrantRoleIx(editor.publicKey, PERMISSION_METADATA_EDITOR),
+        ]);
+        await editorClient.sendTransaction([await editorClient.getUpdateMetadataIx(event, fields, true)]);
+        const metadata = await connection.getAccountInfo(getMetadataAddress(getMintAddress(event)[0])[0]);
+        assert.ok(metadata.data.includes(Buffer.from(fields.eventName)));
+        assert.ok(metadata.data.includes(Buffer.from(fields.imageUri)));
+        console.log("Metadata editor: OK");
+    });
+});
+
+describe("sessions", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("prices, caps and checks in tickets per session", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [pass, passProgram] = await fundedWallet(connection);
+        const [, dayProgram] = await fundedWallet(connection);
+        const [dayTwo, dayTwoProgram] = await fundedWallet(connection);
+        const now = Math.floor(Date.now() / 1000);
+
+        await organiser.sendTransaction([await organiser.getInitEventIx({
+            eventName: "test",
+            eventSymbol: "TST",
+            imageUri: "https://test.com/",
+            metadataUri: "https://testmetadata.com/",
+            eventDate: now + 3 * 86400,
+            ticketPrice: 0.1,
+            numTickets: 10,
+            refundPeriod: 72000,
+        })]);
+        await organiser.sendTransaction([await organiser.getSetSessionsIx([
+            { date: now + 60, price: 0.1, capacity: 1 },
+            { date: now + 3 * 86400, price: 0.2, capacity: 5 },
+        ])]);
+        await organiser.sendTransaction([await organiser.getStartSaleIx()]);
+        const event = getEventAddress(organiser.program.provider.publicKey)[0];
+
+        // Sessions are locked once the sale has started
+        await assertFails(
+            organiser.sendTransaction([await organiser.getSetSessionsIx([])]),
+            "SaleStarted",
+        );
+
+        // A full pass is priced at the sum of its sessions
+        const vault = getVaultAddress(event)[0];
+        const before = await connection.getBalance(vault);
+        await passProgram.sendTransaction([await passProgram.getBuyTicketIx(event, { sessions: 0b11 })]);
+        assert.strictEqual(await connection.getBalance(vault) - before, 0.3 * LAMPORTS_PER_SOL);
+        const receipt = await organiser.program.account.receipt
+            .fetch(getReceiptAddress(event, pass.publicKey)[0]);
+        assert.strictEqual(receipt.sessions, 0b11);
+        console.log("Session pricing: OK");
+
+        await assertFails(
+            dayProgram.sendTransaction([await dayProgram.getBuyTicketIx(event, { sessions: 0b01 })]),
+            "SessionSoldOut",
+        );
+        await dayTwoProgram.sendTransaction([
+            await dayTwoProgram.getBuyTicketIx(event, { sessions: 0b10 }),
+        ]);
+
+        // Check-ins are per session and need an entitlement to it
+        await assertFails(
+            organiser.sendTransaction(
+                [await organiser.getCheckInIx(event, dayTwo.publicKey, 0)],
+                [dayTwo],
+            ),
+            "NotEntitled",
+        );
+        await organiser.sendTransaction(
+            [await organiser.getCheckInIx(event, pass.publicKey, 0)],
+            [pass],
+        );
+        await assertFails(
+            organiser.sendTransaction(
+                [await organiser.getCheckInIx(event, pass.publicKey, 0)],
+                [pass],
+            ),
+            "AlreadyCheckedIn",
+        );
+        await assertFails(
+            organiser.sendTransaction(
+                [await organiser.getCheckInIx(event, pass.publicKey, 1)],
+                [pass],
+            ),
+            "SessionNotOpen",
+        );
+        let eventInfo = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(eventInfo.sessions[0].attendance, 1);
+        console.log("Session check-in: OK");
+
+        // Refunds release the session capacity
+        assert.strictEqual(eventInfo.sessions[1].sold, 2);
+        await organiser.sendTransaction([await organiser.getRefundTicketIx(dayTwo.publicKey)]);
+        eventInfo = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(eventInfo.sessions[1].sold, 1);
+    });
+
+    it("locks sessions once a ticket is issued and releases them on burn", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [guest, guestProgram] = await fundedWallet(connection);
+        const [, buyerProgram] = await fundedWallet(connection);
+        const now = Math.floor(Date.now() / 1000);
+
+        await organiser.sendTransaction([await organiser.getInitEventIx({
+            eventName: "test",
+            eventSymbol: "TST",
+            imageUri: "https://test.com/",
+            metadataUri: "https://testmetadata.com/",
+            eventDate: now + 3 * 86400,
+            ticketPrice: 0.1,
+            numTickets: 10,
+            refundPeriod: 72000,
+        })]);
+        await organiser.sendTransaction([await organiser.getSetSessionsIx([
+            { date: now + 86400, price: 0.1, capacity: 1 },
+        ])]);
+        await organiser.sendTransaction([await organiser.getAmendEventIx({ compQuota: 1 })]);
+        const event = getEventAddress(organiser.program.provider.publicKey)[0];
+
+        // A comp issued before the sale starts references the sessions by index
+        await organiser.sendTransaction(
+            [await organiser.getIssueCompTicketIx(guest.publicKey, 0b01)],
+            [guest],
+        );
+        await assertFails(
+            organiser.sendTransaction([await organiser.getSetSessionsIx([])]),
+            "SaleStarted",
+        );
+        await organiser.sendTransaction([await organiser.getStartSaleIx()]);
+
+        // Burning the comp frees its seat in the session
+        await guestProgram.sendTransaction([await guestProgram.getBurnTicketIx(event)]);
+        const eventInfo = await organiser.program.account.event.fetch(event);
+        assert.strictEqual(eventInfo.sessions[0].sold, 0);
+        await buyerProgram.sendTransaction([await buyerProgram.getBuyTicketIx(event, { sessions: 0b01 })]);
+        console.log("Session lock and burn: OK");
+    });
+});
+
+describe("proof of attendance", () => {
+    let connection: Connection;
+
+    before(() => {
+        connection = anchor.AnchorProvider.env().connection;
+    });
+
+    it("mints a frozen souvenir to checked in holders once", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [holder, holderProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+
+        await organiser.sendTransaction([await organiser.getInitAttendanceIx({
+            name: "test attendance",
+            symbol: "TSTA",
+            uri: "https://testattendance.com/",
+            transferable: false,
+        })]);
+        await holderProgram.sendTransaction([await holderProgram.getBuyTicketIx(event)]);
+
+        // Holders cannot claim before entering the event
+        await assertFails(
+            holderProgram.sendTransaction([await holderProgram.getClaimAttendanceIx(event)]),
+            "NotCheckedIn",
+        );
+
+        await organiser.sendTransaction(
+            [await organiser.getCheckInIx(event, holder.publicKey)],
+            [holder],
+        );
+        await holderProgram.sendTransaction([await holderProgram.getClaimAttendanceIx(event)]);
+
+        const ata = getAssociatedTokenAddressSync(
+            getAttendanceMintAddress(event)[0],
+            holder.publicKey,
+        );
+        const ataData = AccountLayout.decode((await connection.getAccountInfo(ata)).data);
+        assert.strictEqual(ataData.amount.toString(), "1");
+        // Frozen so that the souvenir cannot be transferred
+        assert.strictEqual(ataData.state, 2);
+        console.log("Attendance claimed: OK");
+
+        await assertFails(
+            holderProgram.sendTransaction([await holderProgram.getClaimAttendanceIx(event)]),
+            "AttendanceClaimed",
+        );
+    });
+
+    it("leaves transferable souvenirs unfrozen", async () => {
+        const [, organiser] = await fundedWallet(connection);
+        const [holder, holderProgram] = await fundedWallet(connection);
+        const event = await startEvent(organiser);
+
+        await organiser.sendTransaction([await organiser.getInitAttendanceIx({
+            name: "test attendance",
+            symbol: "TSTA",
+            uri: "https://testattendance.com/",
+            transferable: true,
+        })]);
+        await holderProgram.sendTransaction([await holderProgram.getBuyTicketIx(event)]);
+        await organiser.sendTransaction(
+            [await organiser.getCheckInIx(event, holder.publicKey)],
+            [holder],
+        );
+        await holderProgram.sendTransaction([await holderProgram.getClaimAttendanceIx(event)]);
+
+        const ata = getAssociatedTokenAddressSync(
+            getAttendanceMintAddress(event)[0],
+            holder.publicKey,
+        );
+        const ataData = AccountLayout.decode((await connection.getAccountInfo(ata)).data);
+        assert.strictEqual(ataData.amount.toString(), "1");
+        assert.strictEqual(ataData.state, 1);
+        console.log("Transferable souvenir: OK");
+    });
+});